@@ -15,6 +15,8 @@ use crate::process::{CommandError, CommandReader, CommandReaderBuilder};
 pub struct DecompressionMatcherBuilder {
     /// Команды для каждого подходящего glob-шаблона.
     commands: Vec<DecompressionCommand>,
+    /// Команды для каждой магической сигнатуры.
+    magics: Vec<MagicCommand>,
     /// Следует ли включать правила сопоставления по умолчанию.
     defaults: bool,
 }
@@ -31,6 +33,18 @@ struct DecompressionCommand {
     args: Vec<OsString>,
 }
 
+/// Представление команды распаковки, срабатывающей по магическим байтам
+/// в начале файла, а не по его расширению.
+#[derive(Clone, Debug)]
+struct MagicCommand {
+    /// Сигнатура, с которой сравниваются первые байты файла.
+    magic: Vec<u8>,
+    /// Имя команды или бинарного файла.
+    bin: PathBuf,
+    /// Аргументы для вызова команды.
+    args: Vec<OsString>,
+}
+
 impl Default for DecompressionMatcherBuilder {
     fn default() -> DecompressionMatcherBuilder {
         DecompressionMatcherBuilder::new()
@@ -40,7 +54,11 @@ impl Default for DecompressionMatcherBuilder {
 impl DecompressionMatcherBuilder {
     /// Создает новый построитель для настройки матчера распаковки.
     pub fn new() -> DecompressionMatcherBuilder {
-        DecompressionMatcherBuilder { commands: vec![], defaults: true }
+        DecompressionMatcherBuilder {
+            commands: vec![],
+            magics: vec![],
+            defaults: true,
+        }
     }
 
     /// Построить матчер для определения способа распаковки файлов.
@@ -65,7 +83,7 @@ impl DecompressionMatcherBuilder {
         let globs = glob_builder.build().map_err(|err| {
             CommandError::io(io::Error::new(io::ErrorKind::Other, err))
         })?;
-        Ok(DecompressionMatcher { globs, commands })
+        Ok(DecompressionMatcher { globs, commands, magics: self.magics.clone() })
     }
 
     /// Когда включено, правила сопоставления по умолчанию будут скомпилированы
@@ -143,6 +161,68 @@ impl DecompressionMatcherBuilder {
         self.commands.push(DecompressionCommand { glob, bin, args });
         Ok(self)
     }
+
+    /// Связывает магическую сигнатуру с командой для распаковки файлов,
+    /// первые байты которых совпадают с сигнатурой.
+    ///
+    /// Расширения файлов ненадежны: например, gzip-файл может называться
+    /// `data.dat`. Сопоставление по магическим байтам имеет приоритет над
+    /// сопоставлением по glob-шаблонам. Если несколько сигнатур
+    /// соответствуют одному файлу, то последняя добавленная имеет приоритет.
+    ///
+    /// Как и в `associate`, если программа не может быть разрешена, то она
+    /// молча игнорируется и ассоциация отбрасывается. По этой причине
+    /// вызывающие должны предпочесть `try_add_magic`.
+    pub fn add_magic<P, I, A>(
+        &mut self,
+        magic: &[u8],
+        program: P,
+        args: I,
+    ) -> &mut DecompressionMatcherBuilder
+    where
+        P: AsRef<OsStr>,
+        I: IntoIterator<Item = A>,
+        A: AsRef<OsStr>,
+    {
+        let _ = self.try_add_magic(magic, program, args);
+        self
+    }
+
+    /// Связывает магическую сигнатуру с командой для распаковки файлов,
+    /// первые байты которых совпадают с сигнатурой.
+    ///
+    /// Расширения файлов ненадежны: например, gzip-файл может называться
+    /// `data.dat`. Сопоставление по магическим байтам имеет приоритет над
+    /// сопоставлением по glob-шаблонам. Если несколько сигнатур
+    /// соответствуют одному файлу, то последняя добавленная имеет приоритет.
+    ///
+    /// Если программа не может быть разрешена или сигнатура пуста, то
+    /// возвращается ошибка.
+    pub fn try_add_magic<P, I, A>(
+        &mut self,
+        magic: &[u8],
+        program: P,
+        args: I,
+    ) -> Result<&mut DecompressionMatcherBuilder, CommandError>
+    where
+        P: AsRef<OsStr>,
+        I: IntoIterator<Item = A>,
+        A: AsRef<OsStr>,
+    {
+        if magic.is_empty() {
+            let msg = "magic signature must not be empty";
+            return Err(CommandError::io(io::Error::new(
+                io::ErrorKind::Other,
+                msg,
+            )));
+        }
+        let magic = magic.to_vec();
+        let bin = try_resolve_binary(Path::new(program.as_ref()))?;
+        let args =
+            args.into_iter().map(|a| a.as_ref().to_os_string()).collect();
+        self.magics.push(MagicCommand { magic, bin, args });
+        Ok(self)
+    }
 }
 
 /// Матчер для определения способа распаковки файлов.
@@ -154,6 +234,8 @@ pub struct DecompressionMatcher {
     globs: GlobSet,
     /// Команды для каждого подходящего glob-шаблона.
     commands: Vec<DecompressionCommand>,
+    /// Команды для каждой магической сигнатуры.
+    magics: Vec<MagicCommand>,
 }
 
 impl Default for DecompressionMatcher {
@@ -194,6 +276,37 @@ impl DecompressionMatcher {
     pub fn has_command<P: AsRef<Path>>(&self, path: P) -> bool {
         self.globs.is_match(path)
     }
+
+    /// Возвращает предварительно собранную команду на основе первых байтов
+    /// файла, которая может распаковать его содержимое. Если ни одна
+    /// магическая сигнатура не соответствует данному префиксу, то
+    /// возвращается `None`.
+    ///
+    /// Если есть несколько сигнатур, соответствующих данному префиксу, то
+    /// последняя добавленная имеет приоритет.
+    pub fn command_for_magic(&self, prefix: &[u8]) -> Option<Command> {
+        for magic_cmd in self.magics.iter().rev() {
+            if prefix.starts_with(&magic_cmd.magic) {
+                let mut cmd = Command::new(&magic_cmd.bin);
+                cmd.args(&magic_cmd.args);
+                return Some(cmd);
+            }
+        }
+        None
+    }
+
+    /// Возвращает true тогда и только тогда, когда этот матчер имеет хотя бы
+    /// одну магическую сигнатуру.
+    pub fn has_magic(&self) -> bool {
+        !self.magics.is_empty()
+    }
+
+    /// Возвращает длину самой длинной магической сигнатуры. Именно столько
+    /// байт нужно прочитать из начала файла для сопоставления по магическим
+    /// байтам.
+    fn max_magic_len(&self) -> usize {
+        self.magics.iter().map(|m| m.magic.len()).max().unwrap_or(0)
+    }
 }
 
 /// Настраивает и строит потоковый читатель для распаковки данных.
@@ -226,7 +339,10 @@ impl DecompressionReaderBuilder {
         path: P,
     ) -> Result<DecompressionReader, CommandError> {
         let path = path.as_ref();
-        let Some(mut cmd) = self.matcher.command(path) else {
+        let cmd = self
+            .sniff_magic(path)
+            .or_else(|| self.matcher.command(path));
+        let Some(mut cmd) = cmd else {
             return DecompressionReader::new_passthru(path);
         };
         cmd.arg(path);
@@ -246,6 +362,52 @@ impl DecompressionReaderBuilder {
         }
     }
 
+    /// Читает первые байты файла и ищет команду распаковки по магическим
+    /// сигнатурам матчера.
+    ///
+    /// Префикс читается из отдельно открытого дескриптора, поэтому
+    /// перемотка не требуется: команда распаковки получает путь к файлу и
+    /// открывает его самостоятельно. Если файл не может быть прочитан, то
+    /// это логируется на уровне отладки и возвращается `None`, чтобы
+    /// сопоставление по расширению могло сработать как обычно.
+    fn sniff_magic(&self, path: &Path) -> Option<Command> {
+        use std::io::Read;
+
+        if !self.matcher.has_magic() {
+            return None;
+        }
+        let mut file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) => {
+                log::debug!(
+                    "{}: error opening file for magic sniffing: {} \
+                     (falling back to extension matching)",
+                    path.display(),
+                    err,
+                );
+                return None;
+            }
+        };
+        let mut prefix = vec![0u8; self.matcher.max_magic_len()];
+        let mut nread = 0;
+        while nread < prefix.len() {
+            match file.read(&mut prefix[nread..]) {
+                Ok(0) => break,
+                Ok(n) => nread += n,
+                Err(err) => {
+                    log::debug!(
+                        "{}: error reading file prefix for magic sniffing: \
+                         {} (falling back to extension matching)",
+                        path.display(),
+                        err,
+                    );
+                    return None;
+                }
+            }
+        }
+        self.matcher.command_for_magic(&prefix[..nread])
+    }
+
     /// Установить матчер для использования при поиске команды распаковки
     /// для каждого пути к файлу.
     ///
@@ -536,3 +698,32 @@ fn default_decompression_commands() -> Vec<DecompressionCommand> {
     add("*.Z", ARGS_UNCOMPRESS, &mut cmds);
     cmds
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn magic_matching() {
+        let matcher = DecompressionMatcherBuilder::new()
+            .defaults(false)
+            .add_magic(b"\x1f\x8b", "/bin/sh", &["gzip", "-d", "-c"])
+            .add_magic(b"\x28\xb5\x2f\xfd", "/bin/sh", &["zstd", "-d"])
+            .build()
+            .unwrap();
+        assert!(matcher.has_magic());
+        assert!(matcher.command_for_magic(b"\x1f\x8b\x08\x00").is_some());
+        assert!(
+            matcher.command_for_magic(b"\x28\xb5\x2f\xfd\x00").is_some()
+        );
+        assert!(matcher.command_for_magic(b"plain text").is_none());
+        assert!(matcher.command_for_magic(b"\x1f").is_none());
+    }
+
+    #[test]
+    fn magic_empty_signature() {
+        let mut builder = DecompressionMatcherBuilder::new();
+        assert!(builder.try_add_magic(b"", "gzip", &["-d", "-c"]).is_err());
+    }
+}
@@ -49,7 +49,8 @@ impl std::fmt::Display for ParseSizeError {
                 f,
                 "неверный формат для размера '{}', который должен быть \
                  непустой последовательностью цифр, за которой следует \
-                 необязательный суффикс 'K', 'M' или 'G'",
+                 необязательный суффикс 'K', 'M', 'G', 'T' или 'P'
+                 (или 'KB', 'MB', 'GB', 'TB', 'PB')",
                 self.original
             ),
             InvalidInt(ref err) => write!(
@@ -71,8 +72,10 @@ impl From<ParseSizeError> for std::io::Error {
 /// Разбирает размер, читаемый человеком, например `2M`, в соответствующее
 /// количество байт.
 ///
-/// Поддерживаемые суффиксы размера: `K` (для килобайта), `M` (для мегабайта)
-/// и `G` (для гигабайта). Если суффикс размера отсутствует, то размер
+/// Поддерживаемые суффиксы размера: `K` (для килобайта), `M` (для мегабайта),
+/// `G` (для гигабайта), `T` (для терабайта) и `P` (для петабайта), а также
+/// их двухбуквенные формы `KB`, `MB`, `GB`, `TB` и `PB`. Если суффикс
+/// размера отсутствует, то размер
 /// интерпретируется как байты. Если размер слишком велик для размещения
 /// в `u64`, то возвращается ошибка.
 ///
@@ -92,9 +95,11 @@ pub fn parse_human_readable_size(size: &str) -> Result<u64, ParseSizeError> {
         return Ok(value);
     }
     let bytes = match suffix {
-        "K" => value.checked_mul(1 << 10),
-        "M" => value.checked_mul(1 << 20),
-        "G" => value.checked_mul(1 << 30),
+        "K" | "KB" => value.checked_mul(1 << 10),
+        "M" | "MB" => value.checked_mul(1 << 20),
+        "G" | "GB" => value.checked_mul(1 << 30),
+        "T" | "TB" => value.checked_mul(1 << 40),
+        "P" | "PB" => value.checked_mul(1 << 50),
         _ => return Err(ParseSizeError::format(size)),
     };
     bytes.ok_or_else(|| ParseSizeError::overflow(size))
@@ -143,8 +148,33 @@ mod tests {
         assert!(parse_human_readable_size("9999999999999999G").is_err());
     }
 
+    #[test]
+    fn suffix_t() {
+        let x = parse_human_readable_size("123T").unwrap();
+        assert_eq!(123 * (1u64 << 40), x);
+    }
+
+    #[test]
+    fn suffix_p() {
+        let x = parse_human_readable_size("2P").unwrap();
+        assert_eq!(2 * (1u64 << 50), x);
+    }
+
+    #[test]
+    fn suffix_two_letter() {
+        let x = parse_human_readable_size("123KB").unwrap();
+        assert_eq!(123 * (1 << 10), x);
+        let x = parse_human_readable_size("2TB").unwrap();
+        assert_eq!(2 * (1u64 << 40), x);
+    }
+
+    #[test]
+    fn invalid_overflow_p() {
+        assert!(parse_human_readable_size("99999P").is_err());
+    }
+
     #[test]
     fn invalid_suffix() {
-        assert!(parse_human_readable_size("123T").is_err());
+        assert!(parse_human_readable_size("123X").is_err());
     }
 }
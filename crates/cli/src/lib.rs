@@ -145,11 +145,14 @@ pub use crate::{
     human::{ParseSizeError, parse_human_readable_size},
     pattern::{
         InvalidPatternError, pattern_from_bytes, pattern_from_os,
-        patterns_from_path, patterns_from_reader, patterns_from_stdin,
+        patterns_from_path, patterns_from_reader, patterns_from_reader_iter,
+        patterns_from_stdin,
     },
     process::{CommandError, CommandReader, CommandReaderBuilder},
     wtr::{
-        StandardStream, stdout, stdout_buffered_block, stdout_buffered_line,
+        CrlfWriter, PrefixWriter, StandardStream, stderr,
+        stderr_buffered_block, stderr_buffered_line, stdout,
+        stdout_buffered_block, stdout_buffered_line,
     },
 };
 
@@ -177,72 +180,14 @@ pub fn is_readable_stdin() -> bool {
 
     #[cfg(unix)]
     fn imp() -> bool {
-        use std::{
-            fs::File,
-            os::{fd::AsFd, unix::fs::FileTypeExt},
-        };
-
-        let stdin = std::io::stdin();
-        let fd = match stdin.as_fd().try_clone_to_owned() {
-            Ok(fd) => fd,
-            Err(err) => {
-                log::debug!(
-                    "for heuristic stdin detection on Unix, \
-                     could not clone stdin file descriptor \
-                     (thus assuming stdin is not readable): {err}",
-                );
-                return false;
-            }
-        };
-        let file = File::from(fd);
-        let md = match file.metadata() {
-            Ok(md) => md,
-            Err(err) => {
-                log::debug!(
-                    "for heuristic stdin detection on Unix, \
-                     could not get file metadata for stdin \
-                     (thus assuming stdin is not readable): {err}",
-                );
-                return false;
-            }
-        };
-        let ft = md.file_type();
-        let is_file = ft.is_file();
-        let is_fifo = ft.is_fifo();
-        let is_socket = ft.is_socket();
-        let is_readable = is_file || is_fifo || is_socket;
-        log::debug!(
-            "for heuristic stdin detection on Unix, \
-             found that \
-             is_file={is_file}, is_fifo={is_fifo} and is_socket={is_socket}, \
-             and thus concluded that is_stdin_readable={is_readable}",
-        );
-        is_readable
+        use std::os::fd::AsFd;
+
+        is_readable_fd(std::io::stdin().as_fd(), "stdin")
     }
 
     #[cfg(windows)]
     fn imp() -> bool {
-        let stdin = winapi_util::HandleRef::stdin();
-        let typ = match winapi_util::file::typ(stdin) {
-            Ok(typ) => typ,
-            Err(err) => {
-                log::debug!(
-                    "for heuristic stdin detection on Windows, \
-                     could not get file type of stdin \
-                     (thus assuming stdin is not readable): {err}",
-                );
-                return false;
-            }
-        };
-        let is_disk = typ.is_disk();
-        let is_pipe = typ.is_pipe();
-        let is_readable = is_disk || is_pipe;
-        log::debug!(
-            "for heuristic stdin detection on Windows, \
-             found that is_disk={is_disk} and is_pipe={is_pipe}, \
-             and thus concluded that is_stdin_readable={is_readable}",
-        );
-        is_readable
+        is_readable_handle(winapi_util::HandleRef::stdin(), "stdin")
     }
 
     #[cfg(not(any(unix, windows)))]
@@ -254,6 +199,133 @@ pub fn is_readable_stdin() -> bool {
     !std::io::stdin().is_terminal() && imp()
 }
 
+/// Возвращает true тогда и только тогда, когда stderr считается читаемым.
+///
+/// Это полезно для обнаружения случаев, когда stderr перенаправлен в канал
+/// или файл, например, `command 2>&1 | other-command` или
+/// `command 2> some-file`. В таких случаях программа может выбрать другое
+/// поведение, например, отключить цветное оформление диагностических
+/// сообщений.
+///
+/// Используется та же эвристика, что и в [`is_readable_stdin`]: когда
+/// интроспекция завершается ошибкой, это предпочитает вернуть `false`.
+pub fn is_readable_stderr() -> bool {
+    use std::io::IsTerminal;
+
+    #[cfg(unix)]
+    fn imp() -> bool {
+        use std::os::fd::AsFd;
+
+        is_readable_fd(std::io::stderr().as_fd(), "stderr")
+    }
+
+    #[cfg(windows)]
+    fn imp() -> bool {
+        is_readable_handle(winapi_util::HandleRef::stderr(), "stderr")
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn imp() -> bool {
+        log::debug!(
+            "on non-{{Unix,Windows}}, assuming stderr is not readable"
+        );
+        false
+    }
+
+    !std::io::stderr().is_terminal() && imp()
+}
+
+/// Возвращает true тогда и только тогда, когда данный файловый дескриптор
+/// указывает на файл, FIFO или сокет.
+///
+/// `name` используется только в отладочных сообщениях.
+#[cfg(unix)]
+fn is_readable_fd(fd: std::os::fd::BorrowedFd<'_>, name: &str) -> bool {
+    use std::{fs::File, os::unix::fs::FileTypeExt};
+
+    let fd = match fd.try_clone_to_owned() {
+        Ok(fd) => fd,
+        Err(err) => {
+            log::debug!(
+                "for heuristic {name} detection on Unix, \
+                 could not clone {name} file descriptor \
+                 (thus assuming {name} is not readable): {err}",
+            );
+            return false;
+        }
+    };
+    let file = File::from(fd);
+    let md = match file.metadata() {
+        Ok(md) => md,
+        Err(err) => {
+            log::debug!(
+                "for heuristic {name} detection on Unix, \
+                 could not get file metadata for {name} \
+                 (thus assuming {name} is not readable): {err}",
+            );
+            return false;
+        }
+    };
+    let ft = md.file_type();
+    let is_file = ft.is_file();
+    let is_fifo = ft.is_fifo();
+    let is_socket = ft.is_socket();
+    let is_readable = is_file || is_fifo || is_socket;
+    log::debug!(
+        "for heuristic {name} detection on Unix, \
+         found that \
+         is_file={is_file}, is_fifo={is_fifo} and is_socket={is_socket}, \
+         and thus concluded that is_{name}_readable={is_readable}",
+    );
+    is_readable
+}
+
+/// Возвращает true тогда и только тогда, когда данный дескриптор указывает
+/// на дисковый файл или канал.
+///
+/// `name` используется только в отладочных сообщениях.
+#[cfg(windows)]
+fn is_readable_handle(handle: winapi_util::HandleRef, name: &str) -> bool {
+    let typ = match winapi_util::file::typ(handle) {
+        Ok(typ) => typ,
+        Err(err) => {
+            log::debug!(
+                "for heuristic {name} detection on Windows, \
+                 could not get file type of {name} \
+                 (thus assuming {name} is not readable): {err}",
+            );
+            return false;
+        }
+    };
+    let is_disk = typ.is_disk();
+    let is_pipe = typ.is_pipe();
+    let is_readable = is_disk || is_pipe;
+    log::debug!(
+        "for heuristic {name} detection on Windows, \
+         found that is_disk={is_disk} and is_pipe={is_pipe}, \
+         and thus concluded that is_{name}_readable={is_readable}",
+    );
+    is_readable
+}
+
+/// Возвращает буферизированный читатель для сырых байтов stdin.
+///
+/// В отличие от [`patterns_from_stdin`], никаких предположений о кодировке
+/// не делается: читатель выдает байты stdin как есть, включая невалидный
+/// UTF-8. Это полезно, например, для поиска в stdin как в бинарных данных
+/// через `Searcher::search_reader`, где обнаружение BOM и перекодировка
+/// выполняются самим поисковиком.
+pub fn open_binary_stdin() -> impl std::io::Read {
+    log::debug!("opening stdin for raw binary reading");
+    binary_reader(std::io::stdin().lock())
+}
+
+/// Оборачивает данный читатель в буферизированный читатель, выдающий байты
+/// как есть.
+fn binary_reader<R: std::io::Read>(rdr: R) -> impl std::io::Read {
+    std::io::BufReader::new(rdr)
+}
+
 /// Возвращает true тогда и только тогда, когда stdin считается подключенным
 /// к tty или консоли.
 ///
@@ -301,3 +373,20 @@ pub fn is_tty_stderr() -> bool {
     use std::io::IsTerminal;
     std::io::stderr().is_terminal()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    // Данные больше ёмкости BufReader по умолчанию и содержат нулевые
+    // байты и невалидный UTF-8, чтобы поймать любое усечение или
+    // перекодировку.
+    #[test]
+    fn binary_reader_reads_without_truncation() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(64 * 1024 + 7).collect();
+        let mut rdr = super::binary_reader(&*data);
+        let mut got = vec![];
+        rdr.read_to_end(&mut got).unwrap();
+        assert_eq!(got, data);
+    }
+}
@@ -146,23 +146,66 @@ pub fn patterns_from_stdin() -> io::Result<Vec<String>> {
 /// ```
 pub fn patterns_from_reader<R: io::Read>(rdr: R) -> io::Result<Vec<String>> {
     let mut patterns = vec![];
-    let mut line_number = 0;
-    io::BufReader::new(rdr).for_byte_line(|line| {
-        line_number += 1;
-        match pattern_from_bytes(line) {
-            Ok(pattern) => {
-                patterns.push(pattern.to_string());
-                Ok(true)
+    let rdr = io::BufReader::new(rdr);
+    for (i, result) in patterns_from_reader_iter(rdr).enumerate() {
+        match result {
+            Ok(pattern) => patterns.push(pattern),
+            Err(err) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("{}: {}", i + 1, err),
+                ));
             }
-            Err(err) => Err(io::Error::new(
-                io::ErrorKind::Other,
-                format!("{}: {}", line_number, err),
-            )),
         }
-    })?;
+    }
     Ok(patterns)
 }
 
+/// Читает шаблоны из любого буферизированного читателя, по одному на строку,
+/// лениво.
+///
+/// В отличие от [`patterns_from_reader`], эта подпрограмма не собирает все
+/// шаблоны в память перед возвратом. Вместо этого возвращается итератор,
+/// который выдаёт по одному шаблону за раз. Это полезно, когда файл с
+/// шаблонами очень большой.
+///
+/// Если возникла проблема при чтении или если какой-либо из шаблонов
+/// содержит невалидный UTF-8, то итератор выдаёт ошибку. Для невалидного
+/// UTF-8 ошибка оборачивает [`InvalidPatternError`] с описанием места,
+/// где возникает невалидный UTF-8. Обратите внимание, что номера строк
+/// не включаются в ошибки; вызывающие могут восстановить их, подсчитывая
+/// выданные элементы.
+///
+/// # Пример
+///
+/// Это показывает, как лениво разбирать шаблоны, по одному на строку.
+///
+/// ```
+/// use grep_cli::patterns_from_reader_iter;
+///
+/// let patterns = "\
+/// foo
+/// bar\\s+foo
+/// ";
+///
+/// let mut it = patterns_from_reader_iter(patterns.as_bytes());
+/// assert_eq!(it.next().unwrap()?, r"foo");
+/// assert_eq!(it.next().unwrap()?, r"bar\s+foo");
+/// assert!(it.next().is_none());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn patterns_from_reader_iter<R: io::BufRead>(
+    rdr: R,
+) -> impl Iterator<Item = io::Result<String>> {
+    rdr.byte_lines().map(|result| {
+        let line = result?;
+        match pattern_from_bytes(&line) {
+            Ok(pattern) => Ok(pattern.to_string()),
+            Err(err) => Err(io::Error::from(err)),
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,6 @@
 use std::{
     io::{self, Read},
+    path::{Path, PathBuf},
     process,
 };
 
@@ -84,6 +85,8 @@ impl From<CommandError> for io::Error {
 #[derive(Clone, Debug, Default)]
 pub struct CommandReaderBuilder {
     async_stderr: bool,
+    kill_on_drop: bool,
+    working_dir: Option<PathBuf>,
 }
 
 impl CommandReaderBuilder {
@@ -106,6 +109,9 @@ impl CommandReaderBuilder {
         &self,
         command: &mut process::Command,
     ) -> Result<CommandReader, CommandError> {
+        if let Some(ref dir) = self.working_dir {
+            command.current_dir(dir);
+        }
         let mut child = command
             .stdout(process::Stdio::piped())
             .stderr(process::Stdio::piped())
@@ -115,6 +121,7 @@ impl CommandReaderBuilder {
         } else {
             StderrReader::sync(child.stderr.take().unwrap())
         };
+        let child = KillGuard { child, kill_on_drop: self.kill_on_drop };
         Ok(CommandReader { child, stderr, eof: false })
     }
 
@@ -133,6 +140,39 @@ impl CommandReaderBuilder {
         self.async_stderr = yes;
         self
     }
+
+    /// Когда включено, дочерний процесс принудительно завершается, когда
+    /// читатель удаляется до достижения EOF.
+    ///
+    /// По умолчанию, когда читатель удаляется до исчерпания stdout, читатель
+    /// закрывает канал stdout и ждет завершения дочернего процесса. Хорошо
+    /// ведущий себя процесс выходит при разрыве канала, но процесс, который
+    /// игнорирует его, может продолжать выполняться. Когда эта настройка
+    /// включена, читатель вместо этого посылает дочернему процессу сигнал
+    /// принудительного завершения (`SIGKILL` в Unix, `TerminateProcess` в
+    /// Windows) перед ожиданием.
+    ///
+    /// По умолчанию выключено.
+    pub fn kill_on_drop(&mut self, yes: bool) -> &mut CommandReaderBuilder {
+        self.kill_on_drop = yes;
+        self
+    }
+
+    /// Устанавливает текущий рабочий каталог для дочернего процесса.
+    ///
+    /// Это полезно, когда команда использует относительные пути, которые
+    /// должны разрешаться относительно конкретного каталога, а не текущего
+    /// рабочего каталога вызывающего процесса.
+    ///
+    /// По умолчанию дочерний процесс наследует текущий рабочий каталог
+    /// вызывающего процесса.
+    pub fn working_dir<P: AsRef<Path>>(
+        &mut self,
+        dir: P,
+    ) -> &mut CommandReaderBuilder {
+        self.working_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
 }
 
 /// Потоковый читатель для вывода команды.
@@ -169,7 +209,7 @@ impl CommandReaderBuilder {
 /// ```
 #[derive(Debug)]
 pub struct CommandReader {
-    child: process::Child,
+    child: KillGuard,
     stderr: StderrReader,
     /// Устанавливается в true, когда 'read' возвращает ноль байт. Когда это
     /// не установлено и мы закрываем читатель, то ожидаем ошибку канала
@@ -247,12 +287,61 @@ impl CommandReader {
 
 impl Drop for CommandReader {
     fn drop(&mut self) {
+        // Если мы еще не достигли EOF, то завершаем дочерний процесс до
+        // того, как `close` будет ждать его. Иначе ожидание может заблокировать
+        // удаление на процессе, который игнорирует закрытие своего stdout.
+        if !self.eof {
+            self.child.kill_if_enabled();
+        }
         if let Err(error) = self.close() {
             log::warn!("{}", error);
         }
     }
 }
 
+/// Обертка вокруг дочернего процесса, которая опционально принудительно
+/// завершает его при удалении.
+///
+/// Это последний рубеж защиты: обычно `CommandReader` завершает процесс
+/// явно в своем собственном `drop`, но защита также срабатывает, если
+/// дочерний процесс переживает читатель каким-либо другим путем.
+#[derive(Debug)]
+struct KillGuard {
+    child: process::Child,
+    kill_on_drop: bool,
+}
+
+impl KillGuard {
+    /// Принудительно завершает дочерний процесс, если `kill_on_drop` включен.
+    ///
+    /// Любая ошибка игнорируется, так как процесс мог уже завершиться.
+    fn kill_if_enabled(&mut self) {
+        if self.kill_on_drop {
+            let _ = self.child.kill();
+        }
+    }
+}
+
+impl std::ops::Deref for KillGuard {
+    type Target = process::Child;
+
+    fn deref(&self) -> &process::Child {
+        &self.child
+    }
+}
+
+impl std::ops::DerefMut for KillGuard {
+    fn deref_mut(&mut self) -> &mut process::Child {
+        &mut self.child
+    }
+}
+
+impl Drop for KillGuard {
+    fn drop(&mut self) {
+        self.kill_if_enabled();
+    }
+}
+
 impl io::Read for CommandReader {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         let stdout = match self.child.stdout {
@@ -315,3 +404,36 @@ fn stderr_to_command_error(stderr: &mut process::ChildStderr) -> CommandError {
         Err(err) => CommandError::io(err),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn working_dir_relative_path() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir()
+            .join(format!("grep-cli-working-dir-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let helper = dir.join("helper");
+        std::fs::write(&helper, "#!/bin/sh\necho hello\n").unwrap();
+        std::fs::set_permissions(
+            &helper,
+            std::fs::Permissions::from_mode(0o755),
+        )
+        .unwrap();
+
+        let mut cmd = process::Command::new("./helper");
+        let mut rdr = CommandReaderBuilder::new()
+            .working_dir(&dir)
+            .build(&mut cmd)
+            .unwrap();
+        let mut contents = String::new();
+        rdr.read_to_string(&mut contents).unwrap();
+        assert_eq!("hello\n", contents);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -65,6 +65,59 @@ pub fn stdout_buffered_block(
     StandardStream(StandardStreamKind::BlockBuffered(out))
 }
 
+/// Возвращает возможно буферизированный писатель в stderr для данного
+/// выбора цвета.
+///
+/// Это зеркальный вариант [`stdout`] для stderr: если tty подключен к
+/// stderr, то используется построчная буферизация, иначе — блочная.
+/// Это полезно для инструментов, которые пишут диагностику в stderr и
+/// хотят такого же поведения буферизации, как и для обычного вывода.
+///
+/// Если вам нужен более тонкий контроль над режимом буферизации, то
+/// используйте один из `stderr_buffered_line` или `stderr_buffered_block`.
+///
+/// Выбор цвета передается базовому писателю. Чтобы полностью отключить
+/// цвета во всех случаях, используйте `ColorChoice::Never`.
+pub fn stderr(color_choice: termcolor::ColorChoice) -> StandardStream {
+    if std::io::stderr().is_terminal() {
+        stderr_buffered_line(color_choice)
+    } else {
+        stderr_buffered_block(color_choice)
+    }
+}
+
+/// Возвращает построчно буферизированный писатель в stderr для данного
+/// выбора цвета.
+///
+/// Этот писатель полезен при выводе диагностики напрямую в tty, чтобы
+/// пользователи видели вывод, как только он записан.
+///
+/// Вы можете рассмотреть использование [`stderr`] вместо этого, который
+/// выбирает стратегию буферизации автоматически на основе того, подключен
+/// ли stderr к tty.
+pub fn stderr_buffered_line(
+    color_choice: termcolor::ColorChoice,
+) -> StandardStream {
+    let out = termcolor::StandardStream::stderr(color_choice);
+    StandardStream(StandardStreamKind::LineBuffered(out))
+}
+
+/// Возвращает блочно буферизированный писатель в stderr для данного
+/// выбора цвета.
+///
+/// Этот писатель полезен при перенаправлении stderr в файл, поскольку он
+/// амортизирует стоимость записи данных.
+///
+/// Вы можете рассмотреть использование [`stderr`] вместо этого, который
+/// выбирает стратегию буферизации автоматически на основе того, подключен
+/// ли stderr к tty.
+pub fn stderr_buffered_block(
+    color_choice: termcolor::ColorChoice,
+) -> StandardStream {
+    let out = termcolor::BufferedStandardStream::stderr(color_choice);
+    StandardStream(StandardStreamKind::BlockBuffered(out))
+}
+
 #[derive(Debug)]
 enum StandardStreamKind {
     LineBuffered(termcolor::StandardStream),
@@ -154,3 +207,220 @@ impl termcolor::WriteColor for StandardStream {
         }
     }
 }
+
+/// Писатель, вставляющий `\r` перед каждым `\n`, записываемым в базовый
+/// писатель.
+///
+/// Это полезно для вывода с CRLF-терминаторами строк (как принято в Windows)
+/// без изменения остального содержимого. `\n`, перед которым уже стоит `\r`,
+/// не преобразуется, так что существующие CRLF-терминаторы не удваиваются.
+///
+/// Если писатель создан в отключенном состоянии, то он просто делегирует все
+/// записи базовому писателю без преобразования.
+#[derive(Clone, Debug)]
+pub struct CrlfWriter<W> {
+    wtr: W,
+    enabled: bool,
+    last_byte_was_cr: bool,
+}
+
+impl<W: io::Write> CrlfWriter<W> {
+    /// Создает нового писателя, который преобразует `\n` в `\r\n` при записи
+    /// в `wtr`, если `enabled` истинно. В противном случае записи передаются
+    /// без изменений.
+    pub fn new(wtr: W, enabled: bool) -> CrlfWriter<W> {
+        CrlfWriter { wtr, enabled, last_byte_was_cr: false }
+    }
+
+    /// Возвращает ссылку на базового писателя.
+    pub fn get_ref(&self) -> &W {
+        &self.wtr
+    }
+
+    /// Возвращает изменяемую ссылку на базового писателя.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.wtr
+    }
+
+    /// Потребляет этого писателя и возвращает базового писателя.
+    pub fn into_inner(self) -> W {
+        self.wtr
+    }
+}
+
+impl<W: io::Write> io::Write for CrlfWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !self.enabled {
+            return self.wtr.write(buf);
+        }
+        let mut start = 0;
+        while let Some(off) = buf[start..].iter().position(|&b| b == b'\n') {
+            let i = start + off;
+            self.wtr.write_all(&buf[start..i])?;
+            let prev_is_cr = if i == 0 {
+                self.last_byte_was_cr
+            } else {
+                buf[i - 1] == b'\r'
+            };
+            if !prev_is_cr {
+                self.wtr.write_all(b"\r")?;
+            }
+            self.wtr.write_all(b"\n")?;
+            start = i + 1;
+        }
+        self.wtr.write_all(&buf[start..])?;
+        if let Some(&last) = buf.last() {
+            self.last_byte_was_cr = last == b'\r';
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.wtr.flush()
+    }
+}
+
+/// Писатель, вставляющий фиксированный префикс в начале каждой строки,
+/// записываемой в базовый писатель.
+///
+/// Это полезно для вывода, потребляемого построчными парсерами (например,
+/// системами CI), которым нужен фиксированный маркер для маршрутизации
+/// строк. Префикс вставляется до любых экранирующих последовательностей
+/// цвета, записанных в начале строки.
+///
+/// Если префикс пуст, то писатель просто делегирует все записи базовому
+/// писателю без преобразования.
+#[derive(Clone, Debug)]
+pub struct PrefixWriter<W> {
+    wtr: W,
+    prefix: Vec<u8>,
+    at_line_start: bool,
+}
+
+impl<W: io::Write> PrefixWriter<W> {
+    /// Создает нового писателя, который вставляет `prefix` в начале каждой
+    /// строки, записываемой в `wtr`. Пустой префикс отключает
+    /// преобразование.
+    pub fn new(wtr: W, prefix: Vec<u8>) -> PrefixWriter<W> {
+        PrefixWriter { wtr, prefix, at_line_start: true }
+    }
+
+    /// Возвращает ссылку на базового писателя.
+    pub fn get_ref(&self) -> &W {
+        &self.wtr
+    }
+
+    /// Возвращает изменяемую ссылку на базового писателя.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.wtr
+    }
+
+    /// Потребляет этого писателя и возвращает базового писателя.
+    pub fn into_inner(self) -> W {
+        self.wtr
+    }
+
+    /// Записывает префикс, если следующая запись начинает новую строку.
+    fn write_prefix_if_needed(&mut self) -> io::Result<()> {
+        if self.at_line_start && !self.prefix.is_empty() {
+            self.wtr.write_all(&self.prefix)?;
+            self.at_line_start = false;
+        }
+        Ok(())
+    }
+}
+
+impl<W: io::Write> io::Write for PrefixWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.prefix.is_empty() {
+            return self.wtr.write(buf);
+        }
+        let mut start = 0;
+        while start < buf.len() {
+            self.write_prefix_if_needed()?;
+            match buf[start..].iter().position(|&b| b == b'\n') {
+                Some(off) => {
+                    let i = start + off;
+                    self.wtr.write_all(&buf[start..=i])?;
+                    self.at_line_start = true;
+                    start = i + 1;
+                }
+                None => {
+                    self.wtr.write_all(&buf[start..])?;
+                    start = buf.len();
+                }
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.wtr.flush()
+    }
+}
+
+impl<W: termcolor::WriteColor> termcolor::WriteColor for PrefixWriter<W> {
+    #[inline]
+    fn supports_color(&self) -> bool {
+        self.wtr.supports_color()
+    }
+
+    #[inline]
+    fn supports_hyperlinks(&self) -> bool {
+        self.wtr.supports_hyperlinks()
+    }
+
+    #[inline]
+    fn set_color(&mut self, spec: &termcolor::ColorSpec) -> io::Result<()> {
+        self.write_prefix_if_needed()?;
+        self.wtr.set_color(spec)
+    }
+
+    #[inline]
+    fn set_hyperlink(&mut self, link: &HyperlinkSpec) -> io::Result<()> {
+        self.write_prefix_if_needed()?;
+        self.wtr.set_hyperlink(link)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> io::Result<()> {
+        self.wtr.reset()
+    }
+
+    #[inline]
+    fn is_synchronous(&self) -> bool {
+        self.wtr.is_synchronous()
+    }
+}
+
+impl<W: termcolor::WriteColor> termcolor::WriteColor for CrlfWriter<W> {
+    #[inline]
+    fn supports_color(&self) -> bool {
+        self.wtr.supports_color()
+    }
+
+    #[inline]
+    fn supports_hyperlinks(&self) -> bool {
+        self.wtr.supports_hyperlinks()
+    }
+
+    #[inline]
+    fn set_color(&mut self, spec: &termcolor::ColorSpec) -> io::Result<()> {
+        self.wtr.set_color(spec)
+    }
+
+    #[inline]
+    fn set_hyperlink(&mut self, link: &HyperlinkSpec) -> io::Result<()> {
+        self.wtr.set_hyperlink(link)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> io::Result<()> {
+        self.wtr.reset()
+    }
+
+    #[inline]
+    fn is_synchronous(&self) -> bool {
+        self.wtr.is_synchronous()
+    }
+}
@@ -47,6 +47,32 @@ pub fn args() -> Vec<OsString> {
     args
 }
 
+/// Возвращает последовательность аргументов, полученных из файла конфигурации
+/// rc ripgrep по явно заданному пути, например, через флаг --config-file.
+///
+/// Как и `args`, ошибки чтения или разбора файла не фатальны: они выводятся
+/// как сообщения, а успешно разобранные аргументы возвращаются.
+pub fn args_from_path(path: &Path) -> Vec<OsString> {
+    let (args, errs) = match parse(path) {
+        Ok((args, errs)) => (args, errs),
+        Err(err) => {
+            message!("failed to read config file: {}", err);
+            return vec![];
+        }
+    };
+    if !errs.is_empty() {
+        for err in errs {
+            message!("{}:{}", path.display(), err);
+        }
+    }
+    log::debug!(
+        "{}: arguments loaded from config file: {:?}",
+        path.display(),
+        args
+    );
+    args
+}
+
 /// Разбирает единственный файл rc ripgrep из данного пути.
 ///
 /// При успехе эта функция возвращает набор аргументов оболочки, по порядку,
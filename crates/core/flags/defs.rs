@@ -53,13 +53,23 @@ pub(super) const FLAGS: &[&dyn Flag] = &[
     &ByteOffset,
     &CaseSensitive,
     &Color,
+    &ColorMatchBg,
+    &ColorMatchFg,
     &Colors,
     &Column,
+    &ColumnEnd,
+    &ConfigFile,
     &Context,
+    &ContextOnlyMatchingPortion,
+    &ContextPrintAllMatching,
     &ContextSeparator,
     &Count,
+    &CountFiles,
+    &CountLines,
     &CountMatches,
+    &CountUniqueLines,
     &Crlf,
+    &CrlfOutput,
     &Debug,
     &DfaSizeLimit,
     &Encoding,
@@ -75,26 +85,33 @@ pub(super) const FLAGS: &[&dyn Flag] = &[
     &Glob,
     &GlobCaseInsensitive,
     &Heading,
+    &HeadingSeparator,
     &Help,
     &Hidden,
     &HostnameBin,
     &HyperlinkFormat,
     &IGlob,
     &IgnoreCase,
+    &IgnoreCaseEnv,
     &IgnoreFile,
     &IgnoreFileCaseInsensitive,
     &IncludeZero,
     &InvertMatch,
     &JSON,
+    &JSONNoContext,
+    &JSONPretty,
+    &Label,
     &LineBuffered,
     &LineNumber,
     &LineNumberNo,
+    &LineNumberWidth,
     &LineRegexp,
     &MaxColumns,
     &MaxColumnsPreview,
     &MaxCount,
     &MaxDepth,
     &MaxFilesize,
+    &MinFilesize,
     &Mmap,
     &Multiline,
     &MultilineDotall,
@@ -114,26 +131,39 @@ pub(super) const FLAGS: &[&dyn Flag] = &[
     &NullData,
     &OneFileSystem,
     &OnlyMatching,
+    &OsPathSeparator,
+    &OutputFile,
     &PathSeparator,
     &Passthru,
     &PCRE2,
     &PCRE2Version,
     &Pre,
     &PreGlob,
+    &PrependPattern,
     &Pretty,
+    &PrintLineCount,
+    &Profile,
     &Quiet,
     &RegexSizeLimit,
+    &RelativePaths,
     &Replace,
+    &SearchGitObject,
     &SearchZip,
     &SmartCase,
     &Sort,
+    &SortKey,
+    &SortKeyCache,
     &Sortr,
     &Stats,
+    &StdinFilename,
+    &StatsFormat,
     &StopOnNonmatch,
     &Text,
     &Threads,
+    &Timeout,
     &Trace,
     &Trim,
+    &TrimCrlf,
     &Type,
     &TypeNot,
     &TypeAdd,
@@ -816,6 +846,135 @@ fn test_color() {
     assert!(result.is_err(), "{result:?}");
 }
 
+/// --color-match-bg
+#[derive(Debug)]
+struct ColorMatchBg;
+
+impl Flag for ColorMatchBg {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "color-match-bg"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("COLOR")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        "Задать цвет фона совпадений."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Этот флаг задаёт цвет фона для совпадений в выводе. Это сокращение для
+\fB\-\-colors=match:bg:\fP\fICOLOR\fP. Поддерживаются те же имена цветов,
+а также расширенные 256-цветные коды и коды truecolor, что и для
+\flag{colors}.
+.sp
+Например, следующие две команды эквивалентны:
+.sp
+.EX
+    rg \-\-color\-match\-bg yellow foo
+    rg \-\-colors 'match:bg:yellow' foo
+.EE
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        let v = v.unwrap_value();
+        let v = convert::str(&v)?;
+        args.colors.push(format!("match:bg:{v}").parse()?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_color_match_bg() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert!(args.colors.is_empty());
+
+    let args = parse_low_raw(["--color-match-bg", "yellow"]).unwrap();
+    assert_eq!(args.colors, vec!["match:bg:yellow".parse().unwrap()]);
+
+    let args = parse_low_raw(["--color-match-bg", "0,128,255"]).unwrap();
+    assert_eq!(args.colors, vec!["match:bg:0,128,255".parse().unwrap()]);
+
+    let result = parse_low_raw(["--color-match-bg", "not-a-color"]);
+    assert!(result.is_err(), "{result:?}");
+}
+
+/// --color-match-fg
+#[derive(Debug)]
+struct ColorMatchFg;
+
+impl Flag for ColorMatchFg {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "color-match-fg"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("COLOR")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        "Задать цвет текста совпадений."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Этот флаг задаёт цвет текста для совпадений в выводе. Это сокращение для
+\fB\-\-colors=match:fg:\fP\fICOLOR\fP. Поддерживаются те же имена цветов,
+а также расширенные 256-цветные коды и коды truecolor, что и для
+\flag{colors}.
+.sp
+Например, следующие две команды эквивалентны:
+.sp
+.EX
+    rg \-\-color\-match\-fg magenta foo
+    rg \-\-colors 'match:fg:magenta' foo
+.EE
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        let v = v.unwrap_value();
+        let v = convert::str(&v)?;
+        args.colors.push(format!("match:fg:{v}").parse()?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_color_match_fg() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert!(args.colors.is_empty());
+
+    let args = parse_low_raw(["--color-match-fg", "magenta"]).unwrap();
+    assert_eq!(args.colors, vec!["match:fg:magenta".parse().unwrap()]);
+
+    let args = parse_low_raw([
+        "--color-match-fg",
+        "magenta",
+        "--color-match-bg",
+        "yellow",
+    ])
+    .unwrap();
+    assert_eq!(
+        args.colors,
+        vec![
+            "match:fg:magenta".parse().unwrap(),
+            "match:bg:yellow".parse().unwrap()
+        ]
+    );
+}
+
 /// --colors
 #[derive(Debug)]
 struct Colors;
@@ -1000,6 +1159,57 @@ fn test_column() {
     assert_eq!(Some(true), args.column);
 }
 
+/// --column-end
+#[derive(Debug)]
+struct ColumnEnd;
+
+impl Flag for ColumnEnd {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "column-end"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("no-column-end")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        "Показать также конечные номера столбцов."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Показать также конечный номер столбца каждого совпадения (1-основанный и
+включающий), отделённый от начального номера столбца дефисом. Например,
+\fB3:10\-20:строка\fP. Это полезно для редакторов, которым для подсветки
+нужен весь диапазон совпадения.
+.sp
+Этот флаг имеет эффект только в сочетании с флагом \flag{column}. Как и там,
+Unicode не учитывается: один байт равен одному столбцу.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.column_end = v.unwrap_switch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_column_end() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.column_end);
+
+    let args = parse_low_raw(["--column-end"]).unwrap();
+    assert_eq!(true, args.column_end);
+
+    let args = parse_low_raw(["--column-end", "--no-column-end"]).unwrap();
+    assert_eq!(false, args.column_end);
+}
+
 /// -C/--context
 #[derive(Debug)]
 struct Context;
@@ -1140,6 +1350,192 @@ fn test_context() {
     assert_eq!((2, 1), args.context.get_limited());
 }
 
+/// --context-only-matching-portion
+#[derive(Debug)]
+struct ContextOnlyMatchingPortion;
+
+impl Flag for ContextOnlyMatchingPortion {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "context-only-matching-portion"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("no-context-only-matching-portion")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Усекать контекстные строки до совпадающих частей."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Когда включено, контекстные строки усекаются так, что печатаются только части,
+совпадающие с шаблоном. Контекстные строки без единого совпадения полностью
+опускаются.
+.sp
+Обратите внимание, что при обычном поиске контекстные строки по определению
+не содержат совпадений, поэтому этот флаг приводит к их опусканию. Он в
+основном полезен в сочетании с \flag{invert-match}, при котором контекстные
+строки могут содержать совпадения.
+.sp
+Это не влияет на печать строк совпадений, которой управляет
+\flag{only-matching}. Этот флаг переопределяет
+\flag{context-print-all-matching}.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.context_only_matching_portion = v.unwrap_switch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_context_only_matching_portion() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.context_only_matching_portion);
+
+    let args = parse_low_raw(["--context-only-matching-portion"]).unwrap();
+    assert_eq!(true, args.context_only_matching_portion);
+
+    let args = parse_low_raw([
+        "--context-only-matching-portion",
+        "--no-context-only-matching-portion",
+    ])
+    .unwrap();
+    assert_eq!(false, args.context_only_matching_portion);
+}
+
+/// --context-print-all-matching
+#[derive(Debug)]
+struct ContextPrintAllMatching;
+
+impl Flag for ContextPrintAllMatching {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "context-print-all-matching"
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Печатать контекстные строки целиком."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Когда включено, контекстные строки всегда печатаются целиком, включая части,
+не совпадающие с шаблоном. Это поведение по умолчанию; этот флаг полезен для
+его явного указания и для переопределения
+\flag{context-only-matching-portion}, например, заданного в файле
+конфигурации.
+.sp
+Обратите внимание, что \flag{only-matching} влияет только на печать строк
+совпадений и никогда не усекает контекстные строки.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        assert!(
+            v.unwrap_switch(),
+            "--context-print-all-matching can only be enabled"
+        );
+        args.context_only_matching_portion = false;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_context_print_all_matching() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.context_only_matching_portion);
+
+    let args = parse_low_raw(["--context-print-all-matching"]).unwrap();
+    assert_eq!(false, args.context_only_matching_portion);
+
+    let args = parse_low_raw([
+        "--context-only-matching-portion",
+        "--context-print-all-matching",
+    ])
+    .unwrap();
+    assert_eq!(false, args.context_only_matching_portion);
+
+    let args = parse_low_raw([
+        "--context-print-all-matching",
+        "--context-only-matching-portion",
+    ])
+    .unwrap();
+    assert_eq!(true, args.context_only_matching_portion);
+}
+
+/// --config-file
+#[derive(Debug)]
+struct ConfigFile;
+
+impl Flag for ConfigFile {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "config-file"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("PATH")
+    }
+    fn doc_category(&self) -> Category {
+        Category::OtherBehaviors
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Загрузить файл конфигурации по явному пути."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Загрузить флаги из файла конфигурации по пути \fIPATH\fP, в дополнение к
+файлу, указанному переменной окружения \fBRIPGREP_CONFIG_PATH\fP. Аргументы
+из файлов конфигурации обрабатываются до аргументов командной строки, так
+что аргументы командной строки имеют приоритет.
+.sp
+Этот флаг может быть указан несколько раз. Файлы загружаются в данном
+порядке, и более поздние имеют приоритет. Файл из
+\fBRIPGREP_CONFIG_PATH\fP загружается первым.
+.sp
+Это полезно для монорепозиториев, которые хранят общую конфигурацию ripgrep
+в самом репозитории.
+.sp
+Флаг \flag{no-config} отключает и этот флаг тоже.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.config_files.push(PathBuf::from(v.unwrap_value()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_config_file() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert!(args.config_files.is_empty());
+
+    let args = parse_low_raw(["--config-file", "a.conf"]).unwrap();
+    assert_eq!(vec![PathBuf::from("a.conf")], args.config_files);
+
+    let args =
+        parse_low_raw(["--config-file", "a.conf", "--config-file=b.conf"])
+            .unwrap();
+    assert_eq!(
+        vec![PathBuf::from("a.conf"), PathBuf::from("b.conf")],
+        args.config_files
+    );
+}
+
 /// --context-separator
 #[derive(Debug)]
 struct ContextSeparator;
@@ -1151,6 +1547,9 @@ impl Flag for ContextSeparator {
     fn name_long(&self) -> &'static str {
         "context-separator"
     }
+    fn aliases(&self) -> &'static [&'static str] {
+        &["group-separator"]
+    }
     fn name_negated(&self) -> Option<&'static str> {
         Some("no-context-separator")
     }
@@ -1174,6 +1573,9 @@ impl Flag for ContextSeparator {
 Когда разделитель контекста установлен в пустую строку, разрыв строки всё ещё
 вставляется. Чтобы полностью отключить разделители контекста, используйте флаг
 \flag-negate{context-separator}.
+.sp
+Этот флаг также может быть указан как \fB\-\-group\-separator\fP, как
+в GNU grep.
 "
     }
 
@@ -1206,6 +1608,9 @@ fn test_context_separator() {
     let args = parse_low_raw(["--context-separator", "XYZ"]).unwrap();
     assert_eq!(Some(BString::from("XYZ")), getbytes(args.context_separator));
 
+    let args = parse_low_raw(["--group-separator", "XYZ"]).unwrap();
+    assert_eq!(Some(BString::from("XYZ")), getbytes(args.context_separator));
+
     let args = parse_low_raw(["--no-context-separator"]).unwrap();
     assert_eq!(None, getbytes(args.context_separator));
 
@@ -1328,6 +1733,105 @@ fn test_count() {
     assert_eq!(Mode::Search(SearchMode::Count), args.mode);
 }
 
+/// --count-lines
+#[derive(Debug)]
+struct CountLines;
+
+impl Flag for CountLines {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "count-lines"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("no-count-lines")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Logging
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Учитывать общее количество просмотренных строк в статистике."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Когда включено, ripgrep отслеживает общее количество просмотренных строк,
+независимо от того, совпали они или нет, и сообщает его в сводной статистике
+как \fBlines_searched\fP. Это подразумевает \flag{stats}.
+.sp
+Обратите внимание, что для многострочных поисков сообщаемое количество строк
+может быть меньше фактического количества строк во входных данных.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.count_lines = v.unwrap_switch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_count_lines() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.count_lines);
+
+    let args = parse_low_raw(["--count-lines"]).unwrap();
+    assert_eq!(true, args.count_lines);
+
+    let args = parse_low_raw(["--count-lines", "--no-count-lines"]).unwrap();
+    assert_eq!(false, args.count_lines);
+}
+
+/// --count-files
+#[derive(Debug)]
+struct CountFiles;
+
+impl Flag for CountFiles {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "count-files"
+    }
+    fn doc_category(&self) -> Category {
+        Category::OtherBehaviors
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Вывести количество файлов, которые будут искаться."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Вывести только количество файлов, которые будут искаться, без фактического
+выполнения поиска. Вывод — одно целое число в stdout.
+.sp
+В отличие от \flag{stats}, который также печатает затраченное время и
+количества совпадений, это лёгкий режим, удобный для скриптов.
+.sp
+Это переопределяет \flag{files} и \flag{type-list}.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        assert!(v.unwrap_switch());
+        args.mode.update(Mode::CountFiles);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_count_files() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(Mode::Search(SearchMode::Standard), args.mode);
+
+    let args = parse_low_raw(["--count-files"]).unwrap();
+    assert_eq!(Mode::CountFiles, args.mode);
+
+    let args = parse_low_raw(["--files", "--count-files"]).unwrap();
+    assert_eq!(Mode::CountFiles, args.mode);
+}
+
 /// --count-matches
 #[derive(Debug)]
 struct CountMatches;
@@ -1389,6 +1893,66 @@ fn test_count_matches() {
     assert_eq!(Mode::Search(SearchMode::CountMatches), args.mode);
 }
 
+/// --count-unique-lines
+#[derive(Debug)]
+struct CountUniqueLines;
+
+impl Flag for CountUniqueLines {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "count-unique-lines"
+    }
+    fn doc_category(&self) -> Category {
+        Category::OutputModes
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Показать количество различных совпадающих строк для каждого файла."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Этот флаг подавляет обычный вывод и показывает количество различных по
+содержимому совпадающих строк для каждого искомого файла. В отличие от
+\flag{count}, идентичные совпадающие строки считаются только один раз. Это
+полезно, например, при анализе журналов, где одна и та же строка ошибки может
+появляться много раз, а интересно количество различных сообщений.
+.sp
+Каждый файл, содержащий совпадения, выводит свой путь и количество различных
+совпадающих строк в каждой строке. Терминаторы строк не участвуют в
+сравнении содержимого строк.
+.sp
+Если в ripgrep передан только один файл, то выводится только количество при
+наличии совпадения. Флаг \flag{with-filename} может быть использован для
+принудительного вывода пути к файлу в этом случае.
+.sp
+Это переопределяет флаги \flag{count} и \flag{count-matches}.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        assert!(v.unwrap_switch(), "--count-unique-lines can only be enabled");
+        args.mode.update(Mode::Search(SearchMode::CountUniqueLines));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_count_unique_lines() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(Mode::Search(SearchMode::Standard), args.mode);
+
+    let args = parse_low_raw(["--count-unique-lines"]).unwrap();
+    assert_eq!(Mode::Search(SearchMode::CountUniqueLines), args.mode);
+
+    let args = parse_low_raw(["--count", "--count-unique-lines"]).unwrap();
+    assert_eq!(Mode::Search(SearchMode::CountUniqueLines), args.mode);
+
+    let args = parse_low_raw(["--count-unique-lines", "-c"]).unwrap();
+    assert_eq!(Mode::Search(SearchMode::Count), args.mode);
+}
+
 /// --crlf
 #[derive(Debug)]
 struct Crlf;
@@ -1459,9 +2023,63 @@ fn test_crlf() {
     assert_eq!(false, args.crlf);
     assert_eq!(true, args.null_data);
 
-    let args = parse_low_raw(["--null-data", "--crlf", "--no-crlf"]).unwrap();
-    assert_eq!(false, args.crlf);
-    assert_eq!(false, args.null_data);
+    let args = parse_low_raw(["--null-data", "--crlf", "--no-crlf"]).unwrap();
+    assert_eq!(false, args.crlf);
+    assert_eq!(false, args.null_data);
+}
+
+/// --crlf-output
+#[derive(Debug)]
+struct CrlfOutput;
+
+impl Flag for CrlfOutput {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "crlf-output"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("no-crlf-output")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Использовать CRLF-терминаторы строк в выводе."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Когда включено, ripgrep будет записывать CRLF (\fB\\r\\n\fP) вместо LF
+(\fB\\n\fP) в качестве терминатора строки в своем выводе. Это удобно при
+записи вывода в файлы, предназначенные для инструментов Windows, которые
+ожидают CRLF-терминаторы строк.
+.sp
+Это влияет только на терминаторы строк в выводе. Содержимое самих совпадений
+не изменяется. Терминаторы строк, которые уже являются CRLF, не удваиваются.
+.sp
+Обратите внимание, что этот флаг не влияет на то, как ripgrep разбирает
+искомые данные на строки. Для этого используйте \flag{crlf}.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.crlf_output = v.unwrap_switch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_crlf_output() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.crlf_output);
+
+    let args = parse_low_raw(["--crlf-output"]).unwrap();
+    assert_eq!(true, args.crlf_output);
+
+    let args = parse_low_raw(["--crlf-output", "--no-crlf-output"]).unwrap();
+    assert_eq!(false, args.crlf_output);
 }
 
 /// --debug
@@ -2671,6 +3289,66 @@ fn test_glob_case_insensitive() {
     assert_eq!(true, args.glob_case_insensitive);
 }
 
+/// --heading-separator
+#[derive(Debug)]
+struct HeadingSeparator;
+
+impl Flag for HeadingSeparator {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "heading-separator"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("SEPARATOR")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Установить разделитель, печатаемый после заголовка."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Строка, печатаемая на отдельной строке после заголовка с путём к файлу в
+режиме \flag{heading}. Последовательности экранирования, такие как
+\fB\\x7F\fP или \fB\\t\fP, могут быть использованы.
+.sp
+Когда разделитель пуст, после заголовка ничего не печатается. Это
+поведение по умолчанию.
+.sp
+Этот флаг не имеет эффекта, если режим \flag{heading} отключен.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        use crate::flags::lowargs::HeadingSeparator as Separator;
+
+        args.heading_separator = Separator::new(&v.unwrap_value())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_heading_separator() {
+    use bstr::BString;
+
+    use crate::flags::lowargs::HeadingSeparator as Separator;
+
+    let getbytes = |sep: Separator| sep.into_bytes().map(BString::from);
+
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, getbytes(args.heading_separator));
+
+    let args = parse_low_raw(["--heading-separator", "---"]).unwrap();
+    assert_eq!(Some(BString::from("---")), getbytes(args.heading_separator));
+
+    let args = parse_low_raw([r"--heading-separator", r"\x3d\x3d"]).unwrap();
+    assert_eq!(Some(BString::from("==")), getbytes(args.heading_separator));
+}
+
 /// --heading
 #[derive(Debug)]
 struct Heading;
@@ -3230,6 +3908,75 @@ fn test_ignore_case() {
     assert_eq!(CaseMode::Insensitive, args.case);
 }
 
+/// --ignore-case-env
+#[derive(Debug)]
+struct IgnoreCaseEnv;
+
+impl Flag for IgnoreCaseEnv {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "ignore-case-env"
+    }
+    fn doc_category(&self) -> Category {
+        Category::Search
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Учитывать локаль окружения при игнорировании регистра."
+    }
+    fn doc_long(&self) -> &'static str {
+        r#"
+Когда этот флаг предоставлен, учёт регистра определяется локалью окружения.
+Локаль читается из переменной окружения \fBLC_ALL\fP, а если она не
+установлена или пуста — из \fBLC_COLLATE\fP. Если локаль — \fBC\fP или
+\fBPOSIX\fP (или не установлена), шаблоны ищутся с учётом регистра. Для
+любой другой локали поиск выполняется без учёта регистра.
+.sp
+Обратите внимание, что движки регулярных выражений ripgrep не поддерживают
+локале-зависимые правила приведения регистра. Для локалей, которые их
+требуют (например, турецкой), используется «простое» приведение регистра
+Unicode, и выводится предупреждение.
+"#
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        assert!(v.unwrap_switch(), "flag has no negation");
+        let locale = ["LC_ALL", "LC_COLLATE"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|v| !v.is_empty())
+            .unwrap_or_default();
+        if matches!(locale.as_str(), "" | "C" | "POSIX") {
+            args.case = CaseMode::Sensitive;
+            return Ok(());
+        }
+        // Языки с особыми правилами приведения регистра (например, турецкая
+        // «i» без точки) требуют локале-зависимого сворачивания, которое
+        // движки регулярных выражений ripgrep не поддерживают. Само
+        // предупреждение откладывается до преобразования в высокоуровневые
+        // аргументы, поскольку во время разбора сообщения еще отключены.
+        let lang = locale.split(['_', '.']).next().unwrap_or("");
+        if matches!(lang, "az" | "lt" | "tr") {
+            args.ignore_case_env_unsupported = Some(locale);
+        }
+        args.case = CaseMode::Insensitive;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_ignore_case_env() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(CaseMode::Sensitive, args.case);
+
+    // Результат зависит от локали окружения процесса, поэтому здесь
+    // проверяется только то, что флаг принимается. Поведение для конкретных
+    // локалей покрыто интеграционными тестами.
+    parse_low_raw(["--ignore-case-env"]).unwrap();
+}
+
 /// --ignore-file
 #[derive(Debug)]
 struct IgnoreFile;
@@ -3560,6 +4307,175 @@ fn test_json() {
     assert_eq!(Mode::Search(SearchMode::FilesWithMatches), args.mode);
 }
 
+/// --json-no-context
+#[derive(Debug)]
+struct JSONNoContext;
+
+impl Flag for JSONNoContext {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "json-no-context"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("json-context")
+    }
+    fn doc_category(&self) -> Category {
+        Category::OutputModes
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Подавить контекстные сообщения в выводе JSON."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Подавить сообщения типа \fBcontext\fP в выводе JSON, даже когда один из
+контекстных флагов (то есть, \flag{after-context}, \flag{before-context} или
+\flag{context}) указан. Контекстное окно по-прежнему отслеживается внутренне,
+так что семантика совпадений не меняется; подавляется только вывод.
+.sp
+Этот флаг подразумевает \flag{json}.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.json_no_context = v.unwrap_switch();
+        if args.json_no_context {
+            args.mode.update(Mode::Search(SearchMode::JSON));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_json_no_context() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.json_no_context);
+
+    let args = parse_low_raw(["--json-no-context"]).unwrap();
+    assert_eq!(true, args.json_no_context);
+    assert_eq!(Mode::Search(SearchMode::JSON), args.mode);
+
+    let args = parse_low_raw(["--json-no-context", "--json-context"]).unwrap();
+    assert_eq!(false, args.json_no_context);
+}
+
+/// --json-pretty
+#[derive(Debug)]
+struct JSONPretty;
+
+impl Flag for JSONPretty {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "json-pretty"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("no-json-pretty")
+    }
+    fn doc_category(&self) -> Category {
+        Category::OutputModes
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Показать результаты поиска в формате JSON с отступами."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Включить вывод результатов в формате JSON с отступами.
+.sp
+Это ведёт себя как \flag{json}, за исключением того, что каждое сообщение
+печатается с отступами в 2 пробела вместо компактной формы. Каждое сообщение
+по-прежнему завершается переводом строки. Это в основном полезно для чтения
+вывода в терминале при отладке.
+.sp
+Все ограничения \flag{json} применяются и к этому флагу. Например, он не
+может быть использован вместе с \flag{files}, \flag{count} и т.д.
+.sp
+Флаг \fB\-\-no\-json\-pretty\fP отключает только отступы, не отключая
+сам вывод JSON.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.json_pretty = v.unwrap_switch();
+        if args.json_pretty {
+            args.mode.update(Mode::Search(SearchMode::JSON));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_json_pretty() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.json_pretty);
+    assert_eq!(Mode::Search(SearchMode::Standard), args.mode);
+
+    let args = parse_low_raw(["--json-pretty"]).unwrap();
+    assert_eq!(true, args.json_pretty);
+    assert_eq!(Mode::Search(SearchMode::JSON), args.mode);
+
+    let args = parse_low_raw(["--json-pretty", "--no-json-pretty"]).unwrap();
+    assert_eq!(false, args.json_pretty);
+    assert_eq!(Mode::Search(SearchMode::JSON), args.mode);
+
+    let args = parse_low_raw(["--json", "--json-pretty"]).unwrap();
+    assert_eq!(true, args.json_pretty);
+    assert_eq!(Mode::Search(SearchMode::JSON), args.mode);
+}
+
+/// --label
+#[derive(Debug)]
+struct Label;
+
+impl Flag for Label {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "label"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("TEXT")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Добавлять фиксированную метку перед каждой строкой вывода."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Добавлять \fITEXT\fP с последующим \fB:\fP перед каждой строкой вывода,
+включая совпадения, контекстные строки, имена файлов и разделители. Метка
+вставляется до любых экранирующих последовательностей цвета.
+.sp
+В отличие от \flag{with-filename}, это фиксированная строка, предоставленная
+пользователем, а не путь к файлу. Это полезно, например, когда системы CI
+разбирают вывод ripgrep построчно и нуждаются в префиксе для маршрутизации
+результатов.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.label = Some(convert::string(v.unwrap_value())?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_label() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, args.label);
+
+    let args = parse_low_raw(["--label", "ci"]).unwrap();
+    assert_eq!(Some("ci".to_string()), args.label);
+}
+
 /// --line-buffered
 #[derive(Debug)]
 struct LineBuffered;
@@ -3678,6 +4594,60 @@ fn test_line_number() {
     assert_eq!(Some(false), args.line_number);
 }
 
+/// --line-number-width
+#[derive(Debug)]
+struct LineNumberWidth;
+
+impl Flag for LineNumberWidth {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "line-number-width"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("NUM")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Установить минимальную ширину номеров строк."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Этот флаг устанавливает минимальную ширину поля номера строки. Номера строк,
+содержащие меньше \fINUM\fP цифр, дополняются нулями слева. Это полезно, когда
+вывод обрабатывается инструментами, которые выравнивают столбцы.
+.sp
+Значение по умолчанию \fB0\fP отключает дополнение. Разумные значения лежат
+в диапазоне от \fB1\fP до \fB9\fP; большие значения выводят предупреждение.
+.sp
+Этот флаг не имеет эффекта, если номера строк отключены.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.line_number_width = Some(convert::usize(&v.unwrap_value())?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_line_number_width() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, args.line_number_width);
+
+    let args = parse_low_raw(["--line-number-width", "4"]).unwrap();
+    assert_eq!(Some(4), args.line_number_width);
+
+    let args =
+        parse_low_raw(["--line-number-width=4", "--line-number-width=2"])
+            .unwrap();
+    assert_eq!(Some(2), args.line_number_width);
+}
+
 /// -N/--no-line-number
 #[derive(Debug)]
 struct LineNumberNo;
@@ -4086,6 +5056,63 @@ fn test_max_filesize() {
     assert_eq!(Some(1024 * 1024), args.max_filesize);
 }
 
+/// --min-filesize
+#[derive(Debug)]
+struct MinFilesize;
+
+impl Flag for MinFilesize {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "min-filesize"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("NUM+SUFFIX?")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Filter
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Игнорировать файлы меньше NUM по размеру."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Игнорировать файлы меньше \fINUM\fP по размеру. Это не применяется к каталогам.
+Файлы, размер которых не может быть определен, не игнорируются.
+.sp
+Формат ввода принимает суффиксы \fBK\fP, \fBM\fP или \fBG\fP, которые
+соответствуют килобайтам, мегабайтам и гигабайтам соответственно. Если суффикс
+не предоставлен, ввод рассматривается как байты.
+.sp
+Примеры: \fB\-\-min-filesize 50K\fP или \fB\-\-min\-filesize 80M\fP.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        let v = v.unwrap_value();
+        args.min_filesize = Some(convert::human_readable_u64(&v)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_min_filesize() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, args.min_filesize);
+
+    let args = parse_low_raw(["--min-filesize", "1024"]).unwrap();
+    assert_eq!(Some(1024), args.min_filesize);
+
+    let args = parse_low_raw(["--min-filesize", "1K"]).unwrap();
+    assert_eq!(Some(1024), args.min_filesize);
+
+    let args =
+        parse_low_raw(["--min-filesize", "1K", "--min-filesize=1M"]).unwrap();
+    assert_eq!(Some(1024 * 1024), args.min_filesize);
+}
+
 /// --mmap
 #[derive(Debug)]
 struct Mmap;
@@ -5220,6 +6247,132 @@ fn test_only_matching() {
     assert_eq!(true, args.only_matching);
 }
 
+/// --os-path-separator
+#[derive(Debug)]
+struct OsPathSeparator;
+
+impl Flag for OsPathSeparator {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "os-path-separator"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("SEPARATOR")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Принудительно использовать / или \ как разделитель путей."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Принудительно использовать данный разделитель путей, который должен быть либо
+\fB/\fP, либо \fB\\\fP, при выводе путей к файлам. В отличие от флага
+\flag{path-separator}, который принимает произвольный байт, этот флаг
+ограничен двумя разделителями путей, используемыми операционными системами.
+.sp
+Это полезно, например, когда вывод ripgrep в Windows потребляется
+Unix-инструментами (такими как \fBxargs\fP), запущенными через WSL или MSYS,
+которые ожидают пути, разделенные \fB/\fP.
+.sp
+Этот флаг переопределяет флаг \flag{path-separator} и наоборот, в зависимости
+от того, какой из них дан последним.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        let s = convert::string(v.unwrap_value())?;
+        args.path_separator = match &*s {
+            "/" => Some(b'/'),
+            r"\" => Some(b'\\'),
+            _ => anyhow::bail!(
+                "An OS path separator must be either '/' or '\\', \
+                 but '{s}' was given.\n\
+                 In some shells on Windows '/' is automatically \
+                 expanded. Use '//' instead.",
+            ),
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_os_path_separator() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, args.path_separator);
+
+    let args = parse_low_raw(["--os-path-separator", "/"]).unwrap();
+    assert_eq!(Some(b'/'), args.path_separator);
+
+    let args = parse_low_raw(["--os-path-separator", r"\"]).unwrap();
+    assert_eq!(Some(b'\\'), args.path_separator);
+
+    let args =
+        parse_low_raw(["--path-separator=:", "--os-path-separator=/"])
+            .unwrap();
+    assert_eq!(Some(b'/'), args.path_separator);
+
+    let result = parse_low_raw(["--os-path-separator", ":"]);
+    assert!(result.is_err(), "{result:?}");
+
+    let result = parse_low_raw(["--os-path-separator", "foo"]);
+    assert!(result.is_err(), "{result:?}");
+}
+
+/// --output-file
+#[derive(Debug)]
+struct OutputFile;
+
+impl Flag for OutputFile {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "output-file"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("PATH")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Записывать результаты в файл вместо stdout."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Записывать все результаты поиска в \fIPATH\fP вместо stdout. Файл
+открывается для записи и усекается. Сообщения об ошибках по-прежнему
+выводятся в stderr.
+.sp
+Это полезно, когда ripgrep запускается как подпроцесс (например, из
+системы сборки) и перенаправление оболочки недоступно.
+.sp
+Обратите внимание, что запись в файл всегда заставляет ripgrep отказаться
+от параллелизма и работать в одном потоке.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.output_file = Some(PathBuf::from(v.unwrap_value()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_output_file() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, args.output_file);
+
+    let args = parse_low_raw(["--output-file", "results.txt"]).unwrap();
+    assert_eq!(Some(PathBuf::from("results.txt")), args.output_file);
+}
+
 /// --path-separator
 #[derive(Debug)]
 struct PathSeparator;
@@ -5680,6 +6833,59 @@ fn test_pre_glob() {
     assert_eq!(vec!["*.pdf".to_string(), "foo".to_string()], args.pre_glob);
 }
 
+/// --prepend-pattern
+#[derive(Debug)]
+struct PrependPattern;
+
+impl Flag for PrependPattern {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "prepend-pattern"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("no-prepend-pattern")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Печатать совпавший паттерн перед каждой строкой совпадения."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Печатать паттерн, который произвёл совпадение, перед каждой строкой
+совпадения. Паттерн отделяется от остальной части строки разделителем
+полей, который можно изменить флагом \flag{field-match-separator}.
+.sp
+Это полезно при поиске с несколькими паттернами (то есть, с несколькими
+флагами \flag{regexp} или \flag{file}), чтобы узнать, какой из них совпал
+на каждой строке. Если невозможно определить, какой паттерн произвёл
+совпадение, то поле паттерна опускается.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.prepend_pattern = v.unwrap_switch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_prepend_pattern() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.prepend_pattern);
+
+    let args = parse_low_raw(["--prepend-pattern"]).unwrap();
+    assert_eq!(true, args.prepend_pattern);
+
+    let args =
+        parse_low_raw(["--prepend-pattern", "--no-prepend-pattern"]).unwrap();
+    assert_eq!(false, args.prepend_pattern);
+}
+
 /// -p/--pretty
 #[derive(Debug)]
 struct Pretty;
@@ -5736,6 +6942,106 @@ fn test_pretty() {
     assert_eq!(Some(true), args.line_number);
 }
 
+/// --print-line-count
+#[derive(Debug)]
+struct PrintLineCount;
+
+impl Flag for PrintLineCount {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "print-line-count"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("no-print-line-count")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Печатать количество совпавших строк после совпадений каждого файла."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Печатать итоговую строку с количеством совпавших строк после вывода
+совпадений каждого файла. В отличие от флага \flag{count}, который
+подавляет обычный вывод совпадений, этот флаг добавляет итоговую строку
+к обычному построчному выводу. Для файлов без совпадений итоговая строка
+не печатается.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.print_line_count = v.unwrap_switch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_print_line_count() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.print_line_count);
+
+    let args = parse_low_raw(["--print-line-count"]).unwrap();
+    assert_eq!(true, args.print_line_count);
+
+    let args = parse_low_raw(["--print-line-count", "--no-print-line-count"])
+        .unwrap();
+    assert_eq!(false, args.print_line_count);
+}
+
+/// --profile
+#[derive(Debug)]
+struct Profile;
+
+impl Flag for Profile {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "profile"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("no-profile")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Logging
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Вывести время поиска для каждого файла."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Когда установлен, после завершения поиска ripgrep выводит таблицу файлов,
+отсортированную по затраченному времени, в формате
+\fIduration_ms\fP\fBTAB\fP\fIpath\fP. Таблица выводится в stderr после всех
+результатов, так что она не мешает передаче результатов по конвейеру.
+.sp
+Это полезно для выяснения, какие файлы делают поиск медленным.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.profile = v.unwrap_switch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_profile() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.profile);
+
+    let args = parse_low_raw(["--profile"]).unwrap();
+    assert_eq!(true, args.profile);
+
+    let args = parse_low_raw(["--profile", "--no-profile"]).unwrap();
+    assert_eq!(false, args.profile);
+}
+
 /// -q/--quiet
 #[derive(Debug)]
 struct Quiet;
@@ -6013,6 +7319,58 @@ fn test_regexp() {
     );
 }
 
+/// --relative-paths
+#[derive(Debug)]
+struct RelativePaths;
+
+impl Flag for RelativePaths {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "relative-paths"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("no-relative-paths")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Печатать пути относительно текущего рабочего каталога."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Когда установлен, пути к файлам в выводе печатаются относительно текущего
+рабочего каталога, даже когда ripgrep вызван с абсолютным путем для поиска.
+Пути, которые не начинаются с текущего рабочего каталога, печатаются как
+есть.
+.sp
+Это полезно для инструментов, которые обрабатывают вывод ripgrep и ожидают
+относительные пути.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.relative_paths = v.unwrap_switch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_relative_paths() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.relative_paths);
+
+    let args = parse_low_raw(["--relative-paths"]).unwrap();
+    assert_eq!(true, args.relative_paths);
+
+    let args =
+        parse_low_raw(["--relative-paths", "--no-relative-paths"]).unwrap();
+    assert_eq!(false, args.relative_paths);
+}
+
 /// -r/--replace
 #[derive(Debug)]
 struct Replace;
@@ -6071,33 +7429,93 @@ impl Flag for Replace {
     }
 
     fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
-        args.replace = Some(convert::string(v.unwrap_value())?.into());
+        args.replace = Some(convert::string(v.unwrap_value())?.into());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_replace() {
+    use bstr::BString;
+
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, args.replace);
+
+    let args = parse_low_raw(["--replace", "foo"]).unwrap();
+    assert_eq!(Some(BString::from("foo")), args.replace);
+
+    let args = parse_low_raw(["--replace", "-foo"]).unwrap();
+    assert_eq!(Some(BString::from("-foo")), args.replace);
+
+    let args = parse_low_raw(["-r", "foo"]).unwrap();
+    assert_eq!(Some(BString::from("foo")), args.replace);
+
+    let args = parse_low_raw(["-r", "foo", "-rbar"]).unwrap();
+    assert_eq!(Some(BString::from("bar")), args.replace);
+
+    let args = parse_low_raw(["-r", "foo", "-r", ""]).unwrap();
+    assert_eq!(Some(BString::from("")), args.replace);
+}
+
+/// --search-git-object
+#[derive(Debug)]
+struct SearchGitObject;
+
+impl Flag for SearchGitObject {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "search-git-object"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("REF")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Input
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Искать содержимое git-объекта по данной ссылке."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Этот флаг предписывает ripgrep рассматривать данную git-ссылку как входные
+данные для поиска. Ссылка может быть веткой, тегом, SHA коммита или парой
+путь@ссылка, такой как \fBHEAD:src/lib.rs\fP. Содержимое получается запуском
+\fBgit show \fP\fIREF\fP, и поэтому бинарный файл \fBgit\fP должен быть
+доступен в вашем \fBPATH\fP.
+.sp
+Например, \fBrg foo \-\-search\-git\-object HEAD:src/lib.rs\fP эквивалентно
+\fBgit show HEAD:src/lib.rs | rg foo\fP, за исключением того, что ссылка
+используется в качестве отображаемого имени файла.
+.sp
+Когда этот флаг предоставлен, обход каталога не выполняется, и любые
+позиционные аргументы путей к файлам игнорируются.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.search_git_object = Some(v.unwrap_value());
         Ok(())
     }
 }
 
 #[cfg(test)]
 #[test]
-fn test_replace() {
-    use bstr::BString;
-
+fn test_search_git_object() {
     let args = parse_low_raw(None::<&str>).unwrap();
-    assert_eq!(None, args.replace);
-
-    let args = parse_low_raw(["--replace", "foo"]).unwrap();
-    assert_eq!(Some(BString::from("foo")), args.replace);
-
-    let args = parse_low_raw(["--replace", "-foo"]).unwrap();
-    assert_eq!(Some(BString::from("-foo")), args.replace);
-
-    let args = parse_low_raw(["-r", "foo"]).unwrap();
-    assert_eq!(Some(BString::from("foo")), args.replace);
+    assert_eq!(None, args.search_git_object);
 
-    let args = parse_low_raw(["-r", "foo", "-rbar"]).unwrap();
-    assert_eq!(Some(BString::from("bar")), args.replace);
+    let args =
+        parse_low_raw(["--search-git-object", "HEAD:src/lib.rs"]).unwrap();
+    assert_eq!(
+        Some(std::ffi::OsString::from("HEAD:src/lib.rs")),
+        args.search_git_object
+    );
 
-    let args = parse_low_raw(["-r", "foo", "-r", ""]).unwrap();
-    assert_eq!(Some(BString::from("")), args.replace);
+    let args = parse_low_raw(["--search-git-object=HEAD"]).unwrap();
+    assert_eq!(Some(std::ffi::OsString::from("HEAD")), args.search_git_object);
 }
 
 /// -z/--search-zip
@@ -6383,6 +7801,12 @@ impl Flag for Sort {
 .TP 12
 \fBcreated\fP
 Сортировать по времени создания файла. Всегда однопоточный.
+.TP 12
+\fBmatch-count\fP
+Сортировать по количеству совпадений в файле. Всегда однопоточный. Это требует
+двух проходов: сначала каждый файл ищется для подсчёта совпадений, а затем
+результаты выводятся в отсортированном порядке. Поэтому этот режим примерно
+вдвое медленнее обычного поиска.
 .PP
 Если выбранный (вручную или по умолчанию) критерий сортировки недоступен в вашей
 системе (например, время создания недоступно в файловых системах ext4), то ripgrep
@@ -6396,7 +7820,7 @@ impl Flag for Sort {
 "
     }
     fn doc_choices(&self) -> &'static [&'static str] {
-        &["none", "path", "modified", "accessed", "created"]
+        &["none", "path", "modified", "accessed", "created", "match-count"]
     }
 
     fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
@@ -6409,6 +7833,7 @@ impl Flag for Sort {
             "modified" => SortModeKind::LastModified,
             "accessed" => SortModeKind::LastAccessed,
             "created" => SortModeKind::Created,
+            "match-count" => SortModeKind::MatchCount,
             unk => anyhow::bail!("choice '{unk}' is unrecognized"),
         };
         args.sort = Some(SortMode { reverse: false, kind });
@@ -6439,6 +7864,123 @@ fn test_sort() {
 
     let args = parse_low_raw(["--sort", "path", "--sort=none"]).unwrap();
     assert_eq!(None, args.sort);
+
+    let args = parse_low_raw(["--sort", "match-count"]).unwrap();
+    assert_eq!(
+        Some(SortMode { reverse: false, kind: SortModeKind::MatchCount }),
+        args.sort
+    );
+}
+
+/// --sort-key
+#[derive(Debug)]
+struct SortKey;
+
+impl Flag for SortKey {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "sort-key"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("COMMAND")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Сортировать результаты по выводу внешней команды."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Этот флаг включает сортировку результатов по выводу внешней команды. Для
+каждого файла-кандидата выполняется \fICOMMAND\fP с путем к файлу в качестве
+последнего аргумента, и результаты сортируются по выводу команды как по
+строке в порядке возрастания. Например, \fB\-\-sort-key='git log
+\-\-format=%ct \-1 \-\-'\fP сортирует файлы по времени последнего коммита.
+.sp
+Файлы, для которых команда завершилась с ошибкой, сортируются после всех
+остальных. Чтобы отсортировать в обратном порядке, используйте этот флаг
+вместе с \flag{sortr}.
+.sp
+Вывод команды можно кэшировать между запусками с помощью флага
+\flag{sort-key-cache}.
+.sp
+Обратите внимание, что сортировка результатов всегда заставляет ripgrep
+отказаться от параллелизма и работать в одном потоке.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        let cmd = convert::string(v.unwrap_value())?;
+        anyhow::ensure!(
+            !cmd.trim().is_empty(),
+            "команда для сортировки не может быть пустой"
+        );
+        args.sort_key = Some(cmd);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_sort_key() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, args.sort_key);
+
+    let args = parse_low_raw(["--sort-key", "echo"]).unwrap();
+    assert_eq!(Some("echo".to_string()), args.sort_key);
+
+    let result = parse_low_raw(["--sort-key", ""]);
+    assert!(result.is_err(), "{result:?}");
+}
+
+/// --sort-key-cache
+#[derive(Debug)]
+struct SortKeyCache;
+
+impl Flag for SortKeyCache {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "sort-key-cache"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("PATH")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Кэшировать вывод команды \flag{sort-key} в файле."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Когда этот флаг предоставлен вместе с \flag{sort-key}, вывод команды
+сортировки для каждого пути сохраняется в \fIPATH\fP и повторно
+используется при последующих запусках. Это позволяет избежать повторного
+выполнения потенциально дорогой команды для уже известных путей.
+.sp
+Этот флаг не действует без \flag{sort-key}.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.sort_key_cache = Some(PathBuf::from(v.unwrap_value()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_sort_key_cache() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, args.sort_key_cache);
+
+    let args = parse_low_raw(["--sort-key-cache", "keys.cache"]).unwrap();
+    assert_eq!(Some(PathBuf::from("keys.cache")), args.sort_key_cache);
 }
 
 /// --sortr
@@ -6484,6 +8026,12 @@ impl Flag for Sortr {
 .TP 12
 \fBcreated\fP
 Сортировать по времени создания файла. Всегда однопоточный.
+.TP 12
+\fBmatch-count\fP
+Сортировать по количеству совпадений в файле. Всегда однопоточный. Это требует
+двух проходов: сначала каждый файл ищется для подсчёта совпадений, а затем
+результаты выводятся в отсортированном порядке. Поэтому этот режим примерно
+вдвое медленнее обычного поиска.
 .PP
 Если выбранный (вручную или по умолчанию) критерий сортировки недоступен в вашей
 системе (например, время создания недоступно в файловых системах ext4), то ripgrep
@@ -6497,7 +8045,7 @@ impl Flag for Sortr {
 "
     }
     fn doc_choices(&self) -> &'static [&'static str] {
-        &["none", "path", "modified", "accessed", "created"]
+        &["none", "path", "modified", "accessed", "created", "match-count"]
     }
 
     fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
@@ -6510,6 +8058,7 @@ impl Flag for Sortr {
             "modified" => SortModeKind::LastModified,
             "accessed" => SortModeKind::LastAccessed,
             "created" => SortModeKind::Created,
+            "match-count" => SortModeKind::MatchCount,
             unk => anyhow::bail!("choice '{unk}' is unrecognized"),
         };
         args.sort = Some(SortMode { reverse: true, kind });
@@ -6609,6 +8158,125 @@ fn test_stats() {
     assert_eq!(false, args.stats);
 }
 
+/// --stats-format
+#[derive(Debug)]
+struct StatsFormat;
+
+impl Flag for StatsFormat {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "stats-format"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("FORMAT")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Logging
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Задать формат вывода статистики."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Этот флаг управляет форматом сводной статистики, запрошенной флагом
+\flag{stats}, независимо от формата вывода результатов поиска. \fIFORMAT\fP
+может быть \fBtext\fP или \fBjson\fP.
+.sp
+По умолчанию статистика выводится в виде JSON-сообщения типа \fBsummary\fP,
+когда включён режим \flag{json}, и в виде простого текста в остальных
+случаях. Например, следующая команда напечатает совпадения в виде простого
+текста, а затем статистику в виде одного JSON-сообщения:
+.sp
+.EX
+    rg \-\-stats \-\-stats\-format json foo
+.EE
+"
+    }
+    fn doc_choices(&self) -> &'static [&'static str] {
+        &["text", "json"]
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        use crate::flags::lowargs::StatsFormat as Format;
+
+        let v = v.unwrap_value();
+        let string = convert::str(&v)?;
+        args.stats_format = match string {
+            "text" => Format::Text,
+            "json" => Format::Json,
+            unk => anyhow::bail!("выбор '{unk}' нераспознан"),
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_stats_format() {
+    use crate::flags::lowargs::StatsFormat as Format;
+
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(Format::Auto, args.stats_format);
+
+    let args = parse_low_raw(["--stats-format", "json"]).unwrap();
+    assert_eq!(Format::Json, args.stats_format);
+
+    let args = parse_low_raw(["--stats-format=text"]).unwrap();
+    assert_eq!(Format::Text, args.stats_format);
+
+    let result = parse_low_raw(["--stats-format", "xml"]);
+    assert!(result.is_err(), "{result:?}");
+}
+
+/// --stdin-filename
+#[derive(Debug)]
+struct StdinFilename;
+
+impl Flag for StdinFilename {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "stdin-filename"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("NAME")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Установить отображаемое имя файла для поиска в stdin."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Когда ripgrep читает из stdin, в качестве имени файла отображается
+\fB<stdin>\fP. Этот флаг заменяет это имя на \fINAME\fP. Это полезно для
+инструментов, которые передают содержимое файлов в ripgrep по конвейеру и
+хотят показать осмысленное имя в результатах.
+.sp
+Этот флаг не действует, когда ripgrep не ищет в stdin.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.stdin_filename = Some(PathBuf::from(v.unwrap_value()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_stdin_filename() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, args.stdin_filename);
+
+    let args = parse_low_raw(["--stdin-filename", "<buffer>"]).unwrap();
+    assert_eq!(Some(PathBuf::from("<buffer>")), args.stdin_filename);
+}
+
 /// --stop-on-nonmatch
 #[derive(Debug)]
 struct StopOnNonmatch;
@@ -6808,6 +8476,77 @@ fn test_threads() {
     assert_eq!(None, args.threads);
 }
 
+/// --timeout
+#[derive(Debug)]
+struct Timeout;
+
+impl Flag for Timeout {
+    fn is_switch(&self) -> bool {
+        false
+    }
+    fn name_long(&self) -> &'static str {
+        "timeout"
+    }
+    fn doc_variable(&self) -> Option<&'static str> {
+        Some("DURATION")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Search
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Прервать поиск по истечении лимита времени."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Прервать весь поиск, когда истекает \fIDURATION\fP настенного времени. Когда
+лимит времени превышен, ripgrep выводит сообщение в stderr и завершается с
+кодом выхода \fB2\fP. Результаты, найденные до истечения лимита, выводятся
+как обычно.
+.sp
+Формат ввода принимает суффиксы \fBs\fP, \fBm\fP или \fBh\fP, которые
+соответствуют секундам, минутам и часам соответственно. Если суффикс не
+предоставлен, ввод рассматривается как секунды.
+.sp
+Это полезно для защиты от патологически медленных поисков, например, от
+катастрофического возврата в PCRE2.
+.sp
+Примеры: \fB\-\-timeout 10s\fP или \fB\-\-timeout 5m\fP.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.timeout = Some(convert::human_readable_duration(&v.unwrap_value())?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_timeout() {
+    use std::time::Duration;
+
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(None, args.timeout);
+
+    let args = parse_low_raw(["--timeout", "10"]).unwrap();
+    assert_eq!(Some(Duration::from_secs(10)), args.timeout);
+
+    let args = parse_low_raw(["--timeout", "10s"]).unwrap();
+    assert_eq!(Some(Duration::from_secs(10)), args.timeout);
+
+    let args = parse_low_raw(["--timeout", "5m"]).unwrap();
+    assert_eq!(Some(Duration::from_secs(300)), args.timeout);
+
+    let args = parse_low_raw(["--timeout", "1h"]).unwrap();
+    assert_eq!(Some(Duration::from_secs(3600)), args.timeout);
+
+    let result = parse_low_raw(["--timeout", "10x"]);
+    assert!(result.is_err(), "{result:?}");
+
+    let result = parse_low_raw(["--timeout", ""]);
+    assert!(result.is_err(), "{result:?}");
+}
+
 /// --trace
 #[derive(Debug)]
 struct Trace;
@@ -6899,6 +8638,57 @@ fn test_trim() {
     assert_eq!(false, args.trim);
 }
 
+/// --trim-crlf
+#[derive(Debug)]
+struct TrimCrlf;
+
+impl Flag for TrimCrlf {
+    fn is_switch(&self) -> bool {
+        true
+    }
+    fn name_long(&self) -> &'static str {
+        "trim-crlf"
+    }
+    fn name_negated(&self) -> Option<&'static str> {
+        Some("no-trim-crlf")
+    }
+    fn doc_category(&self) -> Category {
+        Category::Output
+    }
+    fn doc_short(&self) -> &'static str {
+        r"Обрезать завершающие возвраты каретки из печатаемых строк."
+    }
+    fn doc_long(&self) -> &'static str {
+        r"
+Когда установлен, завершающие символы возврата каретки (\fB\r\fP) будут
+удалены из каждой печатаемой строки совпадения или контекста. Это полезно
+при поиске в файлах с окончаниями строк в стиле Windows в Unix-терминале,
+где \fB\r\fP иначе отображается как \fB^M\fP.
+.sp
+В отличие от флага \flag{crlf}, этот флаг не меняет способ разбора
+терминатора строки: он влияет только на вывод.
+"
+    }
+
+    fn update(&self, v: FlagValue, args: &mut LowArgs) -> anyhow::Result<()> {
+        args.trim_crlf = v.unwrap_switch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_trim_crlf() {
+    let args = parse_low_raw(None::<&str>).unwrap();
+    assert_eq!(false, args.trim_crlf);
+
+    let args = parse_low_raw(["--trim-crlf"]).unwrap();
+    assert_eq!(true, args.trim_crlf);
+
+    let args = parse_low_raw(["--trim-crlf", "--no-trim-crlf"]).unwrap();
+    assert_eq!(false, args.trim_crlf);
+}
+
 /// -t/--type
 #[derive(Debug)]
 struct Type;
@@ -7645,6 +9435,24 @@ mod convert {
         grep::cli::parse_human_readable_size(str(v)?).context("invalid size")
     }
 
+    pub(super) fn human_readable_duration(
+        v: &OsStr,
+    ) -> anyhow::Result<std::time::Duration> {
+        let s = str(v)?;
+        let (digits, multiplier) = match s.as_bytes().last() {
+            Some(b's') => (&s[..s.len() - 1], 1),
+            Some(b'm') => (&s[..s.len() - 1], 60),
+            Some(b'h') => (&s[..s.len() - 1], 3600),
+            _ => (s, 1),
+        };
+        let secs: u64 =
+            digits.parse().context("value is not a valid duration")?;
+        let Some(secs) = secs.checked_mul(multiplier) else {
+            anyhow::bail!("duration is too big")
+        };
+        Ok(std::time::Duration::from_secs(secs))
+    }
+
     pub(super) fn human_readable_usize(v: &OsStr) -> anyhow::Result<usize> {
         let size = human_readable_u64(v)?;
         let Ok(size) = usize::try_from(size) else {
@@ -17,7 +17,8 @@ use crate::{
         BinaryMode, BoundaryMode, BufferMode, CaseMode, ColorChoice,
         ContextMode, ContextSeparator, EncodingMode, EngineChoice,
         FieldContextSeparator, FieldMatchSeparator, LowArgs, MmapMode, Mode,
-        PatternSource, SearchMode, SortMode, SortModeKind, TypeChange,
+        PatternSource, SearchMode, SortMode, SortModeKind, StatsFormat,
+        TypeChange,
     },
     haystack::{Haystack, HaystackBuilder},
     search::{PatternMatcher, Printer, SearchWorker, SearchWorkerBuilder},
@@ -44,9 +45,12 @@ pub(crate) struct HiArgs {
     color: ColorChoice,
     colors: grep::printer::ColorSpecs,
     column: bool,
+    column_end: bool,
     context: ContextMode,
+    context_only_matching_portion: bool,
     context_separator: ContextSeparator,
     crlf: bool,
+    crlf_output: bool,
     cwd: PathBuf,
     dfa_size_limit: Option<usize>,
     encoding: EncodingMode,
@@ -58,6 +62,7 @@ pub(crate) struct HiArgs {
     follow: bool,
     globs: ignore::overrides::Override,
     heading: bool,
+    heading_separator: Option<Vec<u8>>,
     hidden: bool,
     hyperlink_config: grep::printer::HyperlinkConfig,
     ignore_file_case_insensitive: bool,
@@ -65,12 +70,17 @@ pub(crate) struct HiArgs {
     include_zero: bool,
     invert_match: bool,
     is_terminal_stdout: bool,
+    json_no_context: bool,
+    json_pretty: bool,
+    label: Option<String>,
     line_number: bool,
+    line_number_width: usize,
     max_columns: Option<u64>,
     max_columns_preview: bool,
     max_count: Option<u64>,
     max_depth: Option<usize>,
     max_filesize: Option<u64>,
+    min_filesize: Option<u64>,
     mmap_choice: grep::searcher::MmapChoice,
     mode: Mode,
     multiline: bool,
@@ -86,22 +96,34 @@ pub(crate) struct HiArgs {
     null_data: bool,
     one_file_system: bool,
     only_matching: bool,
+    output_file: Option<PathBuf>,
     path_separator: Option<u8>,
     paths: Paths,
     path_terminator: Option<u8>,
     patterns: Patterns,
     pre: Option<PathBuf>,
     pre_globs: ignore::overrides::Override,
+    prepend_pattern: bool,
+    print_line_count: bool,
+    profile: bool,
     quiet: bool,
     quit_after_match: bool,
+    relative_paths: bool,
     regex_size_limit: Option<usize>,
     replace: Option<BString>,
+    search_git_object: Option<std::ffi::OsString>,
     search_zip: bool,
     sort: Option<SortMode>,
+    sort_key: Option<String>,
+    sort_key_cache: Option<PathBuf>,
     stats: Option<grep::printer::Stats>,
+    stats_format: StatsFormat,
+    stdin_filename: Option<PathBuf>,
     stop_on_nonmatch: bool,
     threads: usize,
+    timeout: Option<std::time::Duration>,
     trim: bool,
+    trim_crlf: bool,
     types: ignore::types::Types,
     vimgrep: bool,
     with_filename: bool,
@@ -123,6 +145,21 @@ impl HiArgs {
         if let Some(ref sort) = low.sort {
             sort.supported()?;
         }
+        // Предупреждение для --ignore-case-env откладывается до этого места,
+        // потому что во время разбора флагов сообщения еще не включены.
+        if low.line_number_width.map_or(false, |width| width > 9) {
+            message!(
+                "значение --line-number-width больше 9, вероятно, \
+                 является ошибкой",
+            );
+        }
+        if let Some(ref locale) = low.ignore_case_env_unsupported {
+            message!(
+                "локаль '{locale}' требует локале-зависимого приведения \
+                 регистра, которое не поддерживается; используется простое \
+                 приведение регистра Unicode",
+            );
+        }
 
         // Мы изменяем режим на месте в `low`, чтобы последующие преобразования
         // видели правильный режим.
@@ -167,7 +204,11 @@ impl HiArgs {
         };
         let path_terminator = if low.null { Some(b'\x00') } else { None };
         let quit_after_match = stats.is_none() && low.quiet;
-        let threads = if low.sort.is_some() || paths.is_one_file {
+        let threads = if low.sort.is_some()
+            || low.sort_key.is_some()
+            || low.output_file.is_some()
+            || paths.is_one_file
+        {
             1
         } else if let Some(threads) = low.threads {
             threads
@@ -175,9 +216,11 @@ impl HiArgs {
             std::thread::available_parallelism().map_or(1, |n| n.get()).min(12)
         };
         log::debug!("using {threads} thread(s)");
-        let with_filename = low
-            .with_filename
-            .unwrap_or_else(|| low.vimgrep || !paths.is_one_file);
+        let with_filename = low.with_filename.unwrap_or_else(|| {
+            low.vimgrep
+                || !paths.is_one_file
+                || low.search_git_object.is_some()
+        });
 
         let file_separator = match low.mode {
             Mode::Search(SearchMode::Standard) => {
@@ -206,7 +249,8 @@ impl HiArgs {
                 SearchMode::FilesWithMatches
                 | SearchMode::FilesWithoutMatch
                 | SearchMode::Count
-                | SearchMode::CountMatches => return false,
+                | SearchMode::CountMatches
+                | SearchMode::CountUniqueLines => return false,
                 SearchMode::JSON => return true,
                 SearchMode::Standard => {
                     // Несколько вещей могут подразумевать подсчет номеров строк. В
@@ -264,9 +308,12 @@ impl HiArgs {
             color,
             colors,
             column,
+            column_end: low.column_end,
             context: low.context,
+            context_only_matching_portion: low.context_only_matching_portion,
             context_separator: low.context_separator,
             crlf: low.crlf,
+            crlf_output: low.crlf_output,
             cwd: state.cwd,
             dfa_size_limit: low.dfa_size_limit,
             encoding: low.encoding,
@@ -277,6 +324,7 @@ impl HiArgs {
             fixed_strings: low.fixed_strings,
             follow: low.follow,
             heading,
+            heading_separator: low.heading_separator.into_bytes(),
             hidden: low.hidden,
             hyperlink_config,
             ignore_file: low.ignore_file,
@@ -284,12 +332,17 @@ impl HiArgs {
             include_zero: low.include_zero,
             invert_match: low.invert_match,
             is_terminal_stdout: state.is_terminal_stdout,
+            json_no_context: low.json_no_context,
+            json_pretty: low.json_pretty,
+            label: low.label,
             line_number,
+            line_number_width: low.line_number_width.unwrap_or(0),
             max_columns: low.max_columns,
             max_columns_preview: low.max_columns_preview,
             max_count: low.max_count,
             max_depth: low.max_depth,
             max_filesize: low.max_filesize,
+            min_filesize: low.min_filesize,
             mmap_choice,
             multiline: low.multiline,
             multiline_dotall: low.multiline_dotall,
@@ -304,21 +357,33 @@ impl HiArgs {
             null_data: low.null_data,
             one_file_system: low.one_file_system,
             only_matching: low.only_matching,
+            output_file: low.output_file,
             globs,
             path_separator: low.path_separator,
             path_terminator,
             pre: low.pre,
             pre_globs,
+            prepend_pattern: low.prepend_pattern,
+            print_line_count: low.print_line_count,
+            profile: low.profile,
             quiet: low.quiet,
             quit_after_match,
+            relative_paths: low.relative_paths,
             regex_size_limit: low.regex_size_limit,
             replace: low.replace,
+            search_git_object: low.search_git_object,
             search_zip: low.search_zip,
             sort: low.sort,
+            sort_key: low.sort_key,
+            sort_key_cache: low.sort_key_cache,
             stats,
+            stats_format: low.stats_format,
+            stdin_filename: low.stdin_filename,
             stop_on_nonmatch: low.stop_on_nonmatch,
             threads,
+            timeout: low.timeout,
             trim: low.trim,
+            trim_crlf: low.trim_crlf,
             types,
             vimgrep: low.vimgrep,
             with_filename,
@@ -361,6 +426,10 @@ impl HiArgs {
     pub(crate) fn haystack_builder(&self) -> HaystackBuilder {
         let mut builder = HaystackBuilder::new();
         builder.strip_dot_prefix(self.paths.has_implicit_path);
+        if self.relative_paths {
+            builder.strip_cwd_prefix(std::env::current_dir().ok());
+        }
+        builder.stdin_filename(self.stdin_filename.clone());
         builder
     }
 
@@ -563,16 +632,25 @@ impl HiArgs {
     ///
     /// Это выбирает, какой принтер строить (JSON, сводка или стандартный) на
     /// основе данного режима поиска.
+    ///
+    /// Писатель всегда оборачивается в [`grep::cli::PrefixWriter`], который
+    /// добавляет метку `--label` в начале каждой строки (и ничего не делает
+    /// без `--label`), и в [`grep::cli::CrlfWriter`], который преобразует
+    /// терминаторы строк в CRLF, когда пользователь передал
+    /// `--crlf-output`, и в противном случае ведет себя как обычный писатель.
     pub(crate) fn printer<W: termcolor::WriteColor>(
         &self,
         search_mode: SearchMode,
         wtr: W,
-    ) -> Printer<W> {
+    ) -> Printer<grep::cli::CrlfWriter<grep::cli::PrefixWriter<W>>> {
+        let wtr = grep::cli::PrefixWriter::new(wtr, self.label_prefix());
+        let wtr = grep::cli::CrlfWriter::new(wtr, self.crlf_output);
         let summary_kind = if self.quiet {
             match search_mode {
                 SearchMode::FilesWithMatches
                 | SearchMode::Count
                 | SearchMode::CountMatches
+                | SearchMode::CountUniqueLines
                 | SearchMode::JSON
                 | SearchMode::Standard => SummaryKind::QuietWithMatch,
                 SearchMode::FilesWithoutMatch => {
@@ -585,6 +663,9 @@ impl HiArgs {
                 SearchMode::FilesWithoutMatch => SummaryKind::PathWithoutMatch,
                 SearchMode::Count => SummaryKind::Count,
                 SearchMode::CountMatches => SummaryKind::CountMatches,
+                SearchMode::CountUniqueLines => {
+                    SummaryKind::CountUniqueLines
+                }
                 SearchMode::JSON => {
                     return Printer::JSON(self.printer_json(wtr));
                 }
@@ -602,7 +683,8 @@ impl HiArgs {
         wtr: W,
     ) -> grep::printer::JSON<W> {
         grep::printer::JSONBuilder::new()
-            .pretty(false)
+            .pretty(self.json_pretty)
+            .emit_context(!self.json_no_context)
             .always_begin_end(false)
             .replacement(self.replace.clone().map(|r| r.into()))
             .build(wtr)
@@ -619,15 +701,21 @@ impl HiArgs {
             .byte_offset(self.byte_offset)
             .color_specs(self.colors.clone())
             .column(self.column)
+            .column_end(self.column_end)
+            .line_number_width(self.line_number_width)
             .heading(self.heading)
+            .heading_separator(self.heading_separator.clone())
             .hyperlink(self.hyperlink_config.clone())
             .max_columns_preview(self.max_columns_preview)
             .max_columns(self.max_columns)
             .only_matching(self.only_matching)
+            .only_matching_context(self.context_only_matching_portion)
             .path(self.with_filename)
             .path_terminator(self.path_terminator.clone())
             .per_match_one_line(true)
             .per_match(self.vimgrep)
+            .prepend_pattern(self.prepend_pattern)
+            .print_match_count_at_end(self.print_line_count)
             .replacement(self.replace.clone().map(|r| r.into()))
             .separator_context(self.context_separator.clone().into_bytes())
             .separator_field_context(
@@ -638,7 +726,8 @@ impl HiArgs {
             )
             .separator_path(self.path_separator.clone())
             .stats(self.stats.is_some())
-            .trim_ascii(self.trim);
+            .trim_ascii(self.trim)
+            .trim_crlf(self.trim_crlf);
         // При выполнении многопоточного поиска буферный писатель отвечает
         // за запись разделителей, поскольку он является единственной вещью,
         // которая знает, было ли что-то напечатано или нет. Но для однопоточного
@@ -688,6 +777,10 @@ impl HiArgs {
     /// из них. (Исключением здесь является, если дан `--stats`, то
     /// `quit_after_match` всегда будет возвращать false, поскольку пользователь
     /// ожидает, что ripgrep найдет все.)
+    pub(crate) fn profile(&self) -> bool {
+        self.profile
+    }
+
     pub(crate) fn quit_after_match(&self) -> bool {
         self.quit_after_match
     }
@@ -706,6 +799,7 @@ impl HiArgs {
         builder
             .preprocessor(self.pre.clone())?
             .preprocessor_globs(self.pre_globs.clone())
+            .search_git_object(self.search_git_object.clone())
             .search_zip(self.search_zip)
             .binary_detection_explicit(self.binary.explicit.clone())
             .binary_detection_implicit(self.binary.implicit.clone());
@@ -729,7 +823,13 @@ impl HiArgs {
             .line_number(self.line_number)
             .multi_line(self.multiline)
             .memory_map(self.mmap_choice.clone())
-            .stop_on_nonmatch(self.stop_on_nonmatch);
+            .stop_on_nonmatch(self.stop_on_nonmatch)
+            .deadline(
+                // Тайм-аут, который настолько велик, что не помещается в
+                // `Instant`, эквивалентен его отсутствию.
+                self.timeout
+                    .and_then(|t| std::time::Instant::now().checked_add(t)),
+            );
         match self.context {
             ContextMode::Passthru => {
                 builder.passthru(true);
@@ -783,6 +883,9 @@ impl HiArgs {
             })
         }
 
+        if let Some(ref cmd) = self.sort_key {
+            return self.sort_by_key(cmd, haystacks);
+        }
         let Some(ref sort) = self.sort else { return Box::new(haystacks) };
         let mut with_timestamps: Vec<_> = match sort.kind {
             SortModeKind::Path if !sort.reverse => return Box::new(haystacks),
@@ -802,6 +905,9 @@ impl HiArgs {
             SortModeKind::Created => {
                 attach_timestamps(haystacks, |md| md.created()).collect()
             }
+            // Сортировка по количеству совпадений требует поиска по
+            // каждому файлу и выполняется отдельным проходом в main.rs.
+            SortModeKind::MatchCount => return Box::new(haystacks),
         };
         with_timestamps.sort_by(|(_, t1), (_, t2)| {
             let ordering = match (*t1, *t2) {
@@ -819,6 +925,140 @@ impl HiArgs {
         Box::new(with_timestamps.into_iter().map(|(s, _)| s))
     }
 
+    /// Возвращает `Some(reverse)`, когда запрошена сортировка по
+    /// количеству совпадений (--sort match-count или --sortr match-count).
+    ///
+    /// Такая сортировка требует поиска по каждому файлу и потому не может
+    /// быть выполнена в `sort`; она реализована отдельным проходом в
+    /// main.rs.
+    pub(crate) fn sort_by_match_count(&self) -> Option<bool> {
+        match self.sort {
+            Some(SortMode { reverse, kind: SortModeKind::MatchCount }) => {
+                Some(reverse)
+            }
+            _ => None,
+        }
+    }
+
+    /// Возвращает принтер для подсчёта совпадений при сортировке по
+    /// количеству совпадений. Вывод такого принтера отбрасывается, а
+    /// статистика всегда включена, чтобы количество совпадений было
+    /// доступно через `SearchResult::stats`.
+    pub(crate) fn printer_match_count<W: termcolor::WriteColor>(
+        &self,
+        wtr: W,
+    ) -> Printer<W> {
+        Printer::Summary(
+            grep::printer::SummaryBuilder::new()
+                .kind(SummaryKind::CountMatches)
+                .stats(true)
+                .build(wtr),
+        )
+    }
+
+    /// Сортирует стога сена по выводу внешней команды, данной через
+    /// --sort-key.
+    ///
+    /// Для каждого пути выполняется `cmd <path>`, и результаты сортируются
+    /// по выводу команды как по строке. Пути, для которых команда завершилась
+    /// с ошибкой, оказываются в конце. Когда дан --sort-key-cache, пары
+    /// `(путь, ключ)` читаются из файла кэша и записываются обратно после
+    /// вычисления недостающих ключей.
+    fn sort_by_key<'a, I>(
+        &self,
+        cmd: &str,
+        haystacks: I,
+    ) -> Box<dyn Iterator<Item = Haystack> + 'a>
+    where
+        I: Iterator<Item = Haystack> + 'a,
+    {
+        use std::{cmp::Ordering, collections::HashMap, path::Path};
+
+        fn key_for(cmd: &str, path: &Path) -> Option<String> {
+            let mut words = cmd.split_whitespace();
+            let bin = words.next()?;
+            let out = std::process::Command::new(bin)
+                .args(words)
+                .arg(path)
+                .output()
+                .ok()?;
+            if !out.status.success() {
+                return None;
+            }
+            Some(String::from_utf8_lossy(&out.stdout).trim().to_string())
+        }
+
+        let mut cache: HashMap<PathBuf, String> = HashMap::new();
+        if let Some(ref cache_path) = self.sort_key_cache {
+            if let Ok(contents) = std::fs::read_to_string(cache_path) {
+                for line in contents.lines() {
+                    if let Some((path, key)) = line.split_once('\t') {
+                        cache.insert(PathBuf::from(path), key.to_string());
+                    }
+                }
+            }
+        }
+        let mut with_keys: Vec<(Haystack, Option<String>)> = haystacks
+            .map(|h| {
+                let key = match cache.get(h.path()) {
+                    Some(key) => Some(key.clone()),
+                    None => {
+                        let key = key_for(cmd, h.path());
+                        if let Some(ref key) = key {
+                            cache
+                                .insert(h.path().to_path_buf(), key.clone());
+                        }
+                        key
+                    }
+                };
+                (h, key)
+            })
+            .collect();
+        if let Some(ref cache_path) = self.sort_key_cache {
+            let mut contents = String::new();
+            for (path, key) in cache.iter() {
+                // Пути, не являющиеся валидным UTF-8, не кэшируются.
+                let Some(path) = path.to_str() else { continue };
+                contents.push_str(path);
+                contents.push('\t');
+                contents.push_str(key);
+                contents.push('\n');
+            }
+            if let Err(err) = std::fs::write(cache_path, contents) {
+                message!(
+                    "не удалось записать кэш ключей сортировки \
+                     {}: {err}",
+                    cache_path.display(),
+                );
+            }
+        }
+        let reverse = self.sort.as_ref().map_or(false, |sort| sort.reverse);
+        with_keys.sort_by(|(_, k1), (_, k2)| {
+            let ordering = match (k1, k2) {
+                (Some(k1), Some(k2)) => k1.cmp(k2),
+                // Пути с ошибками должны оказаться позже (по возрастанию).
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+            if reverse { ordering.reverse() } else { ordering }
+        });
+        Box::new(with_keys.into_iter().map(|(h, _)| h))
+    }
+
+    /// Возвращает префикс, который должен вставляться в начале каждой
+    /// строки вывода.
+    ///
+    /// Это метка из флага `--label` с завершающим `:`. Когда метка не
+    /// задана, возвращается пустой префикс, отключающий преобразование в
+    /// [`grep::cli::PrefixWriter`].
+    pub(crate) fn label_prefix(&self) -> Vec<u8> {
+        match self.label {
+            None => vec![],
+            Some(ref label) => format!("{label}:").into_bytes(),
+        }
+    }
+
     /// Возвращает объект статистики, если пользователь запросил, чтобы ripgrep
     /// отслеживал различные метрики во время поиска.
     ///
@@ -828,15 +1068,46 @@ impl HiArgs {
         self.stats.clone()
     }
 
-    /// Возвращает писатель с поддержкой цвета для stdout.
+    /// Возвращает формат, в котором должна выводиться сводная статистика,
+    /// запрошенная пользователем.
+    pub(crate) fn stats_format(&self) -> StatsFormat {
+        self.stats_format
+    }
+
+    /// Возвращает писатель с поддержкой цвета для вывода результатов.
     ///
-    /// Возвращаемый писатель также настроен на выполнение либо построчной,
+    /// Обычно это stdout. Когда дан флаг `--output-file`, вместо этого
+    /// возвращается писатель в указанный файл (файл усекается). Открытие
+    /// файла может завершиться ошибкой, например, из-за прав доступа.
+    ///
+    /// Писатель в stdout также настроен на выполнение либо построчной,
     /// либо поблочной буферизации на основе явной конфигурации от пользователя
     /// через флаги CLI или автоматически на основе того, подключен ли stdout
-    /// к tty.
-    pub(crate) fn stdout(&self) -> grep::cli::StandardStream {
+    /// к tty. Писатель в файл всегда буферизируется поблочно, а
+    /// экранирующие последовательности цвета пишутся в него только при
+    /// `--color=always`.
+    pub(crate) fn stdout(
+        &self,
+    ) -> anyhow::Result<Box<dyn termcolor::WriteColor + Send>> {
         let color = self.color.to_termcolor();
-        match self.buffer {
+        if let Some(ref path) = self.output_file {
+            let file = match std::fs::File::create(path) {
+                Ok(file) => file,
+                Err(err) => anyhow::bail!(
+                    "не удалось открыть {} для записи: {err}",
+                    path.display(),
+                ),
+            };
+            let wtr = std::io::BufWriter::new(file);
+            return Ok(match color {
+                termcolor::ColorChoice::Always
+                | termcolor::ColorChoice::AlwaysAnsi => {
+                    Box::new(termcolor::Ansi::new(wtr))
+                }
+                _ => Box::new(termcolor::NoColor::new(wtr)),
+            });
+        }
+        Ok(Box::new(match self.buffer {
             BufferMode::Auto => {
                 if self.is_terminal_stdout {
                     grep::cli::stdout_buffered_line(color)
@@ -846,7 +1117,7 @@ impl HiArgs {
             }
             BufferMode::Line => grep::cli::stdout_buffered_line(color),
             BufferMode::Block => grep::cli::stdout_buffered_block(color),
-        }
+        }))
     }
 
     /// Возвращает общее количество потоков, которые ripgrep должен использовать
@@ -893,6 +1164,7 @@ impl HiArgs {
             .max_depth(self.max_depth)
             .follow_links(self.follow)
             .max_filesize(self.max_filesize)
+            .min_filesize(self.min_filesize)
             .threads(self.threads)
             .same_file_system(self.one_file_system)
             .skip_stdout(matches!(self.mode, Mode::Search(_)))
@@ -1085,6 +1357,16 @@ impl Paths {
         // позволяет нам безопасно предполагать, что все оставшиеся позиционные
         // аргументы предназначены для путей к файлам.
 
+        // Когда дан --search-git-object, входные данные происходят из
+        // `git show`, а не из файловой системы. Мы моделируем это как поиск
+        // stdin, чтобы обход каталога никогда не выполнялся.
+        if low.search_git_object.is_some() {
+            return Ok(Paths {
+                paths: vec![PathBuf::from("-")],
+                has_implicit_path: false,
+                is_one_file: true,
+            });
+        }
         let mut paths = Vec::with_capacity(low.positional.len());
         for osarg in low.positional.drain(..) {
             let path = PathBuf::from(osarg);
@@ -1256,7 +1538,10 @@ fn stats(low: &LowArgs) -> Option<grep::printer::Stats> {
     if !matches!(low.mode, Mode::Search(_)) {
         return None;
     }
-    if low.stats || matches!(low.mode, Mode::Search(SearchMode::JSON)) {
+    if low.stats
+        || low.count_lines
+        || matches!(low.mode, Mode::Search(SearchMode::JSON))
+    {
         return Some(grep::printer::Stats::new());
     }
     None
@@ -1278,17 +1563,13 @@ fn take_hyperlink_config(
     _: &mut State,
     low: &mut LowArgs,
 ) -> anyhow::Result<grep::printer::HyperlinkConfig> {
-    let mut env = grep::printer::HyperlinkEnvironment::new();
-    if let Some(hostname) = hostname(low.hostname_bin.as_deref()) {
+    let mut env = grep::printer::HyperlinkEnvironment::auto_detect();
+    if let Some(bin) = low.hostname_bin.as_deref()
+        && let Some(hostname) = hostname(Some(bin))
+    {
         log::debug!("found hostname for hyperlink configuration: {hostname}");
         env.host(Some(hostname));
     }
-    if let Some(wsl_prefix) = wsl_prefix() {
-        log::debug!(
-            "found wsl_prefix for hyperlink configuration: {wsl_prefix}"
-        );
-        env.wsl_prefix(Some(wsl_prefix));
-    }
     let fmt = std::mem::take(&mut low.hyperlink_format);
     log::debug!("hyperlink format: {:?}", fmt.to_string());
     Ok(grep::printer::HyperlinkConfig::new(env, fmt))
@@ -1396,30 +1677,6 @@ fn platform_hostname() -> Option<String> {
     Some(hostname.to_string())
 }
 
-/// Возвращает значение для переменной `{wslprefix}` в формате гиперссылки.
-///
-/// Префикс WSL — это что-то вроде общего ресурса/сети, что предназначено для
-/// разрешения приложениям Windows открывать файлы, хранящиеся на диске WSL.
-///
-/// Если имя дистрибутива WSL недоступно, не является валидным UTF-8 или это
-/// не выполняется в окружении Unix, то это возвращает None.
-///
-/// См.: <https://learn.microsoft.com/en-us/windows/wsl/filesystems>
-fn wsl_prefix() -> Option<String> {
-    if !cfg!(unix) {
-        return None;
-    }
-    let distro_os = std::env::var_os("WSL_DISTRO_NAME")?;
-    let Some(distro) = distro_os.to_str() else {
-        log::debug!(
-            "found WSL_DISTRO_NAME={:?}, but value is not UTF-8",
-            distro_os
-        );
-        return None;
-    };
-    Some(format!("wsl$/{distro}"))
-}
-
 /// Возможно предлагает другой движок регулярных выражений на основе данного
 /// сообщения об ошибке.
 ///
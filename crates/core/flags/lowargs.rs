@@ -45,9 +45,14 @@ pub(crate) struct LowArgs {
     pub(crate) color: ColorChoice,
     pub(crate) colors: Vec<UserColorSpec>,
     pub(crate) column: Option<bool>,
+    pub(crate) column_end: bool,
+    pub(crate) config_files: Vec<PathBuf>,
     pub(crate) context: ContextMode,
+    pub(crate) context_only_matching_portion: bool,
     pub(crate) context_separator: ContextSeparator,
+    pub(crate) count_lines: bool,
     pub(crate) crlf: bool,
+    pub(crate) crlf_output: bool,
     pub(crate) dfa_size_limit: Option<usize>,
     pub(crate) encoding: EncodingMode,
     pub(crate) engine: EngineChoice,
@@ -58,21 +63,28 @@ pub(crate) struct LowArgs {
     pub(crate) glob_case_insensitive: bool,
     pub(crate) globs: Vec<String>,
     pub(crate) heading: Option<bool>,
+    pub(crate) heading_separator: HeadingSeparator,
     pub(crate) hidden: bool,
     pub(crate) hostname_bin: Option<PathBuf>,
     pub(crate) hyperlink_format: HyperlinkFormat,
     pub(crate) iglobs: Vec<String>,
+    pub(crate) ignore_case_env_unsupported: Option<String>,
     pub(crate) ignore_file: Vec<PathBuf>,
     pub(crate) ignore_file_case_insensitive: bool,
     pub(crate) include_zero: bool,
     pub(crate) invert_match: bool,
+    pub(crate) label: Option<String>,
+    pub(crate) json_no_context: bool,
+    pub(crate) json_pretty: bool,
     pub(crate) line_number: Option<bool>,
+    pub(crate) line_number_width: Option<usize>,
     pub(crate) logging: Option<LoggingMode>,
     pub(crate) max_columns: Option<u64>,
     pub(crate) max_columns_preview: bool,
     pub(crate) max_count: Option<u64>,
     pub(crate) max_depth: Option<usize>,
     pub(crate) max_filesize: Option<u64>,
+    pub(crate) min_filesize: Option<u64>,
     pub(crate) mmap: MmapMode,
     pub(crate) multiline: bool,
     pub(crate) multiline_dotall: bool,
@@ -91,18 +103,30 @@ pub(crate) struct LowArgs {
     pub(crate) null_data: bool,
     pub(crate) one_file_system: bool,
     pub(crate) only_matching: bool,
+    pub(crate) output_file: Option<PathBuf>,
     pub(crate) path_separator: Option<u8>,
     pub(crate) pre: Option<PathBuf>,
     pub(crate) pre_glob: Vec<String>,
+    pub(crate) prepend_pattern: bool,
+    pub(crate) print_line_count: bool,
+    pub(crate) profile: bool,
     pub(crate) quiet: bool,
     pub(crate) regex_size_limit: Option<usize>,
+    pub(crate) relative_paths: bool,
     pub(crate) replace: Option<BString>,
+    pub(crate) search_git_object: Option<OsString>,
     pub(crate) search_zip: bool,
     pub(crate) sort: Option<SortMode>,
+    pub(crate) sort_key: Option<String>,
+    pub(crate) sort_key_cache: Option<PathBuf>,
     pub(crate) stats: bool,
+    pub(crate) stdin_filename: Option<PathBuf>,
+    pub(crate) stats_format: StatsFormat,
     pub(crate) stop_on_nonmatch: bool,
     pub(crate) threads: Option<usize>,
+    pub(crate) timeout: Option<std::time::Duration>,
     pub(crate) trim: bool,
+    pub(crate) trim_crlf: bool,
     pub(crate) type_changes: Vec<TypeChange>,
     pub(crate) unrestricted: usize,
     pub(crate) vimgrep: bool,
@@ -157,6 +181,9 @@ pub(crate) enum Mode {
     Search(SearchMode),
     /// Показывает файлы, которые *будут* искаться, но не ищет их фактически.
     Files,
+    /// Выводит только количество файлов, которые *будут* искаться, но не
+    /// ищет их фактически.
+    CountFiles,
     /// Выводит все определения типов файлов, включая типы файлов по умолчанию
     /// и любые дополнительные типы файлов, добавленные в командной строке.
     Types,
@@ -213,6 +240,9 @@ pub(crate) enum SearchMode {
     /// Показывает файлы, содержащие хотя бы одно совпадение, и общее
     /// количество совпадений.
     CountMatches,
+    /// Показывает файлы, содержащие хотя бы одно совпадение, и количество
+    /// различных по содержимому совпадающих строк.
+    CountUniqueLines,
     /// Печатает совпадения в формате строк JSON.
     JSON,
 }
@@ -534,6 +564,32 @@ impl ContextSeparator {
     }
 }
 
+/// Разделитель, печатаемый после заголовка с путём к файлу в режиме
+/// `--heading`.
+///
+/// По умолчанию разделитель отсутствует.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct HeadingSeparator(Option<BString>);
+
+impl HeadingSeparator {
+    /// Создает новый разделитель заголовка из предоставленного пользователем
+    /// аргумента. Это обрабатывает экранирование.
+    pub(crate) fn new(os: &OsStr) -> anyhow::Result<HeadingSeparator> {
+        let Some(string) = os.to_str() else {
+            anyhow::bail!(
+                "separator must be valid UTF-8 (use escape sequences \
+                 to provide a separator that is not valid UTF-8)"
+            )
+        };
+        Ok(HeadingSeparator(Some(Vec::unescape_bytes(string).into())))
+    }
+
+    /// Возвращает сырые байты этого разделителя.
+    pub(crate) fn into_bytes(self) -> Option<Vec<u8>> {
+        self.0.map(|sep| sep.into())
+    }
+}
+
 /// Режим кодировки, который будет использовать поисковик.
 ///
 /// По умолчанию — `Auto`.
@@ -714,6 +770,8 @@ pub(crate) enum SortModeKind {
     LastAccessed,
     /// Сортировать по времени создания.
     Created,
+    /// Сортировать по количеству совпадений в файле.
+    MatchCount,
 }
 
 impl SortMode {
@@ -749,10 +807,27 @@ impl SortMode {
                     "сортировка по времени создания не поддерживается: {err}"
                 );
             }
+            SortModeKind::MatchCount => Ok(()),
         }
     }
 }
 
+/// Формат, используемый для вывода сводной статистики, запрошенной флагом
+/// --stats.
+///
+/// По умолчанию — `Auto`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum StatsFormat {
+    /// Следовать формату вывода результатов поиска: JSON, когда включён
+    /// режим --json, и простой текст в остальных случаях.
+    #[default]
+    Auto,
+    /// Простой текст.
+    Text,
+    /// Одно JSON-сообщение типа 'summary', как в режиме --json.
+    Json,
+}
+
 /// Единственный экземпляр либо изменения, либо выбора одного из типов файлов
 /// ripgrep.
 #[derive(Debug, Eq, PartialEq)]
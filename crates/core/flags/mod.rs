@@ -36,7 +36,7 @@ pub(crate) use crate::flags::{
         },
     },
     hiargs::HiArgs,
-    lowargs::{GenerateMode, Mode, SearchMode, SpecialMode},
+    lowargs::{GenerateMode, Mode, SearchMode, SpecialMode, StatsFormat},
     parse::{ParseResult, parse},
 };
 
@@ -96,7 +96,13 @@ fn parse_low() -> ParseResult<LowArgs> {
     // Ищем аргументы из файла конфигурации. Если мы ничего не получили
     // (будь то файл пуст или RIPGREP_CONFIG_PATH не был установлен), то
     // нам не нужно разбирать заново.
-    let config_args = crate::flags::config::args();
+    let mut config_args = crate::flags::config::args();
+    // Файлы, заданные через --config-file, загружаются после файла из
+    // RIPGREP_CONFIG_PATH и в данном порядке, так что более поздние имеют
+    // приоритет при повторном разборе.
+    for path in low.config_files.iter() {
+        config_args.extend(crate::flags::config::args_from_path(path));
+    }
     if config_args.is_empty() {
         log::debug!("никаких дополнительных аргументов не найдено из файла конфигурации");
         return ParseResult::Ok(low);
@@ -9,18 +9,24 @@
 некоторую легкую логику уровня приложения.
 */
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Построитель для создания объектов для поиска.
 #[derive(Clone, Debug)]
 pub(crate) struct HaystackBuilder {
     strip_dot_prefix: bool,
+    strip_cwd_prefix: Option<PathBuf>,
+    stdin_filename: Option<PathBuf>,
 }
 
 impl HaystackBuilder {
     /// Вернуть новый построитель стогов сена с конфигурацией по умолчанию.
     pub(crate) fn new() -> HaystackBuilder {
-        HaystackBuilder { strip_dot_prefix: false }
+        HaystackBuilder {
+            strip_dot_prefix: false,
+            strip_cwd_prefix: None,
+            stdin_filename: None,
+        }
     }
 
     /// Создать новый стог сена из возможно отсутствующей записи каталога.
@@ -48,7 +54,12 @@ impl HaystackBuilder {
     /// искаться, то возвращается `None` после вывода любых соответствующих
     /// сообщений журнала.
     fn build(&self, dent: ignore::DirEntry) -> Option<Haystack> {
-        let hay = Haystack { dent, strip_dot_prefix: self.strip_dot_prefix };
+        let hay = Haystack {
+            dent,
+            strip_dot_prefix: self.strip_dot_prefix,
+            strip_cwd_prefix: self.strip_cwd_prefix.clone(),
+            stdin_filename: self.stdin_filename.clone(),
+        };
         if let Some(err) = hay.dent.error() {
             ignore_message!("{err}");
         }
@@ -90,6 +101,32 @@ impl HaystackBuilder {
         self.strip_dot_prefix = yes;
         self
     }
+
+    /// Когда задан, если путь к файлу стога сена начинается с данного
+    /// каталога (обычно текущего рабочего каталога), то этот префикс
+    /// удаляется.
+    ///
+    /// Это полезно для печати относительных путей при поиске по
+    /// абсолютному пути.
+    pub(crate) fn strip_cwd_prefix(
+        &mut self,
+        cwd: Option<PathBuf>,
+    ) -> &mut HaystackBuilder {
+        self.strip_cwd_prefix = cwd;
+        self
+    }
+
+    /// Установить отображаемое имя, используемое для стогов сена,
+    /// соответствующих stdin.
+    ///
+    /// Когда не задано, используется специальный путь `<stdin>`.
+    pub(crate) fn stdin_filename(
+        &mut self,
+        name: Option<PathBuf>,
+    ) -> &mut HaystackBuilder {
+        self.stdin_filename = name;
+        self
+    }
 }
 
 /// Стог сена — это то, что мы хотим искать.
@@ -99,19 +136,35 @@ impl HaystackBuilder {
 pub(crate) struct Haystack {
     dent: ignore::DirEntry,
     strip_dot_prefix: bool,
+    strip_cwd_prefix: Option<PathBuf>,
+    stdin_filename: Option<PathBuf>,
 }
 
 impl Haystack {
     /// Вернуть путь к файлу, соответствующий этому стогу сена.
     ///
     /// Если этот стог сена соответствует stdin, то вместо этого возвращается
-    /// специальный путь `<stdin>`.
+    /// специальный путь `<stdin>` (или имя, заданное через
+    /// `--stdin-filename`, если оно есть).
     pub(crate) fn path(&self) -> &Path {
+        if self.is_stdin() {
+            if let Some(ref name) = self.stdin_filename {
+                return name;
+            }
+        }
         if self.strip_dot_prefix && self.dent.path().starts_with("./") {
-            self.dent.path().strip_prefix("./").unwrap()
-        } else {
-            self.dent.path()
+            return self.dent.path().strip_prefix("./").unwrap();
+        }
+        if let Some(ref cwd) = self.strip_cwd_prefix {
+            if let Ok(stripped) = self.dent.path().strip_prefix(cwd) {
+                // Пустой путь означает, что сам cwd был дан как путь для
+                // поиска; в этом случае оставляем путь как есть.
+                if !stripped.as_os_str().is_empty() {
+                    return stripped;
+                }
+            }
         }
+        self.dent.path()
     }
 
     /// Возвращает true тогда и только тогда, когда эта запись соответствует
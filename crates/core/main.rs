@@ -6,7 +6,10 @@ use std::{io::Write, process::ExitCode};
 
 use ignore::WalkState;
 
-use crate::flags::{HiArgs, SearchMode};
+use crate::{
+    flags::{HiArgs, SearchMode, StatsFormat},
+    haystack::Haystack,
+};
 
 #[macro_use]
 mod messages;
@@ -88,6 +91,7 @@ fn run(result: crate::flags::ParseResult<HiArgs>) -> anyhow::Result<ExitCode> {
         Mode::Search(mode) => search_parallel(&args, mode)?,
         Mode::Files if args.threads() == 1 => files(&args)?,
         Mode::Files => files_parallel(&args)?,
+        Mode::CountFiles => return count_files(&args),
         Mode::Types => return types(&args),
         Mode::Generate(mode) => return generate(mode),
     };
@@ -111,22 +115,35 @@ fn search(args: &HiArgs, mode: SearchMode) -> anyhow::Result<bool> {
         .walk_builder()?
         .build()
         .filter_map(|result| haystack_builder.build_from_result(result));
-    let haystacks = args.sort(unsorted);
+    let mut haystacks = args.sort(unsorted);
+    if let Some(reverse) = args.sort_by_match_count() {
+        haystacks = sort_haystacks_by_match_count(args, haystacks, reverse)?;
+    }
 
     let mut matched = false;
     let mut searched = false;
     let mut stats = args.stats();
+    let mut profile = if args.profile() { Some(vec![]) } else { None };
     let mut searcher = args.search_worker(
         args.matcher()?,
         args.searcher()?,
-        args.printer(mode, args.stdout()),
+        args.printer(mode, args.stdout()?),
     )?;
     for haystack in haystacks {
         searched = true;
-        let search_result = match searcher.search(&haystack) {
+        let timer = profile.as_ref().map(|_| std::time::Instant::now());
+        let result = searcher.search(&haystack);
+        if let (Some(profile), Some(timer)) = (profile.as_mut(), timer) {
+            profile.push((timer.elapsed(), haystack.path().to_path_buf()));
+        }
+        let search_result = match result {
             Ok(search_result) => search_result,
             // Разрыв канала означает грациозное завершение.
             Err(err) if err.kind() == std::io::ErrorKind::BrokenPipe => break,
+            // Истечение крайнего срока (--timeout) прерывает весь поиск.
+            Err(err) if err.kind() == std::io::ErrorKind::TimedOut => {
+                return Err(anyhow::anyhow!("превышен лимит времени поиска"));
+            }
             Err(err) => {
                 err_message!("{}: {}", haystack.path().display(), err);
                 continue;
@@ -145,11 +162,50 @@ fn search(args: &HiArgs, mode: SearchMode) -> anyhow::Result<bool> {
     }
     if let Some(ref stats) = stats {
         let wtr = searcher.printer().get_mut();
-        let _ = print_stats(mode, stats, started_at, wtr);
+        let _ =
+            print_stats(mode, args.stats_format(), stats, started_at, wtr);
+    }
+    if let Some(profile) = profile {
+        print_profile(profile);
     }
     Ok(matched)
 }
 
+/// Сортирует стога сена по количеству совпадений в каждом из них.
+///
+/// Это требует двух проходов: сначала каждый файл ищется с отбрасыванием
+/// вывода, чтобы подсчитать совпадения, а затем файлы сортируются по
+/// подсчитанному количеству. Файлы с наименьшим количеством совпадений
+/// идут первыми; `reverse` инвертирует порядок. Файлы, поиск по которым
+/// завершился ошибкой, считаются файлами без совпадений, а сама ошибка
+/// выводится во время второго прохода.
+fn sort_haystacks_by_match_count<'a>(
+    args: &HiArgs,
+    haystacks: Box<dyn Iterator<Item = Haystack> + 'a>,
+    reverse: bool,
+) -> anyhow::Result<Box<dyn Iterator<Item = Haystack> + 'a>> {
+    let mut counter = args.search_worker(
+        args.matcher()?,
+        args.searcher()?,
+        args.printer_match_count(termcolor::NoColor::new(std::io::sink())),
+    )?;
+    let mut counted: Vec<(Haystack, u64)> = vec![];
+    for haystack in haystacks {
+        let count = match counter.search(&haystack) {
+            Ok(result) => {
+                result.stats().map_or(0, |stats| stats.matches())
+            }
+            Err(_) => 0,
+        };
+        counted.push((haystack, count));
+    }
+    counted.sort_by(|(_, count1), (_, count2)| {
+        let ordering = count1.cmp(count2);
+        if reverse { ordering.reverse() } else { ordering }
+    });
+    Ok(Box::new(counted.into_iter().map(|(haystack, _)| haystack)))
+}
+
 /// Точка входа верхнего уровня для многопоточного поиска.
 ///
 /// Параллелизм сам по себе достигается рекурсивным обходом каталога.
@@ -166,8 +222,14 @@ fn search_parallel(args: &HiArgs, mode: SearchMode) -> anyhow::Result<bool> {
     let haystack_builder = args.haystack_builder();
     let bufwtr = args.buffer_writer();
     let stats = args.stats().map(std::sync::Mutex::new);
+    let profile = if args.profile() {
+        Some(std::sync::Mutex::new(vec![]))
+    } else {
+        None
+    };
     let matched = AtomicBool::new(false);
     let searched = AtomicBool::new(false);
+    let timed_out = AtomicBool::new(false);
 
     let mut searcher = args.search_worker(
         args.matcher()?,
@@ -177,8 +239,10 @@ fn search_parallel(args: &HiArgs, mode: SearchMode) -> anyhow::Result<bool> {
     args.walk_builder()?.build_parallel().run(|| {
         let bufwtr = &bufwtr;
         let stats = &stats;
+        let profile = &profile;
         let matched = &matched;
         let searched = &searched;
+        let timed_out = &timed_out;
         let haystack_builder = &haystack_builder;
         let mut searcher = searcher.clone();
 
@@ -188,9 +252,22 @@ fn search_parallel(args: &HiArgs, mode: SearchMode) -> anyhow::Result<bool> {
                 None => return WalkState::Continue,
             };
             searched.store(true, Ordering::SeqCst);
-            searcher.printer().get_mut().clear();
-            let search_result = match searcher.search(&haystack) {
+            searcher.printer().get_mut().get_mut().get_mut().clear();
+            let timer =
+                profile.as_ref().map(|_| std::time::Instant::now());
+            let result = searcher.search(&haystack);
+            if let (Some(profile), Some(timer)) = (profile, timer) {
+                let elapsed = timer.elapsed();
+                let path = haystack.path().to_path_buf();
+                profile.lock().unwrap().push((elapsed, path));
+            }
+            let search_result = match result {
                 Ok(search_result) => search_result,
+                // Истечение крайнего срока (--timeout) прерывает весь поиск.
+                Err(err) if err.kind() == std::io::ErrorKind::TimedOut => {
+                    timed_out.store(true, Ordering::SeqCst);
+                    return WalkState::Quit;
+                }
                 Err(err) => {
                     err_message!("{}: {}", haystack.path().display(), err);
                     return WalkState::Continue;
@@ -203,7 +280,9 @@ fn search_parallel(args: &HiArgs, mode: SearchMode) -> anyhow::Result<bool> {
                 let mut stats = locked_stats.lock().unwrap();
                 *stats += search_result.stats().unwrap();
             }
-            if let Err(err) = bufwtr.print(searcher.printer().get_mut()) {
+            if let Err(err) =
+                bufwtr.print(searcher.printer().get_mut().get_ref().get_ref())
+            {
                 // Разрыв канала означает грациозное завершение.
                 if err.kind() == std::io::ErrorKind::BrokenPipe {
                     return WalkState::Quit;
@@ -218,14 +297,26 @@ fn search_parallel(args: &HiArgs, mode: SearchMode) -> anyhow::Result<bool> {
             }
         })
     });
+    if timed_out.load(Ordering::SeqCst) {
+        return Err(anyhow::anyhow!("превышен лимит времени поиска"));
+    }
     if args.has_implicit_path() && !searched.load(Ordering::SeqCst) {
         eprint_nothing_searched();
     }
     if let Some(ref locked_stats) = stats {
         let stats = locked_stats.lock().unwrap();
-        let mut wtr = searcher.printer().get_mut();
-        let _ = print_stats(mode, &stats, started_at, &mut wtr);
-        let _ = bufwtr.print(&mut wtr);
+        let wtr = searcher.printer().get_mut();
+        let _ = print_stats(
+            mode,
+            args.stats_format(),
+            &stats,
+            started_at,
+            &mut *wtr,
+        );
+        let _ = bufwtr.print(wtr.get_ref().get_ref());
+    }
+    if let Some(profile) = profile {
+        print_profile(profile.into_inner().unwrap());
     }
     Ok(matched.load(Ordering::SeqCst))
 }
@@ -243,7 +334,9 @@ fn files(args: &HiArgs) -> anyhow::Result<bool> {
     let haystacks = args.sort(unsorted);
 
     let mut matched = false;
-    let mut path_printer = args.path_printer_builder().build(args.stdout());
+    let mut path_printer = args.path_printer_builder().build(
+        grep::cli::PrefixWriter::new(args.stdout()?, args.label_prefix()),
+    );
     for haystack in haystacks {
         matched = true;
         if args.quit_after_match() {
@@ -262,6 +355,23 @@ fn files(args: &HiArgs) -> anyhow::Result<bool> {
     Ok(matched)
 }
 
+/// Точка входа верхнего уровня для `--count-files`.
+///
+/// Это рекурсивно проходит через список файлов (каталог по умолчанию) и
+/// печатает только количество файлов, которые будут искаться, одним целым
+/// числом в stdout.
+fn count_files(args: &HiArgs) -> anyhow::Result<ExitCode> {
+    let haystack_builder = args.haystack_builder();
+    let count = args
+        .walk_builder()?
+        .build()
+        .filter_map(|result| haystack_builder.build_from_result(result))
+        .count();
+    let mut stdout = args.stdout()?;
+    writeln!(stdout, "{count}")?;
+    Ok(ExitCode::from(if count == 0 { 1 } else { 0 }))
+}
+
 /// Точка входа верхнего уровня для многопоточного вывода списка файлов без
 /// поиска.
 ///
@@ -281,7 +391,9 @@ fn files_parallel(args: &HiArgs) -> anyhow::Result<bool> {
     };
 
     let haystack_builder = args.haystack_builder();
-    let mut path_printer = args.path_printer_builder().build(args.stdout());
+    let mut path_printer = args.path_printer_builder().build(
+        grep::cli::PrefixWriter::new(args.stdout()?, args.label_prefix()),
+    );
     let matched = AtomicBool::new(false);
     let (tx, rx) = mpsc::channel::<crate::haystack::Haystack>();
 
@@ -331,7 +443,7 @@ fn files_parallel(args: &HiArgs) -> anyhow::Result<bool> {
 /// Точка входа верхнего уровня для `--type-list`.
 fn types(args: &HiArgs) -> anyhow::Result<ExitCode> {
     let mut count = 0;
-    let mut stdout = args.stdout();
+    let mut stdout = args.stdout()?;
     for def in args.types().definitions() {
         count += 1;
         stdout.write_all(def.name().as_bytes())?;
@@ -407,6 +519,24 @@ fn special(mode: crate::flags::SpecialMode) -> anyhow::Result<ExitCode> {
     Ok(exit)
 }
 
+/// Печатает таблицу времени поиска для каждого файла в stderr.
+///
+/// Файлы сортируются по убыванию затраченного времени, так что самые
+/// медленные файлы оказываются первыми. Таблица печатается после всех
+/// результатов, чтобы не мешать передаче результатов по конвейеру.
+fn print_profile(
+    mut profile: Vec<(std::time::Duration, std::path::PathBuf)>,
+) {
+    profile.sort_by(|(dur1, _), (dur2, _)| dur2.cmp(dur1));
+    // Мы пишем напрямую в stderr без префикса `rg:`, чтобы таблицу было
+    // легко разбирать. Ошибки записи намеренно игнорируются, как и при
+    // выводе других диагностических сообщений.
+    let mut stderr = std::io::stderr().lock();
+    for (dur, path) in profile.iter() {
+        let _ = writeln!(stderr, "{}\t{}", dur.as_millis(), path.display());
+    }
+}
+
 /// Печатает эвристическое сообщение об ошибке, когда ничего не найдено.
 ///
 /// Это может произойти, если применимый файл игнорирования имеет одно или
@@ -440,12 +570,18 @@ fn eprint_nothing_searched() {
 /// удасться — если сама запись в stdout не удается.
 fn print_stats<W: Write>(
     mode: SearchMode,
+    format: StatsFormat,
     stats: &grep::printer::Stats,
     started: std::time::Instant,
     mut wtr: W,
 ) -> std::io::Result<()> {
     let elapsed = std::time::Instant::now().duration_since(started);
-    if matches!(mode, SearchMode::JSON) {
+    let json = match format {
+        StatsFormat::Auto => matches!(mode, SearchMode::JSON),
+        StatsFormat::Text => false,
+        StatsFormat::Json => true,
+    };
+    if json {
         // Мы специально сопоставляем формат, изложенный JSON принтером в
         // крейте grep-printer. Мы просто «расширяем» его типом сообщения
         // 'summary'.
@@ -474,6 +610,7 @@ fn print_stats<W: Write>(
 {searches} files searched
 {bytes_printed} bytes printed
 {bytes_searched} bytes searched
+{lines_searched} lines searched
 {search_time:0.6} seconds spent searching
 {process_time:0.6} seconds total
 ",
@@ -483,6 +620,7 @@ fn print_stats<W: Write>(
             searches = stats.searches(),
             bytes_printed = stats.bytes_printed(),
             bytes_searched = stats.bytes_searched(),
+            lines_searched = stats.lines_searched(),
             search_time = stats.elapsed().as_secs_f64(),
             process_time = elapsed.as_secs_f64(),
         )
@@ -20,6 +20,7 @@ use {grep::matcher::Matcher, termcolor::WriteColor};
 struct Config {
     preprocessor: Option<std::path::PathBuf>,
     preprocessor_globs: ignore::overrides::Override,
+    search_git_object: Option<std::ffi::OsString>,
     search_zip: bool,
     binary_implicit: grep::searcher::BinaryDetection,
     binary_explicit: grep::searcher::BinaryDetection,
@@ -30,6 +31,7 @@ impl Default for Config {
         Config {
             preprocessor: None,
             preprocessor_globs: ignore::overrides::Override::empty(),
+            search_git_object: None,
             search_zip: false,
             binary_implicit: grep::searcher::BinaryDetection::none(),
             binary_explicit: grep::searcher::BinaryDetection::none(),
@@ -114,6 +116,20 @@ impl SearchWorkerBuilder {
         self
     }
 
+    /// Установить git-ссылку, содержимое которой должно быть искано вместо
+    /// файлов на диске.
+    ///
+    /// Когда это установлено, поиск «stdin» фактически ищет вывод команды
+    /// `git show <REF>`, а сама ссылка используется в качестве отображаемого
+    /// имени файла.
+    pub(crate) fn search_git_object(
+        &mut self,
+        gitref: Option<std::ffi::OsString>,
+    ) -> &mut SearchWorkerBuilder {
+        self.config.search_git_object = gitref;
+        self
+    }
+
     /// Включить распаковку и поиск распространенных сжатых файлов.
     ///
     /// Когда включено, если определенный путь к файлу распознан как сжатый
@@ -261,7 +277,11 @@ impl<W: WriteColor> SearchWorker<W> {
 
         self.searcher.set_binary_detection(bin);
         if haystack.is_stdin() {
-            self.search_reader(path, &mut io::stdin().lock())
+            if self.config.search_git_object.is_some() {
+                self.search_git_object()
+            } else {
+                self.search_reader(path, &mut io::stdin().lock())
+            }
         } else if self.should_preprocess(path) {
             self.search_preprocessor(path)
         } else if self.should_decompress(path) {
@@ -328,6 +348,37 @@ impl<W: WriteColor> SearchWorker<W> {
         Ok(search_result)
     }
 
+    /// Искать содержимое git-объекта, сконфигурированного через
+    /// `--search-git-object`, запуская `git show` и ища его вывод.
+    fn search_git_object(&mut self) -> io::Result<SearchResult> {
+        use std::process::Stdio;
+
+        let gitref = self.config.search_git_object.clone().unwrap();
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("show").arg(&gitref).stdin(Stdio::null());
+
+        let mut rdr = self.command_builder.build(&mut cmd).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "команда 'git show' не смогла запуститься: \
+                     '{cmd:?}': {err}",
+                ),
+            )
+        })?;
+        let result =
+            self.search_reader(Path::new(&gitref), &mut rdr).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("команда 'git show' не удалась: '{cmd:?}': {err}"),
+                )
+            });
+        let close_result = rdr.close();
+        let search_result = result?;
+        close_result?;
+        Ok(search_result)
+    }
+
     /// Попытаться распаковать данные по данному пути к файлу и искать
     /// результат. Если данный путь к файлу не распознан как сжатый файл,
     /// то искать его без выполнения какой-либо распаковки.
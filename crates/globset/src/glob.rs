@@ -234,6 +234,10 @@ struct GlobOptions {
     /// Когда это не включено, открывающий `[` без соответствующего `]`
     /// трактуется как ошибка.
     allow_unclosed_class: bool,
+    /// Является ли шаблон отрицанием. Отрицание не меняет то, чему
+    /// соответствует сам шаблон, но меняет интерпретацию совпадения в
+    /// `GlobSet`: сработавшее отрицание исключает путь из результата.
+    negated: bool,
 }
 
 impl GlobOptions {
@@ -244,6 +248,7 @@ impl GlobOptions {
             backslash_escape: !is_separator('\\'),
             empty_alternates: false,
             allow_unclosed_class: false,
+            negated: false,
         }
     }
 }
@@ -310,6 +315,71 @@ impl Glob {
         &self.glob
     }
 
+    /// Возвращает исходный шаблон glob в виде строки.
+    ///
+    /// Это псевдоним для [`Glob::glob`], следующий соглашению об именовании
+    /// `as_str` из стандартной библиотеки.
+    pub fn as_str(&self) -> &str {
+        &self.glob
+    }
+
+    /// Возвращает true, если этот шаблон является отрицанием.
+    ///
+    /// См. [`GlobBuilder::negated`].
+    pub fn is_negated(&self) -> bool {
+        self.opts.negated
+    }
+
+    /// Возвращает грубую оценку стоимости сопоставления этого шаблона.
+    ///
+    /// Буквенные символы дешевле всего (1 за символ), подстановочные знаки
+    /// `?` и `*` — умеренно дороги, рекурсивный `**` — ещё дороже, а группы
+    /// альтернатив `{a,b,c}` умножают сложность своих ветвей. Это позволяет
+    /// вызывающему установить бюджет (например, отклонять шаблоны со
+    /// сложностью больше 1000) до оплаты стоимости компиляции регулярного
+    /// выражения.
+    ///
+    /// Конкретные значения не стабильны между версиями: полагаться можно
+    /// только на относительный порядок оценок.
+    pub fn complexity(&self) -> usize {
+        fn tokens_complexity(tokens: &[Token]) -> usize {
+            tokens.iter().map(token_complexity).sum()
+        }
+
+        fn token_complexity(token: &Token) -> usize {
+            match *token {
+                Token::Literal(_) => 1,
+                Token::Any => 5,
+                Token::ZeroOrMore => 10,
+                Token::RecursivePrefix
+                | Token::RecursiveSuffix
+                | Token::RecursiveZeroOrMore => 25,
+                Token::Class { ref ranges, .. } => 2 + ranges.len(),
+                Token::Alternates(ref branches) => branches
+                    .iter()
+                    .map(|tokens| tokens_complexity(tokens))
+                    .max()
+                    .unwrap_or(0)
+                    .saturating_mul(branches.len().max(1)),
+            }
+        }
+
+        tokens_complexity(&self.tokens)
+    }
+
+    /// Возвращает новый `Glob`, эквивалентный этому, но с включённым
+    /// регистронезависимым сопоставлением. Все остальные опции сохраняются.
+    pub(crate) fn to_case_insensitive(&self) -> Glob {
+        let mut opts = self.opts;
+        opts.case_insensitive = true;
+        Glob {
+            glob: self.glob.clone(),
+            re: self.tokens.to_regex_with(&opts),
+            opts,
+            tokens: self.tokens.clone(),
+        }
+    }
+
     /// Возвращает строку регулярного выражения для этого glob.
     ///
     /// Обратите внимание, что регулярные выражения для glob предназначены
@@ -667,6 +737,19 @@ impl<'a> GlobBuilder<'a> {
         self.opts.allow_unclosed_class = yes;
         self
     }
+
+    /// Переключает, является ли шаблон отрицанием.
+    ///
+    /// Отрицание не меняет то, чему соответствует сам шаблон. Однако когда
+    /// шаблон-отрицание входит в [`GlobSet`](crate::GlobSet) и срабатывает
+    /// для пути, он исключает этот путь из результата сопоставления набора
+    /// вместо того, чтобы добавлять его.
+    ///
+    /// По умолчанию это false.
+    pub fn negated(&mut self, yes: bool) -> &mut GlobBuilder<'a> {
+        self.opts.negated = yes;
+        self
+    }
 }
 
 impl Tokens {
@@ -1686,4 +1769,28 @@ mod tests {
     baseliteral!(extract_baselit2, "foo", None);
     baseliteral!(extract_baselit3, "*foo", None);
     baseliteral!(extract_baselit4, "*/foo", None);
+
+    #[test]
+    fn complexity() {
+        fn c(pat: &str) -> usize {
+            Glob::new(pat).unwrap().complexity()
+        }
+
+        // Точные значения не стабильны, поэтому проверяется только
+        // относительный порядок оценок.
+        assert!(c("foo") < c("foo?"));
+        assert!(c("foo?") < c("foo*"));
+        assert!(c("foo*") < c("**/foo"));
+        assert!(c("a") < c("[ab]"));
+        assert!(c("{a,b}") < c("{a,b,c,d}"));
+        assert!(c("foo") < c("{foo,foo,foo}"));
+    }
+
+    #[test]
+    fn as_str_and_display() {
+        let glob = Glob::new("src/**/*.rs").unwrap();
+        assert_eq!("src/**/*.rs", glob.as_str());
+        assert_eq!("src/**/*.rs", glob.to_string());
+        assert_eq!(r#"Glob("src/**/*.rs")"#, format!("{glob:?}"));
+    }
 }
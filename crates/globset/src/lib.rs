@@ -318,6 +318,8 @@ fn new_regex_set(pats: Vec<String>) -> Result<Regex, Error> {
 pub struct GlobSet {
     len: usize,
     strats: Vec<GlobSetMatchStrategy>,
+    globs: Vec<Glob>,
+    has_negated: bool,
 }
 
 impl GlobSet {
@@ -333,7 +335,7 @@ impl GlobSet {
     /// Создаёт пустой `GlobSet`. Пустой набор ничего не соответствует.
     #[inline]
     pub const fn empty() -> GlobSet {
-        GlobSet { len: 0, strats: vec![] }
+        GlobSet { len: 0, strats: vec![], globs: vec![], has_negated: false }
     }
 
     /// Возвращает true, если этот набор пуст и, следовательно, ничего не соответствует.
@@ -348,6 +350,27 @@ impl GlobSet {
         self.len
     }
 
+    /// Возвращает итератор по строкам шаблонов glob в этом наборе, в том
+    /// порядке, в котором они были добавлены.
+    ///
+    /// Это позволяет восстановить исходные шаблоны после построения набора,
+    /// например, для сериализации в файл конфигурации:
+    ///
+    /// ```
+    /// use globset::{Glob, GlobSet};
+    ///
+    /// let set = GlobSet::new(&[
+    ///     Glob::new("src/*").unwrap(),
+    ///     Glob::new("**/*.rs").unwrap(),
+    /// ]).unwrap();
+    ///
+    /// let patterns: Vec<&str> = set.patterns().collect();
+    /// assert_eq!(vec!["src/*", "**/*.rs"], patterns);
+    /// ```
+    pub fn patterns(&self) -> impl Iterator<Item = &str> {
+        self.globs.iter().map(|glob| glob.glob())
+    }
+
     /// Возвращает true, если какой-либо glob в этом наборе соответствует данному пути.
     pub fn is_match<P: AsRef<Path>>(&self, path: P) -> bool {
         self.is_match_candidate(&Candidate::new(path.as_ref()))
@@ -361,6 +384,11 @@ impl GlobSet {
         if self.is_empty() {
             return false;
         }
+        // При наличии отрицаний недостаточно узнать, что хоть один glob
+        // совпал: нужно полное сопоставление с учетом исключений.
+        if self.has_negated {
+            return !self.matches_candidate(path).is_empty();
+        }
         for strat in &self.strats {
             if strat.is_match(path) {
                 return true;
@@ -463,6 +491,128 @@ impl GlobSet {
         }
         into.sort();
         into.dedup();
+        if self.has_negated {
+            // Сработавшее отрицание исключает из результата все совпадения
+            // предшествующих ему glob. Поскольку индексы отсортированы по
+            // возрастанию, достаточно очистить накопленный результат.
+            let mut result = vec![];
+            for &i in into.iter() {
+                if self.globs[i].is_negated() {
+                    result.clear();
+                } else {
+                    result.push(i);
+                }
+            }
+            *into = result;
+        }
+    }
+
+    /// Возвращает порядковый номер и статус отрицания последнего шаблона
+    /// glob, который соответствует данному пути.
+    ///
+    /// Это полезно для семантики в стиле gitignore, где побеждает последнее
+    /// совпавшее правило: сработавшее отрицание переопределяет более раннее
+    /// положительное совпадение. В отличие от [`GlobSet::matches`], отрицания
+    /// здесь не исключаются из рассмотрения — вместо этого возвращается
+    /// последний сработавший glob вместе с его статусом отрицания, чтобы
+    /// вызывающий мог применить собственную логику приоритета:
+    ///
+    /// ```
+    /// use globset::{Glob, GlobBuilder, GlobSet};
+    ///
+    /// let set = GlobSet::new(&[
+    ///     Glob::new("*.rs").unwrap(),
+    ///     GlobBuilder::new("src/generated.rs")
+    ///         .negated(true)
+    ///         .build()
+    ///         .unwrap(),
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(Some((0, false)), set.matches_with_precedence("foo.rs"));
+    /// assert_eq!(
+    ///     Some((1, true)),
+    ///     set.matches_with_precedence("src/generated.rs"),
+    /// );
+    /// assert_eq!(None, set.matches_with_precedence("foo.c"));
+    /// ```
+    pub fn matches_with_precedence<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Option<(usize, bool)> {
+        self.matches_candidate_with_precedence(&Candidate::new(path.as_ref()))
+    }
+
+    /// Возвращает порядковый номер и статус отрицания последнего шаблона
+    /// glob, который соответствует данному пути.
+    ///
+    /// Это принимает Candidate в качестве входных данных, что можно
+    /// использовать для амортизации стоимости подготовки пути к
+    /// сопоставлению.
+    pub fn matches_candidate_with_precedence(
+        &self,
+        path: &Candidate<'_>,
+    ) -> Option<(usize, bool)> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut into = vec![];
+        for strat in &self.strats {
+            strat.matches_into(path, &mut into);
+        }
+        into.sort();
+        into.dedup();
+        let last = *into.last()?;
+        Some((last, self.globs[last].is_negated()))
+    }
+
+    /// Возвращает единое регулярное выражение, объединяющее все шаблоны
+    /// glob в этом наборе.
+    ///
+    /// Возвращаемая строка — это альтернация регулярных выражений отдельных
+    /// шаблонов в порядке их добавления. Это полезно для взаимодействия с
+    /// инструментами, которые хотят выполнить сопоставление собственным
+    /// движком регулярных выражений.
+    ///
+    /// Формат возвращаемой строки не гарантируется стабильным между
+    /// версиями: гарантируется только то, что она компилируется и
+    /// соответствует тем же путям, что и сам набор (без учета отрицаний).
+    pub fn to_combined_regex(&self) -> String {
+        let mut re = String::new();
+        for (i, glob) in self.globs.iter().enumerate() {
+            if i > 0 {
+                re.push('|');
+            }
+            re.push_str("(?:");
+            re.push_str(glob.regex());
+            re.push(')');
+        }
+        re
+    }
+
+    /// Строит новый matcher из коллекции строк шаблонов glob.
+    ///
+    /// Это удобная обёртка над [`GlobSet::new`], разбирающая каждую строку
+    /// через [`Glob::new`] с семантикой по умолчанию:
+    ///
+    /// ```
+    /// use globset::GlobSet;
+    ///
+    /// let set = GlobSet::from_patterns(["*.rs", "src/**"])?;
+    /// assert!(set.is_match("foo.rs"));
+    /// assert!(set.is_match("src/lib.c"));
+    /// assert!(!set.is_match("foo.c"));
+    /// # Ok::<(), globset::Error>(())
+    /// ```
+    pub fn from_patterns<I, S>(patterns: I) -> Result<GlobSet, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let globs = patterns
+            .into_iter()
+            .map(|pattern| Glob::new(pattern.as_ref()))
+            .collect::<Result<Vec<Glob>, Error>>()?;
+        GlobSet::new(&globs)
     }
 
     /// Строит новый matcher из коллекции шаблонов Glob.
@@ -473,8 +623,9 @@ impl GlobSet {
         I: IntoIterator<Item = G>,
         G: AsRef<Glob>,
     {
-        let mut it = globs.into_iter().peekable();
-        if it.peek().is_none() {
+        let globs: Vec<Glob> =
+            globs.into_iter().map(|g| g.as_ref().clone()).collect();
+        if globs.is_empty() {
             return Ok(GlobSet::empty());
         }
 
@@ -486,10 +637,9 @@ impl GlobSet {
         let mut suffixes = MultiStrategyBuilder::new();
         let mut required_exts = RequiredExtensionStrategyBuilder::new();
         let mut regexes = MultiStrategyBuilder::new();
-        for (i, p) in it.enumerate() {
+        for (i, p) in globs.iter().enumerate() {
             len += 1;
 
-            let p = p.as_ref();
             match MatchStrategy::new(p) {
                 MatchStrategy::Literal(lit) => {
                     lits.add(i, lit);
@@ -559,7 +709,134 @@ impl GlobSet {
             strats.push(GlobSetMatchStrategy::Regex(regexes.regex_set()?));
         }
 
-        Ok(GlobSet { len, strats })
+        let has_negated = globs.iter().any(|glob| glob.is_negated());
+        Ok(GlobSet { len, strats, globs, has_negated })
+    }
+
+    /// Перестраивает этот набор так, что каждый шаблон сопоставляется
+    /// регистронезависимо.
+    ///
+    /// Все остальные опции каждого шаблона сохраняются. Перестроенный набор
+    /// соответствует всем путям, которым соответствовал исходный набор, а
+    /// также путям, отличающимся от них только регистром.
+    ///
+    /// # Пример
+    ///
+    /// ```
+    /// use globset::{Glob, GlobSetBuilder};
+    ///
+    /// let mut builder = GlobSetBuilder::new();
+    /// builder.add(Glob::new("*.RS").unwrap());
+    /// let set = builder.build().unwrap().into_case_insensitive().unwrap();
+    ///
+    /// assert!(set.is_match("foo.rs"));
+    /// assert!(set.is_match("foo.RS"));
+    /// ```
+    pub fn into_case_insensitive(self) -> Result<GlobSet, Error> {
+        let globs: Vec<Glob> =
+            self.globs.iter().map(|g| g.to_case_insensitive()).collect();
+        GlobSet::new(&globs)
+    }
+
+    /// Возвращает разность этого набора и данного набора.
+    ///
+    /// Полученный matcher соответствует пути тогда и только тогда, когда
+    /// путь соответствует этому набору, но не соответствует `other`. Это
+    /// полезно, например, для списков разрешений с исключениями:
+    ///
+    /// ```
+    /// use globset::GlobSet;
+    ///
+    /// let allow = GlobSet::from_patterns(["*.rs"])?;
+    /// let deny = GlobSet::from_patterns(["*_test.rs"])?;
+    /// let set = allow.difference(&deny);
+    ///
+    /// assert!(set.is_match("foo.rs"));
+    /// assert!(!set.is_match("foo_test.rs"));
+    /// assert!(!set.is_match("foo.c"));
+    /// # Ok::<(), globset::Error>(())
+    /// ```
+    pub fn difference(self, other: &GlobSet) -> GlobSetDifference {
+        GlobSetDifference { include: self, exclude: other.clone() }
+    }
+
+    /// Возвращает пересечение этого набора и данного набора.
+    ///
+    /// Полученный matcher соответствует пути тогда и только тогда, когда
+    /// путь соответствует обоим наборам:
+    ///
+    /// ```
+    /// use globset::GlobSet;
+    ///
+    /// let rust = GlobSet::from_patterns(["*.rs"])?;
+    /// let tests = GlobSet::from_patterns(["*_test.*"])?;
+    /// let set = rust.intersection(&tests);
+    ///
+    /// assert!(set.is_match("foo_test.rs"));
+    /// assert!(!set.is_match("foo.rs"));
+    /// assert!(!set.is_match("foo_test.c"));
+    /// # Ok::<(), globset::Error>(())
+    /// ```
+    pub fn intersection(self, other: &GlobSet) -> GlobSetIntersection {
+        GlobSetIntersection { first: self, second: other.clone() }
+    }
+}
+
+/// Разность двух наборов glob, созданная через [`GlobSet::difference`].
+///
+/// Этот matcher является обёрткой над двумя наборами: объединить их в один
+/// `GlobSet` нельзя, поскольку отдельный шаблон не может выразить отрицание
+/// целого набора.
+#[derive(Clone, Debug)]
+pub struct GlobSetDifference {
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl GlobSetDifference {
+    /// Возвращает true, если данный путь соответствует включающему набору,
+    /// но не соответствует исключающему.
+    pub fn is_match<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.is_match_candidate(&Candidate::new(path.as_ref()))
+    }
+
+    /// Возвращает true, если данный путь соответствует включающему набору,
+    /// но не соответствует исключающему.
+    ///
+    /// Это принимает Candidate в качестве входных данных, что можно
+    /// использовать для амортизации стоимости подготовки пути к
+    /// сопоставлению.
+    pub fn is_match_candidate(&self, path: &Candidate<'_>) -> bool {
+        self.include.is_match_candidate(path)
+            && !self.exclude.is_match_candidate(path)
+    }
+}
+
+/// Пересечение двух наборов glob, созданное через [`GlobSet::intersection`].
+///
+/// Этот matcher является обёрткой над двумя наборами: объединить их в один
+/// `GlobSet` нельзя, поскольку `GlobSet` соответствует пути, если совпал
+/// хотя бы один шаблон, а не все.
+#[derive(Clone, Debug)]
+pub struct GlobSetIntersection {
+    first: GlobSet,
+    second: GlobSet,
+}
+
+impl GlobSetIntersection {
+    /// Возвращает true, если данный путь соответствует обоим наборам.
+    pub fn is_match<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.is_match_candidate(&Candidate::new(path.as_ref()))
+    }
+
+    /// Возвращает true, если данный путь соответствует обоим наборам.
+    ///
+    /// Это принимает Candidate в качестве входных данных, что можно
+    /// использовать для амортизации стоимости подготовки пути к
+    /// сопоставлению.
+    pub fn is_match_candidate(&self, path: &Candidate<'_>) -> bool {
+        self.first.is_match_candidate(path)
+            && self.second.is_match_candidate(path)
     }
 }
 
@@ -598,6 +875,49 @@ impl GlobSetBuilder {
         self.pats.push(pat);
         self
     }
+
+    /// Присоединяет все шаблоны другого построителя к этому.
+    ///
+    /// Шаблоны добавляются в конец в их исходном порядке, поэтому индексы,
+    /// сообщаемые [`GlobSet::matches`] для шаблонов этого построителя,
+    /// остаются неизменными, а шаблоны `other` получают последующие индексы.
+    pub fn merge(&mut self, other: GlobSetBuilder) -> &mut GlobSetBuilder {
+        self.pats.extend(other.pats);
+        self
+    }
+
+    /// Возвращает шаблоны, накопленные на данный момент, в порядке
+    /// добавления.
+    pub fn globs(&self) -> &[Glob] {
+        &self.pats
+    }
+}
+
+impl Extend<Glob> for GlobSetBuilder {
+    fn extend<I: IntoIterator<Item = Glob>>(&mut self, iter: I) {
+        self.pats.extend(iter);
+    }
+}
+
+impl FromIterator<Glob> for Result<GlobSet, Error> {
+    /// Собирает итератор glob непосредственно в построенный [`GlobSet`].
+    ///
+    /// Это эквивалентно добавлению каждого glob в [`GlobSetBuilder`] и
+    /// последующему вызову `build`:
+    ///
+    /// ```
+    /// use globset::{Glob, GlobSet};
+    ///
+    /// let globs = vec![Glob::new("*.rs").unwrap()];
+    /// let set: Result<GlobSet, _> = globs.into_iter().collect();
+    /// assert!(set.unwrap().is_match("foo.rs"));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = Glob>>(
+        iter: I,
+    ) -> Result<GlobSet, Error> {
+        let globs: Vec<Glob> = iter.into_iter().collect();
+        GlobSet::new(&globs)
+    }
 }
 
 /// Кандидат пути для сопоставления.
@@ -1062,9 +1382,52 @@ pub fn escape(s: &str) -> String {
     escaped
 }
 
+/// Убирает экранирование мета-символов в данном шаблоне glob.
+///
+/// Это обратная операция к [`escape`]: класс из одного мета-символа, такой
+/// как `[*]`, сворачивается обратно в сам мета-символ. Все остальные
+/// последовательности остаются без изменений, так что для любой строки `s`
+/// без классов символов выполняется `unescape(escape(s)) == s`.
+///
+/// # Пример
+///
+/// ```
+/// use globset::unescape;
+///
+/// assert_eq!(unescape("foo[*]bar"), "foo*bar");
+/// assert_eq!(unescape("foo[?]bar"), "foo?bar");
+/// assert_eq!(unescape("foo[[]bar"), "foo[bar");
+/// assert_eq!(unescape("foo[]]bar"), "foo]bar");
+/// // Обычные классы символов не трогаются.
+/// assert_eq!(unescape("foo[ab]bar"), "foo[ab]bar");
+/// ```
+pub fn unescape(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '[' {
+            unescaped.push(c);
+            continue;
+        }
+        // Сворачиваем только классы, которые создаёт `escape`: ровно один
+        // мета-символ в квадратных скобках.
+        let mut lookahead = chars.clone();
+        match (lookahead.next(), lookahead.next()) {
+            (Some(meta @ ('?' | '*' | '[' | ']' | '{' | '}')), Some(']')) => {
+                unescaped.push(meta);
+                chars = lookahead;
+            }
+            _ => {
+                unescaped.push(c);
+            }
+        }
+    }
+    unescaped
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::glob::Glob;
+    use crate::glob::{Glob, GlobBuilder};
 
     use super::{GlobSet, GlobSetBuilder};
 
@@ -1089,6 +1452,166 @@ mod tests {
         assert_eq!(2, matches[1]);
     }
 
+    #[test]
+    fn set_into_case_insensitive() {
+        let mut builder = GlobSetBuilder::new();
+        builder.add(Glob::new("*.RS").unwrap());
+        builder.add(Glob::new("src/**/*.C").unwrap());
+        let set = builder.build().unwrap();
+
+        assert!(!set.is_match("foo.rs"));
+        assert!(!set.is_match("src/foo.c"));
+
+        let set = set.into_case_insensitive().unwrap();
+        assert_eq!(2, set.len());
+        assert!(set.is_match("foo.rs"));
+        assert!(set.is_match("foo.RS"));
+        assert!(set.is_match("src/foo.c"));
+        assert!(set.is_match("SRC/FOO.C"));
+        assert!(!set.is_match("foo.toml"));
+    }
+
+    #[test]
+    fn set_patterns_round_trip() {
+        let mut builder = GlobSetBuilder::new();
+        builder.add(Glob::new("src/*").unwrap());
+        builder.add(Glob::new("**/*.rs").unwrap());
+        let set = builder.build().unwrap();
+
+        let patterns: Vec<&str> = set.patterns().collect();
+        assert_eq!(vec!["src/*", "**/*.rs"], patterns);
+
+        let globs: Vec<Glob> = set
+            .patterns()
+            .map(|pattern| Glob::new(pattern).unwrap())
+            .collect();
+        let rebuilt = GlobSet::new(&globs).unwrap();
+        assert_eq!(set.len(), rebuilt.len());
+        assert!(rebuilt.is_match("src/lib.c"));
+        assert!(rebuilt.is_match("a/b/main.rs"));
+        assert!(!rebuilt.is_match("a/b/main.c"));
+    }
+
+    #[test]
+    fn set_builder_extend() {
+        let mut builder = GlobSetBuilder::new();
+        builder.add(Glob::new("*.rs").unwrap());
+        builder.extend(vec![
+            Glob::new("*.c").unwrap(),
+            Glob::new("*.h").unwrap(),
+        ]);
+        let set = builder.build().unwrap();
+        assert_eq!(3, set.len());
+        assert!(set.is_match("foo.rs"));
+        assert!(set.is_match("foo.c"));
+        assert!(set.is_match("foo.h"));
+        assert!(!set.is_match("foo.toml"));
+
+        // Пустой итератор ничего не меняет.
+        let mut builder = GlobSetBuilder::new();
+        builder.extend(std::iter::empty());
+        assert!(builder.build().unwrap().is_empty());
+    }
+
+    #[test]
+    fn set_from_iterator() {
+        let globs =
+            vec![Glob::new("src/*").unwrap(), Glob::new("**/*.rs").unwrap()];
+        let set: Result<GlobSet, _> = globs.into_iter().collect();
+        let set = set.unwrap();
+        assert_eq!(2, set.len());
+        assert!(set.is_match("src/lib.c"));
+        assert!(set.is_match("a/b/main.rs"));
+        assert!(!set.is_match("a/b/main.c"));
+
+        let set: Result<GlobSet, _> = std::iter::empty().collect();
+        assert!(set.unwrap().is_empty());
+    }
+
+    #[test]
+    fn set_negated_globs() {
+        let mut builder = GlobSetBuilder::new();
+        builder.add(Glob::new("*.rs").unwrap());
+        builder.add(
+            GlobBuilder::new("src/generated.rs")
+                .negated(true)
+                .build()
+                .unwrap(),
+        );
+        let set = builder.build().unwrap();
+
+        assert!(set.is_match("foo.rs"));
+        assert!(set.is_match("src/lib.rs"));
+        assert!(!set.is_match("src/generated.rs"));
+
+        assert_eq!(vec![0], set.matches("foo.rs"));
+        assert!(set.matches("src/generated.rs").is_empty());
+    }
+
+    #[test]
+    fn set_matches_with_precedence() {
+        let mut builder = GlobSetBuilder::new();
+        builder.add(Glob::new("*.log").unwrap());
+        builder.add(
+            GlobBuilder::new("important.log").negated(true).build().unwrap(),
+        );
+        builder.add(Glob::new("trace.*").unwrap());
+        let set = builder.build().unwrap();
+
+        assert_eq!(Some((0, false)), set.matches_with_precedence("foo.log"));
+        assert_eq!(
+            Some((1, true)),
+            set.matches_with_precedence("important.log")
+        );
+        // Последнее совпавшее правило побеждает, даже после отрицания.
+        assert_eq!(Some((2, false)), set.matches_with_precedence("trace.log"));
+        assert_eq!(None, set.matches_with_precedence("foo.txt"));
+    }
+
+    #[test]
+    fn set_from_patterns() {
+        let set = GlobSet::from_patterns(["src/*", "**/*.rs"]).unwrap();
+        assert_eq!(2, set.len());
+        assert!(set.is_match("src/lib.c"));
+        assert!(set.is_match("a/b/main.rs"));
+        assert!(!set.is_match("a/b/main.c"));
+
+        let err = GlobSet::from_patterns(["a{b"]).unwrap_err();
+        assert_eq!(&crate::ErrorKind::UnclosedAlternates, err.kind());
+    }
+
+    #[test]
+    fn set_builder_merge() {
+        let mut base = GlobSetBuilder::new();
+        base.add(Glob::new("*.rs").unwrap());
+
+        let mut overlay = GlobSetBuilder::new();
+        overlay.add(Glob::new("*.c").unwrap());
+        overlay.add(Glob::new("*.h").unwrap());
+
+        base.merge(overlay);
+        assert_eq!(3, base.globs().len());
+        assert_eq!("*.c", base.globs()[1].glob());
+
+        let set = base.build().unwrap();
+        assert_eq!(vec![0], set.matches("foo.rs"));
+        assert_eq!(vec![1], set.matches("foo.c"));
+        assert_eq!(vec![2], set.matches("foo.h"));
+    }
+
+    #[test]
+    fn set_to_combined_regex() {
+        let mut builder = GlobSetBuilder::new();
+        builder.add(Glob::new("*.rs").unwrap());
+        builder.add(Glob::new("src/**").unwrap());
+        let set = builder.build().unwrap();
+
+        let re = crate::new_regex(&set.to_combined_regex()).unwrap();
+        assert!(re.is_match(b"foo.rs"));
+        assert!(re.is_match(b"src/lib.c"));
+        assert!(!re.is_match(b"foo.c"));
+    }
+
     #[test]
     fn empty_set_works() {
         let set = GlobSetBuilder::new().build().unwrap();
@@ -1116,6 +1639,28 @@ mod tests {
         assert_eq!("bar[[]!![]]!baz", escape("bar[!!]!baz"));
     }
 
+    #[test]
+    fn unescape() {
+        use super::{escape, unescape};
+
+        assert_eq!("foo", unescape("foo"));
+        assert_eq!("foo*", unescape("foo[*]"));
+        assert_eq!("[]", unescape("[[][]]"));
+        assert_eq!("*?", unescape("[*][?]"));
+        assert_eq!("src/**/*.rs", unescape("src/[*][*]/[*].rs"));
+        // Обычные классы символов остаются без изменений.
+        assert_eq!("bar[ab]baz", unescape("bar[ab]baz"));
+        assert_eq!("bar[!!]!baz", unescape("bar[!!]!baz"));
+        // Незакрытая скобка не трогается.
+        assert_eq!("foo[", unescape("foo["));
+        assert_eq!("foo[*", unescape("foo[*"));
+
+        // Прямой и обратный проход дают исходную строку.
+        for s in ["foo", "foo*", "[]", "*?", "src/**/*.rs", "a{b}c?d"] {
+            assert_eq!(s, unescape(&escape(s)));
+        }
+    }
+
     // This tests that regex matching doesn't "remember" the results of
     // previous searches. That is, if any memory is reused from a previous
     // search, then it should be cleared first.
@@ -1136,6 +1681,40 @@ mod tests {
         assert_eq!(0, matches.len());
     }
 
+    #[test]
+    fn set_difference() {
+        let allow = GlobSet::from_patterns(["*.rs", "*.c"]).unwrap();
+        let deny = GlobSet::from_patterns(["*_test.rs"]).unwrap();
+        let set = allow.difference(&deny);
+
+        assert!(set.is_match("foo.rs"));
+        assert!(set.is_match("foo.c"));
+        assert!(!set.is_match("foo_test.rs"));
+        assert!(!set.is_match("foo.toml"));
+
+        // Разность с пустым набором совпадает с исходным набором.
+        let allow = GlobSet::from_patterns(["*.rs"]).unwrap();
+        let set = allow.difference(&GlobSet::empty());
+        assert!(set.is_match("foo.rs"));
+        assert!(!set.is_match("foo.c"));
+    }
+
+    #[test]
+    fn set_intersection() {
+        let rust = GlobSet::from_patterns(["*.rs"]).unwrap();
+        let tests = GlobSet::from_patterns(["*_test.*"]).unwrap();
+        let set = rust.intersection(&tests);
+
+        assert!(set.is_match("foo_test.rs"));
+        assert!(!set.is_match("foo.rs"));
+        assert!(!set.is_match("foo_test.c"));
+
+        // Пересечение с пустым набором ничего не соответствует.
+        let rust = GlobSet::from_patterns(["*.rs"]).unwrap();
+        let set = rust.intersection(&GlobSet::empty());
+        assert!(!set.is_match("foo.rs"));
+    }
+
     #[test]
     fn debug() {
         let mut builder = GlobSetBuilder::new();
@@ -27,7 +27,7 @@ use crate::{
     overrides::{self, Override},
     pathutil::{is_hidden, strip_prefix},
     types::{self, Types},
-    walk::DirEntry,
+    walk::{DirEntry, IgnoreReason},
     {Error, Match, PartialErrorBuilder},
 };
 
@@ -64,6 +64,61 @@ impl<'a> IgnoreMatch<'a> {
     fn hidden() -> IgnoreMatch<'static> {
         IgnoreMatch(IgnoreMatchInner::Hidden)
     }
+
+    /// Returns an owned summary of this match suitable for handing to
+    /// caller-provided callbacks.
+    pub(crate) fn to_info(&self) -> IgnoreMatchInfo {
+        let glob = self.glob();
+        IgnoreMatchInfo {
+            source: glob.and_then(|g| g.from().map(|p| p.to_path_buf())),
+            pattern: glob.map(|g| g.original().to_string()),
+        }
+    }
+
+    /// Returns the ignore rule that produced this match, down to the ignore
+    /// file and line it was defined on, if that provenance is known.
+    pub(crate) fn to_reason(&self) -> Option<IgnoreReason> {
+        let glob = self.glob()?;
+        let path = glob.from()?.to_path_buf();
+        Some(IgnoreReason::new(path, glob.line(), glob.original().to_string()))
+    }
+
+    /// Returns the gitignore glob underlying this match, if one exists.
+    fn glob(&self) -> Option<&gitignore::Glob> {
+        match self.0 {
+            IgnoreMatchInner::Override(ref x) => x.inner(),
+            IgnoreMatchInner::Gitignore(x) => Some(x),
+            IgnoreMatchInner::Types(_) | IgnoreMatchInner::Hidden => None,
+        }
+    }
+}
+
+/// Информация о правиле игнорирования, из-за которого элемент каталога был
+/// пропущен при обходе.
+///
+/// Это владеющая сводка внутренней информации о совпадении, используемая
+/// в обратном вызове, зарегистрированном через
+/// [`WalkBuilder::on_ignore`](crate::WalkBuilder::on_ignore).
+#[derive(Clone, Debug)]
+pub struct IgnoreMatchInfo {
+    source: Option<PathBuf>,
+    pattern: Option<String>,
+}
+
+impl IgnoreMatchInfo {
+    /// Возвращает путь к файлу игнорирования, содержащему совпавшее правило,
+    /// если таковой существует. Правила, добавленные не из файла (например,
+    /// программно или при фильтрации скрытых файлов), не имеют источника.
+    pub fn source(&self) -> Option<&Path> {
+        self.source.as_deref()
+    }
+
+    /// Возвращает исходную строку шаблона совпавшего правила, если таковая
+    /// существует. Совпадения, вызванные фильтрацией скрытых файлов или
+    /// выбором типов файлов, не имеют шаблона.
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
 }
 
 /// Options for the ignore matcher, shared between the matcher itself and the
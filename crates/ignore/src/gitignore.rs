@@ -40,6 +40,9 @@ pub struct Glob {
     is_whitelist: bool,
     /// Whether this glob should only match directories or not.
     is_only_dir: bool,
+    /// The line number in the file that this glob was extracted from,
+    /// starting at 1, if known.
+    line: Option<u64>,
 }
 
 impl Glob {
@@ -53,6 +56,16 @@ impl Glob {
         &self.original
     }
 
+    /// Возвращает номер строки (начиная с 1), на которой этот glob был
+    /// определён в файле gitignore, если он известен.
+    ///
+    /// Номер строки известен только для glob, прочитанных из файла. Для glob,
+    /// добавленных программно (например, через `GitignoreBuilder::add_line`),
+    /// возвращается `None`.
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+
     /// Фактический glob, который был скомпилирован с учётом семантики gitignore.
     pub fn actual(&self) -> &str {
         &self.actual
@@ -177,6 +190,11 @@ impl Gitignore {
         self.num_whitelists
     }
 
+    /// Возвращает все glob в этом matcher в порядке их добавления.
+    pub(crate) fn globs(&self) -> &[Glob] {
+        &self.globs
+    }
+
     /// Возвращает, соответствует ли данный путь (файл или директория)
     /// шаблону в этом matcher gitignore.
     ///
@@ -416,7 +434,9 @@ impl GitignoreBuilder {
             let line =
                 if i == 0 { line.trim_start_matches(UTF8_BOM) } else { &line };
 
-            if let Err(err) = self.add_line(Some(path.to_path_buf()), &line) {
+            if let Err(err) =
+                self.add_line_at(Some(path.to_path_buf()), Some(lineno), &line)
+            {
                 errs.push(err.tagged(path, lineno));
             }
         }
@@ -425,18 +445,22 @@ impl GitignoreBuilder {
 
     /// Добавляет каждую строку glob из данной строки.
     ///
-    /// Если эта строка получена из конкретного файла `gitignore`, то его
-    /// путь должен быть предоставлен здесь.
+    /// Это полезно, когда содержимое gitignore доступно без физического
+    /// файла, например, когда оно получено по сети или из конфигурации
+    /// редактора. Если эта строка всё же получена из конкретного файла
+    /// `gitignore`, то его путь должен быть предоставлен здесь.
     ///
     /// Данная строка должна быть отформатирована как файл `gitignore`.
-    #[cfg(test)]
-    fn add_str(
+    ///
+    /// Если какая-либо строка не может быть разобрана как glob, то
+    /// возвращается ошибка, и никакие последующие строки не добавляются.
+    pub fn add_str(
         &mut self,
         from: Option<PathBuf>,
         gitignore: &str,
     ) -> Result<&mut GitignoreBuilder, Error> {
-        for line in gitignore.lines() {
-            self.add_line(from.clone(), line)?;
+        for (i, line) in gitignore.lines().enumerate() {
+            self.add_line_at(from.clone(), Some((i + 1) as u64), line)?;
         }
         Ok(self)
     }
@@ -450,6 +474,17 @@ impl GitignoreBuilder {
     pub fn add_line(
         &mut self,
         from: Option<PathBuf>,
+        line: &str,
+    ) -> Result<&mut GitignoreBuilder, Error> {
+        self.add_line_at(from, None, line)
+    }
+
+    /// Like `add_line`, but additionally records the line number (starting
+    /// at 1) that the glob came from, if known.
+    fn add_line_at(
+        &mut self,
+        from: Option<PathBuf>,
+        lineno: Option<u64>,
         mut line: &str,
     ) -> Result<&mut GitignoreBuilder, Error> {
         #![allow(deprecated)]
@@ -469,6 +504,7 @@ impl GitignoreBuilder {
             actual: String::new(),
             is_whitelist: false,
             is_only_dir: false,
+            line: lineno,
         };
         let mut is_absolute = false;
         if line.starts_with("\\!") || line.starts_with("\\#") {
@@ -849,4 +885,21 @@ mod tests {
     not_ignored!(cs2, ROOT, "*.html", "foo.HTML");
     not_ignored!(cs3, ROOT, "*.html", "foo.htm");
     not_ignored!(cs4, ROOT, "*.html", "foo.HTM");
+
+    #[test]
+    fn add_str_no_file_context() {
+        let mut builder = GitignoreBuilder::new(ROOT);
+        builder.add_str(None, "# comment\n!foo\nbar/").unwrap();
+        let gi = builder.build().unwrap();
+
+        assert!(gi.matched("foo", false).is_whitelist());
+        let m = gi.matched("bar", true);
+        assert!(m.is_ignore());
+        let glob = m.inner().unwrap();
+        assert!(glob.from().is_none());
+        assert_eq!(Some(3), glob.line());
+        assert_eq!("bar/", glob.original());
+        assert!(glob.is_only_dir());
+        assert!(!gi.matched("bar", false).is_ignore());
+    }
 }
@@ -49,9 +49,10 @@ for result in WalkBuilder::new("./").hidden(false).build() {
 
 use std::path::{Path, PathBuf};
 
+pub use crate::dir::IgnoreMatchInfo;
 pub use crate::walk::{
-    DirEntry, ParallelVisitor, ParallelVisitorBuilder, Walk, WalkBuilder,
-    WalkParallel, WalkState,
+    DirEntry, IgnoreReason, ParallelVisitor, ParallelVisitorBuilder,
+    TraversalOrder, Walk, WalkBuilder, WalkParallel, WalkState, WalkStats,
 };
 
 mod default_types;
@@ -169,6 +170,18 @@ impl Error {
         }
     }
 
+    /// Возвращает true, если эта ошибка вызвана циклом символических
+    /// ссылок, обнаруженным во время обхода каталога.
+    pub fn is_loop(&self) -> bool {
+        match *self {
+            Error::Loop { .. } => true,
+            Error::WithLineNumber { ref err, .. } => err.is_loop(),
+            Error::WithPath { ref err, .. } => err.is_loop(),
+            Error::WithDepth { ref err, .. } => err.is_loop(),
+            _ => false,
+        }
+    }
+
     /// Возвращает true, если эта ошибка является исключительно ошибкой I/O.
     pub fn is_io(&self) -> bool {
         match *self {
@@ -250,6 +263,44 @@ impl Error {
         }
     }
 
+    /// Проверяет исходную ошибку [`Error::Glob`], если она существует.
+    ///
+    /// Возвращает исходный glob (когда он доступен) и сообщение об ошибке.
+    /// Обёртки вроде [`Error::WithPath`] и [`Error::WithLineNumber`]
+    /// раскрываются так же, как в [`io_error`](Error::io_error).
+    /// [`None`] возвращается, если эта ошибка не вызвана разбором glob.
+    pub fn as_glob_error(&self) -> Option<(Option<&str>, &str)> {
+        match *self {
+            Error::Partial(ref errs) => {
+                if errs.len() == 1 {
+                    errs[0].as_glob_error()
+                } else {
+                    None
+                }
+            }
+            Error::WithLineNumber { ref err, .. } => err.as_glob_error(),
+            Error::WithPath { ref err, .. } => err.as_glob_error(),
+            Error::WithDepth { ref err, .. } => err.as_glob_error(),
+            Error::Glob { ref glob, ref err } => {
+                Some((glob.as_deref(), err))
+            }
+            _ => None,
+        }
+    }
+
+    /// Возвращает исходную [`std::io::Error`], если эта ошибка является
+    /// непосредственно [`Error::Io`].
+    ///
+    /// В отличие от [`io_error`](Error::io_error), этот метод не раскрывает
+    /// обёртки вроде [`Error::WithPath`]: для вложенных ошибок возвращается
+    /// [`None`].
+    pub fn as_io_error(&self) -> Option<&std::io::Error> {
+        match *self {
+            Error::Io(ref err) => Some(err),
+            _ => None,
+        }
+    }
+
     /// Возвращает глубину, связанную с рекурсивным обходом директории
     /// (если эта ошибка была сгенерирована рекурсивным итератором директорий).
     pub fn depth(&self) -> Option<usize> {
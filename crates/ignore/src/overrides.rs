@@ -41,6 +41,42 @@ impl<'a> Glob<'a> {
     fn unmatched() -> Glob<'a> {
         Glob(GlobInner::UnmatchedIgnore)
     }
+
+    /// Возвращает glob, который совпал, если таковой существует.
+    pub(crate) fn inner(&self) -> Option<&'a gitignore::Glob> {
+        match self.0 {
+            GlobInner::UnmatchedIgnore => None,
+            GlobInner::Matched(glob) => Some(glob),
+        }
+    }
+}
+
+/// Детали совпадения, возвращаемые [`Override::explain`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OverrideMatch {
+    pattern: String,
+    is_negated: bool,
+    index: usize,
+}
+
+impl OverrideMatch {
+    /// Возвращает исходную строку шаблона, как она была передана в
+    /// [`OverrideBuilder::add`].
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Возвращает true, если совпавший шаблон является отрицанием (начинается
+    /// с `!`), то есть игнорирует путь вместо добавления его в белый список.
+    pub fn is_negated(&self) -> bool {
+        self.is_negated
+    }
+
+    /// Возвращает порядковый номер совпавшего шаблона среди добавленных
+    /// в построитель (начиная с нуля).
+    pub fn index(&self) -> usize {
+        self.index
+    }
 }
 
 /// Управляет набором override, предоставленных явно конечным пользователем.
@@ -109,6 +145,40 @@ impl Override {
         }
         mat.map(move |giglob| Glob(GlobInner::Matched(giglob)))
     }
+
+    /// Возвращает детали glob с наивысшим приоритетом, соответствующего
+    /// данному пути, если таковой существует.
+    ///
+    /// Это полезно для диагностики того, почему шаблоны `--glob` работают
+    /// или не работают так, как ожидает пользователь. В отличие от
+    /// [`matched`](Override::matched), возвращаемое значение содержит
+    /// исходную строку шаблона и его порядковый номер.
+    ///
+    /// `None` возвращается, когда путь не соответствует ни одному glob.
+    /// Обратите внимание, что путь, игнорируемый только потому, что он не
+    /// соответствует ни одному glob белого списка, также даёт `None`, так
+    /// как в этом случае конкретного совпавшего glob нет.
+    pub fn explain<P: AsRef<Path>>(
+        &self,
+        path: P,
+        is_dir: bool,
+    ) -> Option<OverrideMatch> {
+        let giglob = match self.matched(path, is_dir) {
+            Match::None => return None,
+            Match::Ignore(glob) | Match::Whitelist(glob) => glob.inner()?,
+        };
+        let index = self
+            .0
+            .globs()
+            .iter()
+            .position(|g| std::ptr::eq(g, giglob))
+            .expect("matched glob must belong to this matcher");
+        Some(OverrideMatch {
+            pattern: giglob.original().to_string(),
+            is_negated: giglob.is_whitelist(),
+            index,
+        })
+    }
 }
 
 /// Строит matcher для набора glob override.
@@ -261,6 +331,30 @@ mod tests {
         assert!(ov.matched("src/foo", true).is_none());
     }
 
+    #[test]
+    fn explain() {
+        let ov = ov(&["*.foo", "!*.bar"]);
+
+        let mat = ov.explain("a.foo", false).unwrap();
+        assert_eq!("*.foo", mat.pattern());
+        assert!(!mat.is_negated());
+        assert_eq!(0, mat.index());
+
+        let mat = ov.explain("a.bar", false).unwrap();
+        assert_eq!("!*.bar", mat.pattern());
+        assert!(mat.is_negated());
+        assert_eq!(1, mat.index());
+
+        // Путь, игнорируемый лишь из-за несовпадения с белым списком,
+        // не имеет конкретного совпавшего glob.
+        assert!(ov.matched("a.rs", false).is_ignore());
+        assert!(ov.explain("a.rs", false).is_none());
+
+        // Пустой matcher ничего не объясняет.
+        let empty = super::Override::empty();
+        assert!(empty.explain("a.foo", false).is_none());
+    }
+
     #[test]
     fn absolute_path() {
         let ov = ov(&["!/bar"]);
@@ -84,7 +84,11 @@ assert!(matcher.matched("y.cpp", false).is_whitelist());
 ```
 */
 
-use std::{collections::HashMap, path::Path, sync::Arc};
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, OnceLock},
+};
 
 use {
     globset::{GlobBuilder, GlobSet, GlobSetBuilder},
@@ -405,10 +409,32 @@ impl TypesBuilder {
 
     /// Clear any file type definitions for the type name given.
     pub fn clear(&mut self, name: &str) -> &mut TypesBuilder {
+        self.remove(name)
+    }
+
+    /// Remove all globs registered for the file type given, un-registering
+    /// the type entirely.
+    ///
+    /// This is useful for replacing a default type definition: remove the
+    /// type and then `add` new globs under the same name. Building a matcher
+    /// with a selection for a removed type results in an error.
+    ///
+    /// This is a no-op if the given name is not a known file type.
+    pub fn remove(&mut self, name: &str) -> &mut TypesBuilder {
         self.types.remove(name);
         self
     }
 
+    /// Remove all file type definitions, resetting this builder to a blank
+    /// slate.
+    ///
+    /// Note that this does not undo selections made with `select` or
+    /// `negate`.
+    pub fn clear_all(&mut self) -> &mut TypesBuilder {
+        self.types.clear();
+        self
+    }
+
     /// Add a new file type definition. `name` can be arbitrary and `pat`
     /// should be a glob recognizing file paths belonging to the `name` type.
     ///
@@ -490,6 +516,34 @@ impl TypesBuilder {
         }
         self
     }
+
+    /// Returns a sorted list of the names of all built-in file types.
+    ///
+    /// These are the names of the type definitions added by `add_defaults`,
+    /// including aliases. This is useful for offering type name completion
+    /// without building a full `Types` matcher.
+    pub fn builtin_names() -> &'static [&'static str] {
+        static NAMES: OnceLock<Vec<&'static str>> = OnceLock::new();
+        NAMES.get_or_init(|| {
+            let mut names: Vec<&'static str> = DEFAULT_TYPES
+                .iter()
+                .flat_map(|&(names, _)| names.iter().copied())
+                .collect();
+            names.sort_unstable();
+            names
+        })
+    }
+
+    /// Returns the globs of the built-in file type with the given name.
+    ///
+    /// This returns `None` if the name (or alias) does not correspond to a
+    /// built-in file type.
+    pub fn builtin_globs(name: &str) -> Option<&'static [&'static str]> {
+        DEFAULT_TYPES
+            .iter()
+            .find(|&&(names, _)| names.contains(&name))
+            .map(|&(_, globs)| globs)
+    }
 }
 
 #[cfg(test)]
@@ -581,4 +635,48 @@ mod tests {
             assert_eq!(btypes.definitions(), original_defs);
         }
     }
+
+    #[test]
+    fn remove_builtin_type() {
+        let mut btypes = TypesBuilder::new();
+        btypes.add_defaults();
+        btypes.remove("ts");
+        btypes.select("ts");
+        match btypes.build() {
+            Err(crate::Error::UnrecognizedFileType(name)) => {
+                assert_eq!("ts", name);
+            }
+            result => {
+                panic!("expected unrecognized file type error, got {result:?}")
+            }
+        }
+    }
+
+    #[test]
+    fn clear_all_types() {
+        let mut btypes = TypesBuilder::new();
+        btypes.add_defaults();
+        btypes.clear_all();
+        assert!(btypes.definitions().is_empty());
+    }
+
+    #[test]
+    fn builtin_names_and_globs() {
+        let names = TypesBuilder::builtin_names();
+        assert!(!names.is_empty());
+        assert!(names.is_sorted());
+        assert!(names.contains(&"rust"));
+        // Aliases are included too.
+        assert!(names.contains(&"md"));
+        assert!(names.contains(&"markdown"));
+
+        let globs = TypesBuilder::builtin_globs("rust").unwrap();
+        assert!(globs.contains(&"*.rs"));
+        // An alias resolves to the same globs as its canonical name.
+        assert_eq!(
+            TypesBuilder::builtin_globs("md"),
+            TypesBuilder::builtin_globs("markdown"),
+        );
+        assert_eq!(None, TypesBuilder::builtin_globs("notatype"));
+    }
 }
@@ -1,11 +1,14 @@
 use std::{
     cmp::Ordering,
+    collections::VecDeque,
     ffi::OsStr,
     fs::{self, FileType, Metadata},
     io,
     path::{Path, PathBuf},
-    sync::atomic::{AtomicBool, AtomicUsize, Ordering as AtomicOrdering},
-    sync::{Arc, OnceLock},
+    sync::atomic::{
+        AtomicBool, AtomicU64, AtomicUsize, Ordering as AtomicOrdering,
+    },
+    sync::{Arc, Condvar, Mutex, OnceLock},
 };
 
 use {
@@ -16,7 +19,7 @@ use {
 
 use crate::{
     Error, PartialErrorBuilder,
-    dir::{Ignore, IgnoreBuilder},
+    dir::{Ignore, IgnoreBuilder, IgnoreMatchInfo},
     gitignore::GitignoreBuilder,
     overrides::Override,
     types::Types,
@@ -30,6 +33,7 @@ use crate::{
 pub struct DirEntry {
     dent: DirEntryInner,
     err: Option<Error>,
+    ignored: Option<IgnoreReason>,
 }
 
 impl DirEntry {
@@ -98,21 +102,74 @@ impl DirEntry {
         self.err.as_ref()
     }
 
+    /// Returns the ignore rule that caused this entry to be skipped, if one
+    /// exists.
+    ///
+    /// Entries yielded by a walk are, by definition, not ignored, so this
+    /// always returns `None` for them. It returns a reason only for entries
+    /// handed to the callback registered via [`WalkBuilder::on_ignore`], and
+    /// only when the matching rule came from an ignore file. (For example,
+    /// hidden file filtering has no corresponding rule.)
+    pub fn ignored_reason(&self) -> Option<&IgnoreReason> {
+        self.ignored.as_ref()
+    }
+
     /// Returns true if and only if this entry points to a directory.
     pub(crate) fn is_dir(&self) -> bool {
         self.dent.is_dir()
     }
 
     fn new_stdin() -> DirEntry {
-        DirEntry { dent: DirEntryInner::Stdin, err: None }
+        DirEntry { dent: DirEntryInner::Stdin, err: None, ignored: None }
     }
 
     fn new_walkdir(dent: walkdir::DirEntry, err: Option<Error>) -> DirEntry {
-        DirEntry { dent: DirEntryInner::Walkdir(dent), err }
+        DirEntry { dent: DirEntryInner::Walkdir(dent), err, ignored: None }
     }
 
     fn new_raw(dent: DirEntryRaw, err: Option<Error>) -> DirEntry {
-        DirEntry { dent: DirEntryInner::Raw(dent), err }
+        DirEntry { dent: DirEntryInner::Raw(dent), err, ignored: None }
+    }
+}
+
+/// The ignore rule that caused a directory entry to be skipped during a walk.
+///
+/// This identifies the rule all the way down to the ignore file and line it
+/// was defined on. It is reported by [`DirEntry::ignored_reason`] for entries
+/// handed to the callback registered via [`WalkBuilder::on_ignore`].
+#[derive(Clone, Debug)]
+pub struct IgnoreReason {
+    path: PathBuf,
+    line: Option<u64>,
+    pattern: String,
+}
+
+impl IgnoreReason {
+    pub(crate) fn new(
+        path: PathBuf,
+        line: Option<u64>,
+        pattern: String,
+    ) -> IgnoreReason {
+        IgnoreReason { path, line, pattern }
+    }
+
+    /// Returns the path of the ignore file containing the rule.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the line number (starting at 1) on which the rule was defined,
+    /// if known.
+    ///
+    /// Line numbers are known only for rules read from a file. Rules added
+    /// programmatically (e.g., via `GitignoreBuilder::add_line`) have none.
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+
+    /// Returns the original pattern string of the rule.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
     }
 }
 
@@ -486,12 +543,19 @@ pub struct WalkBuilder {
     max_depth: Option<usize>,
     min_depth: Option<usize>,
     max_filesize: Option<u64>,
+    min_filesize: Option<u64>,
     follow_links: bool,
     same_file_system: bool,
     sorter: Option<Sorter>,
     threads: usize,
+    max_open_files: Option<usize>,
     skip: Option<Arc<Handle>>,
     filter: Option<Filter>,
+    on_ignore: Option<OnIgnore>,
+    skip_vcs_dirs: bool,
+    max_results: Option<usize>,
+    traversal: TraversalOrder,
+    bind_mount_check: bool,
     /// The directory that gitignores should be interpreted relative to.
     ///
     /// Usually this is the directory containing the gitignore file. But in
@@ -515,6 +579,24 @@ enum Sorter {
 #[derive(Clone)]
 struct Filter(Arc<dyn Fn(&DirEntry) -> bool + Send + Sync + 'static>);
 
+/// The order in which a directory tree is traversed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TraversalOrder {
+    /// Descend into each directory completely before moving on to its
+    /// siblings. This is the default.
+    DepthFirst,
+    /// Yield all entries of a directory before descending into any of its
+    /// children, so shallower entries are always reported before deeper
+    /// ones.
+    BreadthFirst,
+}
+
+type OnIgnoreCallback =
+    dyn Fn(&DirEntry, &IgnoreMatchInfo) + Send + Sync + 'static;
+
+#[derive(Clone)]
+struct OnIgnore(Arc<OnIgnoreCallback>);
+
 impl std::fmt::Debug for WalkBuilder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("WalkBuilder")
@@ -523,12 +605,19 @@ impl std::fmt::Debug for WalkBuilder {
             .field("max_depth", &self.max_depth)
             .field("min_depth", &self.min_depth)
             .field("max_filesize", &self.max_filesize)
+            .field("min_filesize", &self.min_filesize)
             .field("follow_links", &self.follow_links)
             .field("same_file_system", &self.same_file_system)
             .field("sorter", &"<...>")
             .field("threads", &self.threads)
+            .field("max_open_files", &self.max_open_files)
             .field("skip", &self.skip)
             .field("filter", &"<...>")
+            .field("on_ignore", &"<...>")
+            .field("skip_vcs_dirs", &self.skip_vcs_dirs)
+            .field("max_results", &self.max_results)
+            .field("traversal", &self.traversal)
+            .field("bind_mount_check", &self.bind_mount_check)
             .field(
                 "global_gitignores_relative_to",
                 &self.global_gitignores_relative_to,
@@ -551,12 +640,19 @@ impl WalkBuilder {
             max_depth: None,
             min_depth: None,
             max_filesize: None,
+            min_filesize: None,
             follow_links: false,
             same_file_system: false,
             sorter: None,
             threads: 0,
+            max_open_files: None,
             skip: None,
             filter: None,
+            on_ignore: None,
+            skip_vcs_dirs: false,
+            max_results: None,
+            traversal: TraversalOrder::DepthFirst,
+            bind_mount_check: false,
             global_gitignores_relative_to: OnceLock::new(),
         }
     }
@@ -612,8 +708,23 @@ impl WalkBuilder {
             ig_root: ig_root.clone(),
             ig: ig_root.clone(),
             max_filesize: self.max_filesize,
+            min_filesize: self.min_filesize,
             skip: self.skip.clone(),
             filter: self.filter.clone(),
+            on_ignore: self.on_ignore.clone(),
+            skip_vcs_dirs: self.skip_vcs_dirs,
+            max_results: self.max_results,
+            num_results: 0,
+            traversal: self.traversal,
+            max_depth: self.max_depth,
+            min_depth: self.min_depth,
+            follow_links: self.follow_links,
+            same_file_system: self.same_file_system,
+            sorter: self.sorter.clone(),
+            bfs_queue: VecDeque::new(),
+            bfs_pending: VecDeque::new(),
+            stats: WalkStats::default(),
+            mounts: self.mounts(),
         }
     }
 
@@ -633,11 +744,18 @@ impl WalkBuilder {
             max_depth: self.max_depth,
             min_depth: self.min_depth,
             max_filesize: self.max_filesize,
+            min_filesize: self.min_filesize,
             follow_links: self.follow_links,
             same_file_system: self.same_file_system,
             threads: self.threads,
+            max_open_files: self.max_open_files,
             skip: self.skip.clone(),
             filter: self.filter.clone(),
+            on_ignore: self.on_ignore.clone(),
+            skip_vcs_dirs: self.skip_vcs_dirs,
+            max_results: self.max_results,
+            traversal: self.traversal,
+            mounts: self.mounts(),
         }
     }
 
@@ -691,6 +809,29 @@ impl WalkBuilder {
         self
     }
 
+    /// Whether to ignore files below the specified limit.
+    ///
+    /// Files whose size cannot be determined are never ignored by this
+    /// filter.
+    pub fn min_filesize(&mut self, filesize: Option<u64>) -> &mut WalkBuilder {
+        self.min_filesize = filesize;
+        self
+    }
+
+    /// The maximum total number of directory entries to yield.
+    ///
+    /// Once the limit is reached, traversal stops and no further entries are
+    /// yielded. Errors do not count towards the limit. Note that this is a
+    /// total count across the entire walk, and not a depth limit like
+    /// `max_depth`. In the parallel walker, the limit is shared across all
+    /// threads.
+    ///
+    /// By default, no limit is imposed.
+    pub fn max_results(&mut self, limit: Option<usize>) -> &mut WalkBuilder {
+        self.max_results = limit;
+        self
+    }
+
     /// The number of threads to use for traversal.
     ///
     /// Note that this only has an effect when using `build_parallel`.
@@ -702,6 +843,22 @@ impl WalkBuilder {
         self
     }
 
+    /// The maximum number of directory handles to keep open simultaneously.
+    ///
+    /// This is useful on systems with a low limit on the number of open file
+    /// descriptors (e.g., containers), where a parallel traversal with many
+    /// threads can otherwise fail with "too many open files" errors. A value
+    /// of `0` is treated as `1`, since traversal cannot make progress without
+    /// opening at least one directory.
+    ///
+    /// Note that this only has an effect when using `build_parallel`.
+    ///
+    /// The default, `None`, imposes no limit.
+    pub fn max_open_files(&mut self, n: Option<usize>) -> &mut WalkBuilder {
+        self.max_open_files = n;
+        self
+    }
+
     /// Add a global ignore file to the matcher.
     ///
     /// This has lower precedence than all other sources of ignore rules.
@@ -738,6 +895,31 @@ impl WalkBuilder {
         errs.into_error_option()
     }
 
+    /// Add global ignore rules from a string formatted as a `gitignore`
+    /// file.
+    ///
+    /// Like ignore files added via `WalkBuilder::add_ignore`, these rules
+    /// have lower precedence than all other sources of ignore rules. Rules
+    /// are interpreted relative to the first path given to this builder.
+    ///
+    /// # Errors
+    ///
+    /// If any line could not be parsed as a glob, then an error is returned
+    /// and none of the rules are added.
+    pub fn add_ignore_str(
+        &mut self,
+        content: &str,
+    ) -> Result<&mut WalkBuilder, Error> {
+        // OK: `WalkBuilder::new` guarantees at least one path.
+        let root = self.paths[0].clone();
+        let mut builder = GitignoreBuilder::new(root);
+        for line in content.lines() {
+            builder.add_line(None, line)?;
+        }
+        self.ig_builder.add_ignore(builder.build()?);
+        Ok(self)
+    }
+
     /// Add a custom ignore file name
     ///
     /// These ignore files have higher precedence than all other ignore files.
@@ -937,6 +1119,25 @@ impl WalkBuilder {
         self
     }
 
+    /// Do not descend into directories that are mount points.
+    ///
+    /// The `same_file_system` option compares device numbers, which on Linux
+    /// cannot detect bind mounts: a bind mount shares its device number with
+    /// the original mount. When this option is enabled, the set of mount
+    /// points is read from `/proc/mounts` once per walk, and directory
+    /// traversal refuses to cross any of them, even when device numbers
+    /// match. The mount point directory itself is still yielded.
+    ///
+    /// Note that mount points are compared by path, so this is only reliable
+    /// when walking absolute paths.
+    ///
+    /// This is only supported on Linux and has no effect on other platforms.
+    /// It is disabled by default.
+    pub fn bind_mount_check(&mut self, yes: bool) -> &mut WalkBuilder {
+        self.bind_mount_check = yes;
+        self
+    }
+
     /// Do not yield directory entries that are believed to correspond to
     /// stdout.
     ///
@@ -957,6 +1158,27 @@ impl WalkBuilder {
         self
     }
 
+    /// Set the order in which the directory tree is traversed.
+    ///
+    /// The default is `TraversalOrder::DepthFirst`, which descends into each
+    /// directory completely before moving on to its siblings.
+    /// `TraversalOrder::BreadthFirst` instead yields all entries of a
+    /// directory before descending into any of its children, so shallower
+    /// entries are always reported before deeper ones.
+    ///
+    /// Note that breadth first traversal tends to use more memory than depth
+    /// first traversal, since entire directory levels are kept in memory at
+    /// once. On wide directory trees with a lot of gitignores, it can also
+    /// be substantially slower.
+    ///
+    /// When traversing in parallel, this only changes how work is scheduled
+    /// within each thread; entries are still reported in a nondeterministic
+    /// order.
+    pub fn traversal(&mut self, order: TraversalOrder) -> &mut WalkBuilder {
+        self.traversal = order;
+        self
+    }
+
     /// Yields only entries which satisfy the given predicate and skips
     /// descending into directories that do not satisfy the given predicate.
     ///
@@ -967,14 +1189,56 @@ impl WalkBuilder {
     /// Note that the errors for reading entries that may not satisfy the
     /// predicate will still be yielded.
     ///
-    /// Note also that only one filter predicate can be applied to a
-    /// `WalkBuilder`. Calling this subsequent times overrides previous filter
-    /// predicates.
+    /// Note also that multiple filter predicates chain with AND semantics:
+    /// an entry is yielded only if every predicate installed via this
+    /// method returns true for it.
     pub fn filter_entry<P>(&mut self, filter: P) -> &mut WalkBuilder
     where
         P: Fn(&DirEntry) -> bool + Send + Sync + 'static,
     {
-        self.filter = Some(Filter(Arc::new(filter)));
+        self.filter = match self.filter.take() {
+            None => Some(Filter(Arc::new(filter))),
+            Some(Filter(prev)) => Some(Filter(Arc::new(move |dent| {
+                prev(dent) && filter(dent)
+            }))),
+        };
+        self
+    }
+
+    /// Register a callback that is invoked whenever a directory entry is
+    /// skipped because it matched an ignore rule.
+    ///
+    /// The callback receives the skipped entry along with information about
+    /// the rule that matched it, such as the path of the ignore file the
+    /// rule came from and the original pattern string. Entries skipped for
+    /// other reasons (e.g., `filter_entry` predicates or the maximum file
+    /// size limit) do not trigger the callback.
+    ///
+    /// When traversing in parallel, the callback may be invoked from
+    /// multiple threads simultaneously.
+    pub fn on_ignore<F>(&mut self, callback: F) -> &mut WalkBuilder
+    where
+        F: Fn(&DirEntry, &IgnoreMatchInfo) + Send + Sync + 'static,
+    {
+        self.on_ignore = Some(OnIgnore(Arc::new(callback)));
+        self
+    }
+
+    /// Skip directories containing metadata for version control systems.
+    ///
+    /// When enabled, directories named `.git`, `.hg`, `.svn`, `.bzr` or
+    /// `_darcs` are skipped entirely, regardless of any ignore rules. This
+    /// is a shorthand for adding override globs like `!.git` for each such
+    /// directory.
+    ///
+    /// Note that this is distinct from disabling VCS ignore files (e.g., via
+    /// `git_ignore`), which only controls whether such files are read. This
+    /// option controls whether the VCS metadata directories themselves are
+    /// traversed.
+    ///
+    /// This is disabled by default.
+    pub fn skip_vcs_dirs(&mut self, yes: bool) -> &mut WalkBuilder {
+        self.skip_vcs_dirs = yes;
         self
     }
 
@@ -999,6 +1263,24 @@ impl WalkBuilder {
         self
     }
 
+    /// Returns the set of system mount points if bind mount checking is
+    /// enabled and they could be read.
+    fn mounts(&self) -> Option<Arc<Vec<PathBuf>>> {
+        if !self.bind_mount_check {
+            return None;
+        }
+        match mount_points() {
+            Ok(mounts) => Some(Arc::new(mounts)),
+            Err(err) => {
+                log::debug!(
+                    "failed to read mount points \
+                     (bind mount boundaries will not be detected): {err}"
+                );
+                None
+            }
+        }
+    }
+
     /// Gets the currently configured CWD on this walk builder.
     ///
     /// This is "lazy." That is, we only ask for the CWD from the environment
@@ -1040,8 +1322,117 @@ pub struct Walk {
     ig_root: Ignore,
     ig: Ignore,
     max_filesize: Option<u64>,
+    min_filesize: Option<u64>,
     skip: Option<Arc<Handle>>,
     filter: Option<Filter>,
+    on_ignore: Option<OnIgnore>,
+    skip_vcs_dirs: bool,
+    max_results: Option<usize>,
+    num_results: usize,
+    traversal: TraversalOrder,
+    max_depth: Option<usize>,
+    min_depth: Option<usize>,
+    follow_links: bool,
+    same_file_system: bool,
+    sorter: Option<Sorter>,
+    bfs_queue: VecDeque<BfsWork>,
+    bfs_pending: VecDeque<Result<DirEntry, Error>>,
+    stats: WalkStats,
+    mounts: Option<Arc<Vec<PathBuf>>>,
+}
+
+/// Summary statistics for a completed walk.
+///
+/// These are reported by [`Walk::into_stats`] and
+/// [`WalkParallel::run_with_stats`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WalkStats {
+    files_visited: u64,
+    dirs_visited: u64,
+    ignored: u64,
+    errors: u64,
+    symlinks_followed: u64,
+    symlink_cycles_detected: u64,
+}
+
+impl WalkStats {
+    /// Returns the number of file entries yielded by the walk.
+    pub fn files_visited(&self) -> u64 {
+        self.files_visited
+    }
+
+    /// Returns the number of directory entries yielded by the walk.
+    pub fn dirs_visited(&self) -> u64 {
+        self.dirs_visited
+    }
+
+    /// Returns the number of entries skipped by the ignore matcher. This
+    /// includes hidden file filtering when it is enabled.
+    ///
+    /// Note that entries inside of an ignored directory are never visited,
+    /// so they are not counted here.
+    pub fn ignored(&self) -> u64 {
+        self.ignored
+    }
+
+    /// Returns the number of errors reported during the walk.
+    pub fn errors(&self) -> u64 {
+        self.errors
+    }
+
+    /// Returns the number of symbolic links resolved and followed during
+    /// the walk. This is always zero unless following symbolic links is
+    /// enabled.
+    pub fn symlinks_followed(&self) -> u64 {
+        self.symlinks_followed
+    }
+
+    /// Returns the number of symbolic link cycles that were detected and
+    /// skipped during the walk. Such cycles are also counted as errors.
+    pub fn symlink_cycles_detected(&self) -> u64 {
+        self.symlink_cycles_detected
+    }
+}
+
+/// Atomic counters for walk statistics, shared across all workers of a
+/// parallel walk.
+#[derive(Debug, Default)]
+struct WalkStatsAtomic {
+    files_visited: AtomicU64,
+    dirs_visited: AtomicU64,
+    ignored: AtomicU64,
+    errors: AtomicU64,
+    symlinks_followed: AtomicU64,
+    symlink_cycles_detected: AtomicU64,
+}
+
+impl WalkStatsAtomic {
+    /// Returns a point-in-time copy of these counters.
+    fn snapshot(&self) -> WalkStats {
+        WalkStats {
+            files_visited: self.files_visited.load(AtomicOrdering::Relaxed),
+            dirs_visited: self.dirs_visited.load(AtomicOrdering::Relaxed),
+            ignored: self.ignored.load(AtomicOrdering::Relaxed),
+            errors: self.errors.load(AtomicOrdering::Relaxed),
+            symlinks_followed: self
+                .symlinks_followed
+                .load(AtomicOrdering::Relaxed),
+            symlink_cycles_detected: self
+                .symlink_cycles_detected
+                .load(AtomicOrdering::Relaxed),
+        }
+    }
+}
+
+/// A directory pending expansion in a breadth first traversal.
+struct BfsWork {
+    /// The directory entry to expand.
+    dent: DirEntry,
+    /// The ignore matcher for the directory's parent.
+    ig: Ignore,
+    /// The device number of the root this directory came from, if one is
+    /// needed for enforcing `same_file_system`.
+    root_device: Option<u64>,
 }
 
 impl Walk {
@@ -1054,7 +1445,16 @@ impl Walk {
         WalkBuilder::new(path).build()
     }
 
-    fn skip_entry(&self, ent: &DirEntry) -> Result<bool, Error> {
+    /// Returns summary statistics for this walk.
+    ///
+    /// This is typically called after the iterator has been exhausted, i.e.,
+    /// after it yields `None`. The statistics only cover the portion of the
+    /// traversal that was actually driven.
+    pub fn into_stats(self) -> WalkStats {
+        self.stats
+    }
+
+    fn skip_entry(&mut self, ent: &DirEntry) -> Result<bool, Error> {
         if ent.depth() == 0 {
             return Ok(false);
         }
@@ -1067,7 +1467,12 @@ impl Walk {
         // when particular filesystem operations occurred. Users of this system
         // who ensured correct file-type filters were being used could still
         // get unnecessary file access resulting in large downloads.
-        if should_skip_entry(&self.ig, ent) {
+        if self.skip_vcs_dirs && ent.is_dir() && is_vcs_dir(ent.file_name())
+        {
+            return Ok(true);
+        }
+        if should_skip_entry(&self.ig, ent, self.on_ignore.as_ref()) {
+            self.stats.ignored += 1;
             return Ok(true);
         }
         if let Some(ref stdout) = self.skip {
@@ -1075,9 +1480,12 @@ impl Walk {
                 return Ok(true);
             }
         }
-        if self.max_filesize.is_some() && !ent.is_dir() {
+        if (self.max_filesize.is_some() || self.min_filesize.is_some())
+            && !ent.is_dir()
+        {
             return Ok(skip_filesize(
-                self.max_filesize.unwrap(),
+                self.max_filesize,
+                self.min_filesize,
                 ent.path(),
                 &ent.metadata().ok(),
             ));
@@ -1089,6 +1497,179 @@ impl Walk {
         }
         Ok(false)
     }
+
+    /// Returns true if the given path is a mount point that traversal should
+    /// not descend into.
+    fn crosses_mount_boundary(&self, path: &Path) -> bool {
+        self.mounts
+            .as_ref()
+            .map_or(false, |mounts| mounts.iter().any(|m| m == path))
+    }
+
+    /// Advances a breadth first traversal.
+    ///
+    /// This maintains a FIFO queue of directories pending expansion and a
+    /// queue of entries ready to be yielded. Expanding a directory pushes
+    /// all of its (non-skipped) entries on to the latter queue and its child
+    /// directories on to the former.
+    fn bfs_next(&mut self) -> Option<Result<DirEntry, Error>> {
+        loop {
+            if let Some(result) = self.bfs_pending.pop_front() {
+                return Some(result);
+            }
+            if let Some(work) = self.bfs_queue.pop_front() {
+                self.bfs_expand(work);
+                continue;
+            }
+            match self.its.next() {
+                None => return None,
+                Some((_, None)) => return Some(Ok(DirEntry::new_stdin())),
+                Some((path, Some(_))) => {
+                    if let Err(err) = self.bfs_push_root(path) {
+                        return Some(Err(err));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Starts a breadth first traversal of the given root path.
+    fn bfs_push_root(&mut self, path: PathBuf) -> Result<(), Error> {
+        let root_device = if !self.same_file_system {
+            None
+        } else {
+            match device_num(&path) {
+                Ok(root_device) => Some(root_device),
+                Err(err) => return Err(Error::Io(err).with_path(path)),
+            }
+        };
+        let follow = self.follow_links || path.is_file();
+        let dent = DirEntryRaw::from_path(0, path, follow)
+            .map(|dent| DirEntry::new_raw(dent, None))?;
+        let (ig, err) = self.ig_root.add_parents(dent.path());
+        self.ig = ig;
+        if let Some(err) = err {
+            self.bfs_pending.push_back(Err(err));
+        }
+        let is_dir = dent.is_dir();
+        if self.min_depth.map_or(true, |min| min == 0) {
+            self.bfs_pending.push_back(Ok(dent.clone()));
+        }
+        if is_dir && self.max_depth.map_or(true, |max| max > 0) {
+            let ig = self.ig.clone();
+            self.bfs_queue.push_back(BfsWork { dent, ig, root_device });
+        }
+        Ok(())
+    }
+
+    /// Expands a single directory in a breadth first traversal.
+    ///
+    /// Any errors encountered are queued for yielding instead of being
+    /// returned.
+    fn bfs_expand(&mut self, work: BfsWork) {
+        let (ig, err) = work.ig.add_child(work.dent.path());
+        self.ig = ig;
+        if let Some(err) = err {
+            self.bfs_pending.push_back(Err(err));
+        }
+        let readdir = match fs::read_dir(work.dent.path()) {
+            Ok(readdir) => readdir,
+            Err(err) => {
+                let err = Error::from(err)
+                    .with_path(work.dent.path())
+                    .with_depth(work.dent.depth());
+                self.bfs_pending.push_back(Err(err));
+                return;
+            }
+        };
+        let depth = work.dent.depth() + 1;
+        let mut entries = vec![];
+        for result in readdir {
+            let fs_dent = match result {
+                Ok(fs_dent) => fs_dent,
+                Err(err) => {
+                    let err = Error::from(err).with_depth(depth);
+                    self.bfs_pending.push_back(Err(err));
+                    continue;
+                }
+            };
+            let mut dent = match DirEntryRaw::from_entry(depth, &fs_dent) {
+                Ok(dent) => DirEntry::new_raw(dent, None),
+                Err(err) => {
+                    self.bfs_pending.push_back(Err(err));
+                    continue;
+                }
+            };
+            let is_symlink =
+                dent.file_type().map_or(false, |ft| ft.is_symlink());
+            if self.follow_links && is_symlink {
+                let path = dent.path().to_path_buf();
+                dent = match DirEntryRaw::from_path(depth, path, true) {
+                    Ok(dent) => DirEntry::new_raw(dent, None),
+                    Err(err) => {
+                        self.bfs_pending.push_back(Err(err));
+                        continue;
+                    }
+                };
+                if dent.is_dir() {
+                    if let Err(err) =
+                        check_symlink_loop(&self.ig, dent.path(), depth)
+                    {
+                        self.bfs_pending.push_back(Err(err));
+                        continue;
+                    }
+                }
+            }
+            entries.push(dent);
+        }
+        if let Some(ref sorter) = self.sorter {
+            match sorter {
+                Sorter::ByName(cmp) => {
+                    entries.sort_by(|a, b| cmp(a.file_name(), b.file_name()));
+                }
+                Sorter::ByPath(cmp) => {
+                    entries.sort_by(|a, b| cmp(a.path(), b.path()));
+                }
+            }
+        }
+        for dent in entries {
+            let should_skip = match self.skip_entry(&dent) {
+                Err(err) => {
+                    self.bfs_pending.push_back(Err(err));
+                    continue;
+                }
+                Ok(should_skip) => should_skip,
+            };
+            if should_skip {
+                continue;
+            }
+            let descend = if !dent.is_dir() {
+                false
+            } else if self.crosses_mount_boundary(dent.path()) {
+                false
+            } else if let Some(root_device) = work.root_device {
+                match is_same_file_system(root_device, dent.path()) {
+                    Ok(descend) => descend,
+                    Err(err) => {
+                        self.bfs_pending.push_back(Err(err));
+                        continue;
+                    }
+                }
+            } else {
+                true
+            };
+            if self.min_depth.map_or(true, |min| depth >= min) {
+                self.bfs_pending.push_back(Ok(dent.clone()));
+            }
+            if descend && self.max_depth.map_or(true, |max| depth < max) {
+                self.bfs_queue.push_back(BfsWork {
+                    dent,
+                    ig: self.ig.clone(),
+                    root_device: work.root_device,
+                });
+            }
+        }
+    }
 }
 
 impl Iterator for Walk {
@@ -1096,6 +1677,43 @@ impl Iterator for Walk {
 
     #[inline(always)]
     fn next(&mut self) -> Option<Result<DirEntry, Error>> {
+        let result = self.next_impl();
+        match result {
+            Some(Ok(ref dent)) => {
+                if self.follow_links && dent.path_is_symlink() {
+                    self.stats.symlinks_followed += 1;
+                }
+                if dent.is_dir() {
+                    self.stats.dirs_visited += 1;
+                } else {
+                    self.stats.files_visited += 1;
+                }
+            }
+            Some(Err(ref err)) => {
+                if err.is_loop() {
+                    self.stats.symlink_cycles_detected += 1;
+                }
+                self.stats.errors += 1;
+            }
+            None => {}
+        }
+        result
+    }
+}
+
+impl Walk {
+    /// Advances the iterator without updating any statistics.
+    fn next_impl(&mut self) -> Option<Result<DirEntry, Error>> {
+        if self.max_results.map_or(false, |max| self.num_results >= max) {
+            return None;
+        }
+        if let TraversalOrder::BreadthFirst = self.traversal {
+            let result = self.bfs_next();
+            if let Some(Ok(_)) = result {
+                self.num_results += 1;
+            }
+            return result;
+        }
         loop {
             let ev = match self.it.as_mut().and_then(|it| it.next()) {
                 Some(ev) => ev,
@@ -1103,6 +1721,7 @@ impl Iterator for Walk {
                     match self.its.next() {
                         None => return None,
                         Some((_, None)) => {
+                            self.num_results += 1;
                             return Some(Ok(DirEntry::new_stdin()));
                         }
                         Some((path, Some(it))) => {
@@ -1143,9 +1762,15 @@ impl Iterator for Walk {
                         self.ig = igtmp;
                         continue;
                     }
+                    if ent.depth() > 0
+                        && self.crosses_mount_boundary(ent.path())
+                    {
+                        self.it.as_mut().unwrap().it.skip_current_dir();
+                    }
                     let (igtmp, err) = self.ig.add_child(ent.path());
                     self.ig = igtmp;
                     ent.err = err;
+                    self.num_results += 1;
                     return Some(Ok(ent));
                 }
                 Ok(WalkEvent::File(ent)) => {
@@ -1157,6 +1782,7 @@ impl Iterator for Walk {
                     if should_skip {
                         continue;
                     }
+                    self.num_results += 1;
                     return Some(Ok(ent));
                 }
             }
@@ -1315,13 +1941,20 @@ pub struct WalkParallel {
     paths: std::vec::IntoIter<PathBuf>,
     ig_root: Ignore,
     max_filesize: Option<u64>,
+    min_filesize: Option<u64>,
     max_depth: Option<usize>,
     min_depth: Option<usize>,
     follow_links: bool,
     same_file_system: bool,
     threads: usize,
+    max_open_files: Option<usize>,
     skip: Option<Arc<Handle>>,
     filter: Option<Filter>,
+    on_ignore: Option<OnIgnore>,
+    skip_vcs_dirs: bool,
+    max_results: Option<usize>,
+    traversal: TraversalOrder,
+    mounts: Option<Arc<Vec<PathBuf>>>,
 }
 
 impl WalkParallel {
@@ -1335,6 +1968,15 @@ impl WalkParallel {
         self.visit(&mut FnBuilder { builder: mkf })
     }
 
+    /// Like `run`, but additionally returns summary statistics for the walk
+    /// once it has finished.
+    pub fn run_with_stats<'s, F>(self, mkf: F) -> WalkStats
+    where
+        F: FnMut() -> FnVisitor<'s>,
+    {
+        self.visit_with_stats(&mut FnBuilder { builder: mkf })
+    }
+
     /// Execute the parallel recursive directory iterator using a custom
     /// visitor.
     ///
@@ -1352,8 +1994,18 @@ impl WalkParallel {
     /// visitor runs on only one thread, this build-up can be done without
     /// synchronization. Then, once traversal is complete, all of the results
     /// can be merged together into a single data structure.
-    pub fn visit(mut self, builder: &mut dyn ParallelVisitorBuilder<'_>) {
+    pub fn visit(self, builder: &mut dyn ParallelVisitorBuilder<'_>) {
+        self.visit_with_stats(builder);
+    }
+
+    /// Like `visit`, but additionally returns summary statistics for the
+    /// walk once it has finished.
+    pub fn visit_with_stats(
+        mut self,
+        builder: &mut dyn ParallelVisitorBuilder<'_>,
+    ) -> WalkStats {
         let threads = self.threads();
+        let stats = Arc::new(WalkStatsAtomic::default());
         let mut stack = vec![];
         {
             let mut visitor = builder.build();
@@ -1373,8 +2025,11 @@ impl WalkParallel {
                             Ok(root_device) => Some(root_device),
                             Err(err) => {
                                 let err = Error::Io(err).with_path(path);
+                                stats
+                                    .errors
+                                    .fetch_add(1, AtomicOrdering::Relaxed);
                                 if visitor.visit(Err(err)).is_quit() {
-                                    return;
+                                    return stats.snapshot();
                                 }
                                 continue;
                             }
@@ -1385,8 +2040,9 @@ impl WalkParallel {
                             (DirEntry::new_raw(dent, None), root_device)
                         }
                         Err(err) => {
+                            stats.errors.fetch_add(1, AtomicOrdering::Relaxed);
                             if visitor.visit(Err(err)).is_quit() {
-                                return;
+                                return stats.snapshot();
                             }
                             continue;
                         }
@@ -1400,13 +2056,18 @@ impl WalkParallel {
             }
             // ... but there's no need to start workers if we don't need them.
             if stack.is_empty() {
-                return;
+                return stats.snapshot();
             }
         }
         // Create the workers and then wait for them to finish.
         let quit_now = Arc::new(AtomicBool::new(false));
         let active_workers = Arc::new(AtomicUsize::new(threads));
-        let stacks = Stack::new_for_each_thread(threads, stack);
+        let num_results = Arc::new(AtomicUsize::new(0));
+        let open_dirs = self
+            .max_open_files
+            .map(|n| Arc::new(Semaphore::new(n.max(1))));
+        let stacks =
+            Stack::new_for_each_thread(threads, stack, self.traversal);
         std::thread::scope(|s| {
             let handles: Vec<_> = stacks
                 .into_iter()
@@ -1418,9 +2079,17 @@ impl WalkParallel {
                     max_depth: self.max_depth,
                     min_depth: self.min_depth,
                     max_filesize: self.max_filesize,
+            min_filesize: self.min_filesize,
                     follow_links: self.follow_links,
                     skip: self.skip.clone(),
                     filter: self.filter.clone(),
+                    on_ignore: self.on_ignore.clone(),
+                    skip_vcs_dirs: self.skip_vcs_dirs,
+                    max_results: self.max_results,
+                    num_results: num_results.clone(),
+                    stats: stats.clone(),
+                    mounts: self.mounts.clone(),
+                    open_dirs: open_dirs.clone(),
                 })
                 .map(|worker| s.spawn(|| worker.run()))
                 .collect();
@@ -1428,6 +2097,7 @@ impl WalkParallel {
                 handle.join().unwrap();
             }
         });
+        stats.snapshot()
     }
 
     fn threads(&self) -> usize {
@@ -1528,13 +2198,22 @@ impl Stack {
     /// Create a work-stealing stack for each thread. The given messages
     /// correspond to the initial paths to start the search at. They will
     /// be distributed automatically to each stack in a round-robin fashion.
-    fn new_for_each_thread(threads: usize, init: Vec<Message>) -> Vec<Stack> {
-        // Using new_lifo() ensures each worker operates depth-first, not
-        // breadth-first. We do depth-first because a breadth first traversal
-        // on wide directories with a lot of gitignores is disastrous (for
-        // example, searching a directory tree containing all of crates.io).
+    fn new_for_each_thread(
+        threads: usize,
+        init: Vec<Message>,
+        order: TraversalOrder,
+    ) -> Vec<Stack> {
+        // Using new_lifo() makes each worker operate depth-first, while
+        // new_fifo() makes it operate breadth-first. We do depth-first by
+        // default because a breadth first traversal on wide directories with
+        // a lot of gitignores is disastrous (for example, searching a
+        // directory tree containing all of crates.io).
+        let new_deque = match order {
+            TraversalOrder::DepthFirst => Deque::new_lifo,
+            TraversalOrder::BreadthFirst => Deque::new_fifo,
+        };
         let deques: Vec<Deque<Message>> =
-            std::iter::repeat_with(Deque::new_lifo).take(threads).collect();
+            std::iter::repeat_with(new_deque).take(threads).collect();
         let stealers = Arc::<[Stealer<Message>]>::from(
             deques.iter().map(Deque::stealer).collect::<Vec<_>>(),
         );
@@ -1583,6 +2262,47 @@ impl Stack {
     }
 }
 
+/// A simple counting semaphore built on a mutex and a condition variable.
+///
+/// This is used to bound the number of simultaneously open directory handles
+/// across all workers. Each worker holds at most one permit at a time and
+/// never blocks while holding one, so the traversal cannot deadlock as long
+/// as at least one permit exists.
+#[derive(Debug)]
+struct Semaphore {
+    permits: Mutex<usize>,
+    cond: Condvar,
+}
+
+impl Semaphore {
+    /// Create a new semaphore with the given number of permits.
+    fn new(permits: usize) -> Semaphore {
+        Semaphore { permits: Mutex::new(permits), cond: Condvar::new() }
+    }
+
+    /// Block until a permit is available and take it. The permit is returned
+    /// to the semaphore when the guard is dropped.
+    fn acquire(&self) -> SemaphoreGuard<'_> {
+        let mut permits = self.permits.lock().unwrap();
+        while *permits == 0 {
+            permits = self.cond.wait(permits).unwrap();
+        }
+        *permits -= 1;
+        SemaphoreGuard(self)
+    }
+}
+
+/// A permit acquired from a `Semaphore`, released on drop.
+#[derive(Debug)]
+struct SemaphoreGuard<'a>(&'a Semaphore);
+
+impl<'a> Drop for SemaphoreGuard<'a> {
+    fn drop(&mut self) {
+        *self.0.permits.lock().unwrap() += 1;
+        self.0.cond.notify_one();
+    }
+}
+
 /// A worker is responsible for descending into directories, updating the
 /// ignore matchers, producing new work and invoking the caller's callback.
 ///
@@ -1611,6 +2331,7 @@ struct Worker<'s> {
     /// The maximum size a searched file can be (in bytes). If a file exceeds
     /// this size it will be skipped.
     max_filesize: Option<u64>,
+    min_filesize: Option<u64>,
     /// Whether to follow symbolic links or not. When this is enabled, loop
     /// detection is performed.
     follow_links: bool,
@@ -1620,6 +2341,21 @@ struct Worker<'s> {
     /// A predicate applied to dir entries. If true, the entry and all
     /// children will be skipped.
     filter: Option<Filter>,
+    /// A callback invoked for entries skipped by ignore rules.
+    on_ignore: Option<OnIgnore>,
+    /// Whether to skip VCS metadata directories (e.g., `.git`) entirely.
+    skip_vcs_dirs: bool,
+    /// The maximum total number of entries to yield across all workers.
+    max_results: Option<usize>,
+    /// The number of entries yielded so far, shared across all workers.
+    num_results: Arc<AtomicUsize>,
+    /// Summary statistics, shared across all workers.
+    stats: Arc<WalkStatsAtomic>,
+    /// The set of system mount points, if bind mount checking is enabled.
+    mounts: Option<Arc<Vec<PathBuf>>>,
+    /// A semaphore limiting the number of simultaneously open directory
+    /// handles across all workers, if a limit was requested.
+    open_dirs: Option<Arc<Semaphore>>,
 }
 
 impl<'s> Worker<'s> {
@@ -1645,24 +2381,28 @@ impl<'s> Worker<'s> {
         // caller's callback immediately and move on.
         if work.is_symlink() || !work.is_dir() {
             return if should_visit {
-                self.visitor.visit(Ok(work.dent))
+                self.visit_ok(work.dent)
             } else {
                 WalkState::Continue
             };
         }
         if let Some(err) = work.add_parents() {
-            let state = self.visitor.visit(Err(err));
+            let state = self.visit_err(err);
             if state.is_quit() {
                 return state;
             }
         }
 
-        let descend = if let Some(root_device) = work.root_device {
+        let descend = if work.dent.depth() > 0
+            && self.crosses_mount_boundary(work.dent.path())
+        {
+            false
+        } else if let Some(root_device) = work.root_device {
             match is_same_file_system(root_device, work.dent.path()) {
                 Ok(true) => true,
                 Ok(false) => false,
                 Err(err) => {
-                    let state = self.visitor.visit(Err(err));
+                    let state = self.visit_err(err);
                     if state.is_quit() {
                         return state;
                     }
@@ -1673,6 +2413,13 @@ impl<'s> Worker<'s> {
             true
         };
 
+        // When a limit on open directory handles is in effect, hold a permit
+        // for as long as the handle returned by `read_dir` below is alive.
+        // The guard is declared before `readdir` so that it is dropped after
+        // the handle is closed. The `Arc` is cloned into a local so that the
+        // guard does not borrow `self`.
+        let open_dirs = self.open_dirs.clone();
+        let _permit = open_dirs.as_ref().map(|sem| sem.acquire());
         // Try to read the directory first before we transfer ownership
         // to the provided closure. Do not unwrap it immediately, though,
         // as we may receive an `Err` value e.g. in the case when we do not
@@ -1682,7 +2429,7 @@ impl<'s> Worker<'s> {
         let readdir = work.read_dir();
         let depth = work.dent.depth();
         if should_visit {
-            let state = self.visitor.visit(Ok(work.dent));
+            let state = self.visit_ok(work.dent);
             if !state.is_continue() {
                 return state;
             }
@@ -1694,7 +2441,7 @@ impl<'s> Worker<'s> {
         let readdir = match readdir {
             Ok(readdir) => readdir,
             Err(err) => {
-                return self.visitor.visit(Err(err));
+                return self.visit_err(err);
             }
         };
 
@@ -1715,6 +2462,51 @@ impl<'s> Worker<'s> {
         WalkState::Continue
     }
 
+    /// Visits a successful directory entry, enforcing the result limit.
+    ///
+    /// If the limit on the total number of yielded entries has already been
+    /// reached, then the entry is dropped and the traversal quits.
+    fn visit_ok(&mut self, dent: DirEntry) -> WalkState {
+        if let Some(max) = self.max_results {
+            let yielded =
+                self.num_results.fetch_add(1, AtomicOrdering::SeqCst);
+            if yielded >= max {
+                return WalkState::Quit;
+            }
+        }
+        if self.follow_links && dent.path_is_symlink() {
+            self.stats
+                .symlinks_followed
+                .fetch_add(1, AtomicOrdering::Relaxed);
+        }
+        if dent.is_dir() {
+            self.stats.dirs_visited.fetch_add(1, AtomicOrdering::Relaxed);
+        } else {
+            self.stats.files_visited.fetch_add(1, AtomicOrdering::Relaxed);
+        }
+        self.visitor.visit(Ok(dent))
+    }
+
+    /// Returns true if the given path is a mount point that traversal should
+    /// not descend into.
+    fn crosses_mount_boundary(&self, path: &Path) -> bool {
+        self.mounts
+            .as_ref()
+            .map_or(false, |mounts| mounts.iter().any(|m| m == path))
+    }
+
+    /// Reports the given error to the caller's callback, counting it in the
+    /// walk statistics.
+    fn visit_err(&mut self, err: Error) -> WalkState {
+        if err.is_loop() {
+            self.stats
+                .symlink_cycles_detected
+                .fetch_add(1, AtomicOrdering::Relaxed);
+        }
+        self.stats.errors.fetch_add(1, AtomicOrdering::Relaxed);
+        self.visitor.visit(Err(err))
+    }
+
     /// Decides whether to submit the given directory entry as a file to
     /// search.
     ///
@@ -1746,7 +2538,7 @@ impl<'s> Worker<'s> {
         let mut dent = match DirEntryRaw::from_entry(depth, &fs_dent) {
             Ok(dent) => DirEntry::new_raw(dent, None),
             Err(err) => {
-                return self.visitor.visit(Err(err));
+                return self.visit_err(err);
             }
         };
         let is_symlink = dent.file_type().map_or(false, |ft| ft.is_symlink());
@@ -1755,39 +2547,47 @@ impl<'s> Worker<'s> {
             dent = match DirEntryRaw::from_path(depth, path, true) {
                 Ok(dent) => DirEntry::new_raw(dent, None),
                 Err(err) => {
-                    return self.visitor.visit(Err(err));
+                    return self.visit_err(err);
                 }
             };
             if dent.is_dir() {
                 if let Err(err) = check_symlink_loop(ig, dent.path(), depth) {
-                    return self.visitor.visit(Err(err));
+                    return self.visit_err(err);
                 }
             }
         }
         // N.B. See analogous call in the single-threaded implementation about
         // why it's important for this to come before the checks below.
-        if should_skip_entry(ig, &dent) {
+        if self.skip_vcs_dirs && dent.is_dir() && is_vcs_dir(dent.file_name())
+        {
+            return WalkState::Continue;
+        }
+        if should_skip_entry(ig, &dent, self.on_ignore.as_ref()) {
+            self.stats.ignored.fetch_add(1, AtomicOrdering::Relaxed);
             return WalkState::Continue;
         }
         if let Some(ref stdout) = self.skip {
             let is_stdout = match path_equals(&dent, stdout) {
                 Ok(is_stdout) => is_stdout,
-                Err(err) => return self.visitor.visit(Err(err)),
+                Err(err) => return self.visit_err(err),
             };
             if is_stdout {
                 return WalkState::Continue;
             }
         }
-        let should_skip_filesize =
-            if self.max_filesize.is_some() && !dent.is_dir() {
-                skip_filesize(
-                    self.max_filesize.unwrap(),
-                    dent.path(),
-                    &dent.metadata().ok(),
-                )
-            } else {
-                false
-            };
+        let should_skip_filesize = if (self.max_filesize.is_some()
+            || self.min_filesize.is_some())
+            && !dent.is_dir()
+        {
+            skip_filesize(
+                self.max_filesize,
+                self.min_filesize,
+                dent.path(),
+                &dent.metadata().ok(),
+            )
+        } else {
+            false
+        };
         let should_skip_filtered =
             if let Some(Filter(predicate)) = &self.filter {
                 !predicate(&dent)
@@ -1915,7 +2715,8 @@ fn check_symlink_loop(
 // Before calling this function, make sure that you ensure that is really
 // necessary as the arguments imply a file stat.
 fn skip_filesize(
-    max_filesize: u64,
+    max_filesize: Option<u64>,
+    min_filesize: Option<u64>,
     path: &Path,
     ent: &Option<Metadata>,
 ) -> bool {
@@ -1925,7 +2726,9 @@ fn skip_filesize(
     };
 
     if let Some(fs) = filesize {
-        if fs > max_filesize {
+        if max_filesize.map_or(false, |max| fs > max)
+            || min_filesize.map_or(false, |min| fs < min)
+        {
             log::debug!("ignoring {}: {} bytes", path.display(), fs);
             true
         } else {
@@ -1936,10 +2739,28 @@ fn skip_filesize(
     }
 }
 
-fn should_skip_entry(ig: &Ignore, dent: &DirEntry) -> bool {
+/// Returns true if and only if the given file name corresponds to a
+/// directory used for version control system metadata.
+fn is_vcs_dir(name: &OsStr) -> bool {
+    const VCS_DIRS: &[&str] = &[".git", ".hg", ".svn", ".bzr", "_darcs"];
+    VCS_DIRS.iter().any(|dir| name == OsStr::new(dir))
+}
+
+fn should_skip_entry(
+    ig: &Ignore,
+    dent: &DirEntry,
+    on_ignore: Option<&OnIgnore>,
+) -> bool {
     let m = ig.matched_dir_entry(dent);
     if m.is_ignore() {
         log::debug!("ignoring {}: {:?}", dent.path().display(), m);
+        if let Some(OnIgnore(callback)) = on_ignore {
+            // OK: `is_ignore` guarantees the match information exists.
+            let im = m.inner().unwrap();
+            let mut dent = dent.clone();
+            dent.ignored = im.to_reason();
+            callback(&dent, &im.to_info());
+        }
         true
     } else if m.is_whitelist() {
         log::debug!("whitelisting {}: {:?}", dent.path().display(), m);
@@ -2021,6 +2842,56 @@ fn is_same_file_system(root_device: u64, path: &Path) -> Result<bool, Error> {
     Ok(root_device == dent_device)
 }
 
+/// Returns the set of mount points on the system.
+///
+/// This is used for detecting bind mounts, which share their device number
+/// with the original mount and so cannot be detected by comparing device
+/// numbers.
+#[cfg(target_os = "linux")]
+fn mount_points() -> io::Result<Vec<PathBuf>> {
+    use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+
+    /// Decodes a mount point field from `/proc/mounts`, where whitespace
+    /// and backslashes are encoded as three digit octal escapes, e.g.,
+    /// `\040` for a space.
+    fn unescape(field: &str) -> PathBuf {
+        let raw = field.as_bytes();
+        let mut bytes = Vec::with_capacity(raw.len());
+        let mut i = 0;
+        while i < raw.len() {
+            if raw[i] == b'\\' && i + 3 < raw.len() {
+                let digits = &raw[i + 1..i + 4];
+                if digits.iter().all(|&b| (b'0'..=b'7').contains(&b)) {
+                    let b = digits
+                        .iter()
+                        .fold(0u16, |n, &d| n * 8 + u16::from(d - b'0'));
+                    bytes.push(b as u8);
+                    i += 4;
+                    continue;
+                }
+            }
+            bytes.push(raw[i]);
+            i += 1;
+        }
+        PathBuf::from(OsString::from_vec(bytes))
+    }
+
+    let contents = std::fs::read_to_string("/proc/mounts")?;
+    let mut points = vec![];
+    for line in contents.lines() {
+        // The mount point is the second whitespace separated field.
+        if let Some(point) = line.split_whitespace().nth(1) {
+            points.push(unescape(point));
+        }
+    }
+    Ok(points)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn mount_points() -> io::Result<Vec<PathBuf>> {
+    Ok(vec![])
+}
+
 #[cfg(unix)]
 fn device_num<P: AsRef<Path>>(path: P) -> io::Result<u64> {
     use std::os::unix::fs::MetadataExt;
@@ -2052,7 +2923,7 @@ mod tests {
     use std::path::Path;
     use std::sync::{Arc, Mutex};
 
-    use super::{DirEntry, WalkBuilder, WalkState};
+    use super::{DirEntry, TraversalOrder, WalkBuilder, WalkState};
     use crate::tests::TempDir;
 
     fn wfile<P: AsRef<Path>>(path: P, contents: &str) {
@@ -2176,6 +3047,23 @@ mod tests {
         assert_paths(td.path(), &builder, &["bar", "a", "a/bar"]);
     }
 
+    #[test]
+    fn custom_ignore_multiple_names() {
+        let td = tmpdir();
+        mkdirp(td.path().join("a"));
+        wfile(td.path().join(".npmignore"), "foo");
+        wfile(td.path().join("a/.dockerignore"), "bar");
+        wfile(td.path().join("foo"), "");
+        wfile(td.path().join("a/foo"), "");
+        wfile(td.path().join("bar"), "");
+        wfile(td.path().join("a/bar"), "");
+
+        let mut builder = WalkBuilder::new(td.path());
+        builder.add_custom_ignore_filename(".npmignore");
+        builder.add_custom_ignore_filename(".dockerignore");
+        assert_paths(td.path(), &builder, &["bar", "a"]);
+    }
+
     #[test]
     fn custom_ignore_exclusive_use() {
         let td = tmpdir();
@@ -2367,6 +3255,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn min_filesize() {
+        let td = tmpdir();
+        mkdirp(td.path().join("a/b"));
+        wfile_size(td.path().join("foo"), 0);
+        wfile_size(td.path().join("bar"), 400);
+        wfile_size(td.path().join("baz"), 600);
+        wfile_size(td.path().join("a/foo"), 600);
+        wfile_size(td.path().join("a/bar"), 500);
+        wfile_size(td.path().join("a/baz"), 200);
+
+        let mut builder = WalkBuilder::new(td.path());
+        assert_paths(
+            td.path(),
+            builder.min_filesize(Some(500)),
+            &["a", "a/b", "baz", "a/foo", "a/bar"],
+        );
+        assert_paths(
+            td.path(),
+            builder.min_filesize(Some(50000)),
+            &["a", "a/b"],
+        );
+        assert_paths(
+            td.path(),
+            builder.min_filesize(Some(200)).max_filesize(Some(500)),
+            &["a", "a/b", "bar", "a/bar", "a/baz"],
+        );
+    }
+
     #[cfg(unix)] // because symlinks on windows are weird
     #[test]
     fn symlinks() {
@@ -2417,6 +3334,36 @@ mod tests {
         assert_paths(td.path(), &builder.follow_links(true), &["a", "a/b"]);
     }
 
+    #[cfg(unix)] // because symlinks on windows are weird
+    #[test]
+    fn walk_stats_symlinks() {
+        let td = tmpdir();
+        mkdirp(td.path().join("a/b"));
+        wfile(td.path().join("a/b/foo"), "");
+        symlink(td.path().join("a/b"), td.path().join("z"));
+        symlink(td.path().join("a"), td.path().join("a/b/c"));
+
+        let mut builder = WalkBuilder::new(td.path());
+        builder.follow_links(true);
+
+        let mut walk = builder.build();
+        while walk.next().is_some() {}
+        let stats = walk.into_stats();
+        // `z` is followed into `a/b` and `z/c` into `a`; descending
+        // further runs into the two loops, which are detected and counted
+        // separately (and also reported as errors).
+        assert_eq!(2, stats.symlinks_followed());
+        assert_eq!(2, stats.symlink_cycles_detected());
+        assert_eq!(stats.errors(), stats.symlink_cycles_detected());
+
+        let stats = builder
+            .build_parallel()
+            .run_with_stats(|| Box::new(|_| WalkState::Continue));
+        assert_eq!(2, stats.symlinks_followed());
+        assert_eq!(2, stats.symlink_cycles_detected());
+        assert_eq!(stats.errors(), stats.symlink_cycles_detected());
+    }
+
     // It's a little tricky to test the 'same_file_system' option since
     // we need an environment with more than one file system. We adopt a
     // heuristic where /sys is typically a distinct volume on Linux and roll
@@ -2491,4 +3438,280 @@ mod tests {
             &["x", "x/y", "x/y/foo"],
         );
     }
+
+    #[test]
+    fn filter_multiple_chain_with_and() {
+        let td = tmpdir();
+        mkdirp(td.path().join("a/b/c"));
+        mkdirp(td.path().join("x/y"));
+        wfile(td.path().join("a/b/foo"), "");
+        wfile(td.path().join("x/y/foo"), "");
+
+        assert_paths(
+            td.path(),
+            &WalkBuilder::new(td.path())
+                .filter_entry(|entry| entry.file_name() != OsStr::new("a"))
+                .filter_entry(|entry| entry.file_name() != OsStr::new("foo")),
+            &["x", "x/y"],
+        );
+    }
+
+    #[test]
+    fn skip_vcs_dirs() {
+        let td = tmpdir();
+        mkdirp(td.path().join(".git/objects"));
+        mkdirp(td.path().join(".hg"));
+        mkdirp(td.path().join(".svn"));
+        mkdirp(td.path().join(".bzr"));
+        mkdirp(td.path().join("_darcs"));
+        mkdirp(td.path().join("src"));
+        wfile(td.path().join(".git/config"), "");
+        wfile(td.path().join("src/main.rs"), "");
+
+        let mut builder = WalkBuilder::new(td.path());
+        builder.hidden(false);
+        assert_paths(
+            td.path(),
+            &builder,
+            &[
+                ".git",
+                ".git/objects",
+                ".git/config",
+                ".hg",
+                ".svn",
+                ".bzr",
+                "_darcs",
+                "src",
+                "src/main.rs",
+            ],
+        );
+
+        builder.skip_vcs_dirs(true);
+        assert_paths(td.path(), &builder, &["src", "src/main.rs"]);
+    }
+
+    #[test]
+    fn max_results() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let td = tmpdir();
+        for i in 0..100 {
+            wfile(td.path().join(format!("file-{i:03}")), "");
+        }
+
+        let mut builder = WalkBuilder::new(td.path());
+        builder.max_results(Some(5));
+        assert_eq!(5, builder.build().filter(|r| r.is_ok()).count());
+
+        let count = Arc::new(AtomicUsize::new(0));
+        builder.build_parallel().run(|| {
+            let count = count.clone();
+            Box::new(move |result| {
+                if result.is_ok() {
+                    count.fetch_add(1, Ordering::SeqCst);
+                }
+                WalkState::Continue
+            })
+        });
+        assert_eq!(5, count.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn max_open_files() {
+        let td = tmpdir();
+        mkdirp(td.path().join("a/b/c"));
+        mkdirp(td.path().join("x/y"));
+        wfile(td.path().join("a/b/foo"), "");
+        wfile(td.path().join("x/y/foo"), "");
+
+        // A walk with a single permit must still visit everything, even
+        // with more threads than permits.
+        let mut builder = WalkBuilder::new(td.path());
+        builder.max_open_files(Some(1)).threads(4);
+        let got = walk_collect_parallel(td.path(), &builder);
+        assert_eq!(
+            got,
+            mkpaths(&["x", "x/y", "x/y/foo", "a", "a/b", "a/b/foo", "a/b/c"]),
+        );
+
+        // A limit of zero is clamped to one rather than deadlocking.
+        builder.max_open_files(Some(0));
+        let got = walk_collect_parallel(td.path(), &builder);
+        assert_eq!(
+            got,
+            mkpaths(&["x", "x/y", "x/y/foo", "a", "a/b", "a/b/foo", "a/b/c"]),
+        );
+    }
+
+    #[test]
+    fn on_ignore_callback() {
+        use std::sync::Mutex;
+
+        let td = tmpdir();
+        mkdirp(td.path().join(".git"));
+        wfile(td.path().join(".gitignore"), "foo");
+        wfile(td.path().join("foo"), "");
+        wfile(td.path().join("bar"), "");
+
+        let ignored = Arc::new(Mutex::new(vec![]));
+        let mut builder = WalkBuilder::new(td.path());
+        builder.on_ignore({
+            let ignored = ignored.clone();
+            move |dent, info| {
+                ignored.lock().unwrap().push((
+                    dent.path().to_path_buf(),
+                    info.source().map(|p| p.to_path_buf()),
+                    info.pattern().map(|s| s.to_string()),
+                ));
+            }
+        });
+        assert_paths(td.path(), &builder, &["bar"]);
+
+        // Hidden file filtering (e.g., of `.gitignore` itself) also invokes
+        // the callback, but without a source or a pattern. Only check the
+        // records that came from a gitignore rule.
+        let ignored = ignored.lock().unwrap();
+        let got: Vec<_> = ignored
+            .iter()
+            .filter(|(_, _, pattern)| pattern.is_some())
+            .collect();
+        // The walk above runs twice: single threaded and parallel.
+        assert_eq!(2, got.len());
+        for (path, source, pattern) in got {
+            assert_eq!(td.path().join("foo"), *path);
+            assert_eq!(Some(td.path().join(".gitignore")), *source);
+            assert_eq!(Some("foo".to_string()), *pattern);
+        }
+    }
+
+    #[test]
+    fn bind_mount_check_smoke() {
+        // Actually crossing a bind mount requires root privileges to set up,
+        // so this only checks that a walk with the option enabled is
+        // otherwise unaffected.
+        let td = tmpdir();
+        mkdirp(td.path().join("a"));
+        wfile(td.path().join("a/foo"), "");
+        wfile(td.path().join("bar"), "");
+
+        let mut builder = WalkBuilder::new(td.path());
+        builder.bind_mount_check(true);
+        assert_paths(td.path(), &builder, &["a", "a/foo", "bar"]);
+    }
+
+    #[test]
+    fn walk_stats() {
+        let td = tmpdir();
+        mkdirp(td.path().join(".git"));
+        mkdirp(td.path().join("a"));
+        wfile(td.path().join(".gitignore"), "foo");
+        wfile(td.path().join("foo"), "");
+        wfile(td.path().join("a/bar"), "");
+        wfile(td.path().join("a/baz"), "");
+
+        let builder = WalkBuilder::new(td.path());
+        let mut walk = builder.build();
+        while walk.next().is_some() {}
+        let stats = walk.into_stats();
+        // The root and `a` are directories; `a/bar` and `a/baz` are files.
+        assert_eq!(2, stats.dirs_visited());
+        assert_eq!(2, stats.files_visited());
+        // `foo` matches the gitignore rule, while `.git` and `.gitignore`
+        // are skipped by hidden file filtering.
+        assert_eq!(3, stats.ignored());
+        assert_eq!(0, stats.errors());
+
+        let stats = builder
+            .build_parallel()
+            .run_with_stats(|| Box::new(|_| WalkState::Continue));
+        assert_eq!(2, stats.dirs_visited());
+        assert_eq!(2, stats.files_visited());
+        assert_eq!(3, stats.ignored());
+        assert_eq!(0, stats.errors());
+    }
+
+    #[test]
+    fn breadth_first() {
+        let td = tmpdir();
+        mkdirp(td.path().join("a/b"));
+        wfile(td.path().join("zoo"), "");
+        wfile(td.path().join("a/foo"), "");
+        wfile(td.path().join("a/b/baz"), "");
+
+        let mut builder = WalkBuilder::new(td.path());
+        builder
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .traversal(TraversalOrder::BreadthFirst);
+        // Both traversal orders must see the same set of entries.
+        assert_paths(
+            td.path(),
+            &builder,
+            &["a", "zoo", "a/b", "a/foo", "a/b/baz"],
+        );
+
+        // With a sorter, the serial breadth first order is fully determined:
+        // shallower entries always come before deeper ones.
+        let mut got = vec![];
+        for result in builder.build() {
+            let dent = result.unwrap();
+            let path = dent.path().strip_prefix(td.path()).unwrap();
+            if path.as_os_str().is_empty() {
+                continue;
+            }
+            got.push(normal_path(path.to_str().unwrap()));
+        }
+        assert_eq!(vec!["a", "zoo", "a/b", "a/foo", "a/b/baz"], got);
+    }
+
+    #[test]
+    fn add_ignore_str() {
+        let td = tmpdir();
+        mkdirp(td.path().join("a"));
+        wfile(td.path().join("foo"), "");
+        wfile(td.path().join("a/foo"), "");
+        wfile(td.path().join("bar"), "");
+
+        let mut builder = WalkBuilder::new(td.path());
+        builder.add_ignore_str("foo").unwrap();
+        assert_paths(td.path(), &builder, &["a", "bar"]);
+    }
+
+    #[test]
+    fn ignored_reason() {
+        use std::sync::Mutex;
+
+        let td = tmpdir();
+        mkdirp(td.path().join(".git"));
+        wfile(td.path().join(".gitignore"), "# comment\nfoo\n");
+        wfile(td.path().join("foo"), "");
+        wfile(td.path().join("bar"), "");
+
+        let reasons = Arc::new(Mutex::new(vec![]));
+        let mut builder = WalkBuilder::new(td.path());
+        builder.on_ignore({
+            let reasons = reasons.clone();
+            move |dent, _| {
+                if let Some(reason) = dent.ignored_reason() {
+                    reasons.lock().unwrap().push((
+                        dent.path().to_path_buf(),
+                        reason.path().to_path_buf(),
+                        reason.line(),
+                        reason.pattern().to_string(),
+                    ));
+                }
+            }
+        });
+        assert_paths(td.path(), &builder, &["bar"]);
+
+        // The walk above runs twice: single threaded and parallel. Hidden
+        // file filtering also invokes the callback, but without a reason.
+        let reasons = reasons.lock().unwrap();
+        assert_eq!(2, reasons.len());
+        for (path, from, line, pattern) in reasons.iter() {
+            assert_eq!(td.path().join("foo"), *path);
+            assert_eq!(td.path().join(".gitignore"), *from);
+            assert_eq!(Some(2), *line);
+            assert_eq!("foo", pattern);
+        }
+    }
 }
@@ -612,6 +612,46 @@ pub trait Matcher {
         None
     }
 
+    /// Returns the names of all capturing groups in this matcher, in index
+    /// order. Unnamed groups (including the first group, which always
+    /// corresponds to the overall match) are reported as `None`.
+    ///
+    /// If a matcher supports capturing groups, then the length of the
+    /// returned sequence must be equivalent to `capture_count`.
+    ///
+    /// By default, capturing groups are not supported, so this always returns
+    /// `None`.
+    #[inline]
+    fn capture_names(&self) -> Option<Vec<Option<String>>> {
+        None
+    }
+
+    /// Returns the index of the pattern that produced the given match, if
+    /// the matcher is able to determine it.
+    ///
+    /// When a matcher is built from multiple patterns, the index refers to
+    /// the position of the pattern given at construction. Matchers built
+    /// from a single pattern should return `Some(0)`. Matchers that cannot
+    /// attribute a match to a particular pattern may return `None`, which
+    /// is the default.
+    #[inline]
+    fn which_pattern_matched(
+        &self,
+        _haystack: &[u8],
+        _m: Match,
+    ) -> Option<usize> {
+        None
+    }
+
+    /// Returns the original pattern string at the given index, if available.
+    ///
+    /// The index corresponds to the value reported by
+    /// `which_pattern_matched`. By default, this returns `None`.
+    #[inline]
+    fn pattern_str(&self, _index: usize) -> Option<&str> {
+        None
+    }
+
     /// Returns the start and end byte range of the first match in `haystack`.
     /// If no match exists, then `None` is returned.
     ///
@@ -1168,6 +1208,19 @@ impl<'a, M: Matcher> Matcher for &'a M {
         (*self).capture_count()
     }
 
+    #[inline]
+    fn capture_names(&self) -> Option<Vec<Option<String>>> {
+        (*self).capture_names()
+    }
+
+    fn which_pattern_matched(&self, haystack: &[u8], m: Match) -> Option<usize> {
+        (*self).which_pattern_matched(haystack, m)
+    }
+
+    fn pattern_str(&self, index: usize) -> Option<&str> {
+        (*self).pattern_str(index)
+    }
+
     #[inline]
     fn find(&self, haystack: &[u8]) -> Result<Option<Match>, Self::Error> {
         (*self).find(haystack)
@@ -85,6 +85,10 @@ fn captures() {
     assert_eq!(matcher.capture_index("a"), Some(1));
     assert_eq!(matcher.capture_index("b"), Some(2));
     assert_eq!(matcher.capture_index("nada"), None);
+    assert_eq!(
+        matcher.capture_names(),
+        Some(vec![None, Some("a".to_string()), Some("b".to_string())])
+    );
 
     let mut caps = matcher.new_captures().unwrap();
     assert!(matcher.captures(b" homer simpson ", &mut caps).unwrap());
@@ -159,6 +163,7 @@ fn no_captures() {
     assert_eq!(matcher.capture_index("a"), None);
     assert_eq!(matcher.capture_index("b"), None);
     assert_eq!(matcher.capture_index("nada"), None);
+    assert_eq!(matcher.capture_names(), None);
 
     let mut caps = matcher.new_captures().unwrap();
     assert!(!matcher.captures(b"homer simpson", &mut caps).unwrap());
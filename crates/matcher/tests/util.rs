@@ -57,6 +57,15 @@ impl Matcher for RegexMatcher {
         self.names.get(name).map(|i| *i)
     }
 
+    fn capture_names(&self) -> Option<Vec<Option<String>>> {
+        Some(
+            self.re
+                .capture_names()
+                .map(|name| name.map(|n| n.to_string()))
+                .collect(),
+        )
+    }
+
     // We purposely don't implement any other methods, so that we test the
     // default impls. The "real" Regex impl for Matcher provides a few more
     // impls. e.g., Its `find_iter` impl is faster than what we can do here,
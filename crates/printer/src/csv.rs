@@ -0,0 +1,482 @@
+use std::{
+    io::{self, Write},
+    path::Path,
+};
+
+use {
+    grep_matcher::{Match, Matcher},
+    grep_searcher::{Searcher, Sink, SinkFinish, SinkMatch},
+};
+
+use crate::{counter::CounterWriter, util::find_iter_at_in_context};
+
+/// Конфигурация для CSV принтера.
+///
+/// Это управляется CSVBuilder, а затем используется фактической
+/// реализацией. Как только принтер построен, конфигурация заморожена и
+/// не может быть изменена.
+#[derive(Debug, Clone)]
+struct Config {
+    delimiter: u8,
+    header: bool,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { delimiter: b',', header: false }
+    }
+}
+
+/// Построитель для CSV принтера.
+///
+/// Построитель позволяет настраивать поведение принтера. CSV принтер
+/// имеет немного опций конфигурации, потому что формат фиксирован: одна
+/// запись на каждое отдельное совпадение с полями `path`, `line_number`,
+/// `column` и `match_text`.
+///
+/// Как только `CSV` принтер построен, его конфигурация не может быть изменена.
+#[derive(Clone, Debug)]
+pub struct CSVBuilder {
+    config: Config,
+}
+
+impl CSVBuilder {
+    /// Возвращает новый построитель для конфигурирования CSV принтера.
+    pub fn new() -> CSVBuilder {
+        CSVBuilder { config: Config::default() }
+    }
+
+    /// Создаёт CSV принтер, который записывает результаты в данный writer.
+    pub fn build<W: io::Write>(&self, wtr: W) -> CSV<W> {
+        CSV {
+            config: self.config.clone(),
+            wtr: CounterWriter::new(wtr),
+            matches: vec![],
+            header_printed: false,
+        }
+    }
+
+    /// Устанавливает байт, используемый для разделения полей.
+    ///
+    /// По умолчанию это `,`.
+    pub fn delimiter(&mut self, delimiter: u8) -> &mut CSVBuilder {
+        self.config.delimiter = delimiter;
+        self
+    }
+
+    /// Когда включено, перед первой записью выводится строка заголовка
+    /// `path,line_number,column,match_text`.
+    ///
+    /// Заголовок выводится не более одного раза, даже если принтер
+    /// используется для нескольких поисков.
+    ///
+    /// Это отключено по умолчанию.
+    pub fn header(&mut self, yes: bool) -> &mut CSVBuilder {
+        self.config.header = yes;
+        self
+    }
+}
+
+/// CSV принтер, который выводит одну запись на каждое отдельное совпадение.
+///
+/// Этот тип параметризован над `W`, который представляет любую реализацию
+/// трейта стандартной библиотеки `io::Write`.
+///
+/// # Формат
+///
+/// Каждая запись содержит поля `path`, `line_number`, `column` и
+/// `match_text`, в этом порядке. Поле `path` пусто, когда путь не был
+/// предоставлен. Поле `column` — это 1-основанное байтовое смещение начала
+/// совпадения в его строке. Поля, содержащие разделитель, двойные кавычки
+/// или терминаторы строки, экранируются согласно RFC 4180: поле
+/// оборачивается в двойные кавычки, а каждая двойная кавычка внутри
+/// удваивается.
+///
+/// Байты, не являющиеся валидным UTF-8, записываются как есть.
+#[derive(Clone, Debug)]
+pub struct CSV<W> {
+    config: Config,
+    wtr: CounterWriter<W>,
+    matches: Vec<Match>,
+    header_printed: bool,
+}
+
+impl<W: io::Write> CSV<W> {
+    /// Возвращает CSV принтер с конфигурацией по умолчанию, который
+    /// записывает совпадения в данный writer.
+    pub fn new(wtr: W) -> CSV<W> {
+        CSVBuilder::new().build(wtr)
+    }
+
+    /// Возвращает реализацию `Sink` для CSV принтера.
+    ///
+    /// Это не связывает принтер с путём к файлу, что означает, что поле
+    /// `path` каждой записи будет пустым.
+    pub fn sink<'s, M: Matcher>(
+        &'s mut self,
+        matcher: M,
+    ) -> CSVSink<'static, 's, M, W> {
+        CSVSink {
+            matcher,
+            csv: self,
+            path: None,
+            match_count: 0,
+            binary_byte_offset: None,
+        }
+    }
+
+    /// Возвращает реализацию `Sink`, связанную с путём к файлу.
+    ///
+    /// Когда принтер связан с путём, то поле `path` каждой записи
+    /// содержит этот путь.
+    pub fn sink_with_path<'p, 's, M, P>(
+        &'s mut self,
+        matcher: M,
+        path: &'p P,
+    ) -> CSVSink<'p, 's, M, W>
+    where
+        M: Matcher,
+        P: ?Sized + AsRef<Path>,
+    {
+        CSVSink {
+            matcher,
+            csv: self,
+            path: Some(path.as_ref()),
+            match_count: 0,
+            binary_byte_offset: None,
+        }
+    }
+
+    /// Записывает строку заголовка, если она настроена и ещё не была
+    /// записана.
+    fn write_header_if_needed(&mut self) -> io::Result<()> {
+        if !self.config.header || self.header_printed {
+            return Ok(());
+        }
+        let delim = [self.config.delimiter];
+        self.wtr.write_all(b"path")?;
+        self.wtr.write_all(&delim)?;
+        self.wtr.write_all(b"line_number")?;
+        self.wtr.write_all(&delim)?;
+        self.wtr.write_all(b"column")?;
+        self.wtr.write_all(&delim)?;
+        self.wtr.write_all(b"match_text")?;
+        self.wtr.write_all(b"\n")?;
+        self.header_printed = true;
+        Ok(())
+    }
+
+    /// Записывает единственное поле, экранируя его согласно RFC 4180,
+    /// если необходимо.
+    fn write_field(&mut self, field: &[u8]) -> io::Result<()> {
+        let needs_quoting = field.iter().any(|&b| {
+            b == self.config.delimiter || b == b'"' || b == b'\n' || b == b'\r'
+        });
+        if !needs_quoting {
+            return self.wtr.write_all(field);
+        }
+        self.wtr.write_all(b"\"")?;
+        for &byte in field.iter() {
+            if byte == b'"' {
+                self.wtr.write_all(b"\"\"")?;
+            } else {
+                self.wtr.write_all(&[byte])?;
+            }
+        }
+        self.wtr.write_all(b"\"")
+    }
+}
+
+impl<W> CSV<W> {
+    /// Возвращает true тогда и только тогда, когда этот принтер записал
+    /// хотя бы один байт в базовый writer во время любого из предыдущих
+    /// поисков.
+    pub fn has_written(&self) -> bool {
+        self.wtr.total_count() > 0
+    }
+
+    /// Возвращает изменяемую ссылку на базовый writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.wtr.get_mut()
+    }
+
+    /// Поглощает этот принтер и возвращает обратно владение базовым
+    /// writer.
+    pub fn into_inner(self) -> W {
+        self.wtr.into_inner()
+    }
+}
+
+/// Реализация `Sink`, связанная с matcher и опциональным путём к файлу
+/// для CSV принтера.
+///
+/// Этот тип параметризован несколькими параметрами типа:
+///
+/// * `'p` относится к времени жизни пути к файлу, если он предоставлен.
+///   Когда путь к файлу не дан, то это `'static`.
+/// * `'s` относится к времени жизни принтера [`CSV`], который этот тип
+///   заимствует.
+/// * `M` относится к типу matcher, используемого `grep_searcher::Searcher`,
+///   который сообщает результаты в этот sink.
+/// * `W` относится к базовому writer, в который этот принтер записывает
+///   свой вывод.
+#[derive(Debug)]
+pub struct CSVSink<'p, 's, M: Matcher, W> {
+    matcher: M,
+    csv: &'s mut CSV<W>,
+    path: Option<&'p Path>,
+    match_count: u64,
+    binary_byte_offset: Option<u64>,
+}
+
+impl<'p, 's, M: Matcher, W: io::Write> CSVSink<'p, 's, M, W> {
+    /// Возвращает true тогда и только тогда, когда этот принтер получил
+    /// совпадение в предыдущем поиске.
+    ///
+    /// Это не зависит от результата поисков до предыдущего поиска.
+    pub fn has_match(&self) -> bool {
+        self.match_count > 0
+    }
+
+    /// Возвращает общее количество совпадений, сообщённых в этот sink.
+    ///
+    /// Это соответствует количеству вызовов `Sink::matched`.
+    pub fn match_count(&self) -> u64 {
+        self.match_count
+    }
+
+    /// Если бинарные данные были найдены в предыдущем поиске, это
+    /// возвращает смещение, на котором бинарные данные были впервые
+    /// обнаружены.
+    ///
+    /// Возвращаемое смещение — это абсолютное смещение относительно
+    /// всего набора исканных байтов.
+    pub fn binary_byte_offset(&self) -> Option<u64> {
+        self.binary_byte_offset
+    }
+
+    /// Выполняет matcher на данных байтах и записывает расположения
+    /// каждого отдельного совпадения.
+    fn record_matches(
+        &mut self,
+        searcher: &Searcher,
+        bytes: &[u8],
+        range: std::ops::Range<usize>,
+    ) -> io::Result<()> {
+        self.csv.matches.clear();
+        let matches = &mut self.csv.matches;
+        find_iter_at_in_context(
+            searcher,
+            &self.matcher,
+            bytes,
+            range.clone(),
+            |m| {
+                let (s, e) = (m.start() - range.start, m.end() - range.start);
+                matches.push(Match::new(s, e));
+                true
+            },
+        )?;
+        // Не сообщаем пустые совпадения, появляющиеся в конце байтов.
+        if !matches.is_empty()
+            && matches.last().unwrap().is_empty()
+            && matches.last().unwrap().start() >= bytes.len()
+        {
+            matches.pop().unwrap();
+        }
+        Ok(())
+    }
+}
+
+impl<'p, 's, M: Matcher, W: io::Write> Sink for CSVSink<'p, 's, M, W> {
+    type Error = io::Error;
+
+    fn matched(
+        &mut self,
+        searcher: &Searcher,
+        mat: &SinkMatch<'_>,
+    ) -> Result<bool, io::Error> {
+        self.match_count += 1;
+        self.csv.write_header_if_needed()?;
+        self.record_matches(
+            searcher,
+            mat.buffer(),
+            mat.bytes_range_in_buffer(),
+        )?;
+
+        let path = match self.path {
+            None => String::new(),
+            Some(path) => path.to_string_lossy().into_owned(),
+        };
+        let line_number = match mat.line_number() {
+            None => String::new(),
+            Some(n) => n.to_string(),
+        };
+        let delim = [self.csv.config.delimiter];
+        for i in 0..self.csv.matches.len() {
+            let m = self.csv.matches[i];
+            let column = (m.start() + 1).to_string();
+            self.csv.write_field(path.as_bytes())?;
+            self.csv.wtr.write_all(&delim)?;
+            self.csv.write_field(line_number.as_bytes())?;
+            self.csv.wtr.write_all(&delim)?;
+            self.csv.write_field(column.as_bytes())?;
+            self.csv.wtr.write_all(&delim)?;
+            let match_text = mat.bytes()[m].to_vec();
+            self.csv.write_field(&match_text)?;
+            self.csv.wtr.write_all(b"\n")?;
+        }
+        Ok(true)
+    }
+
+    fn binary_data(
+        &mut self,
+        searcher: &Searcher,
+        binary_byte_offset: u64,
+    ) -> Result<bool, io::Error> {
+        if searcher.binary_detection().quit_byte().is_some() {
+            if let Some(ref path) = self.path {
+                log::debug!(
+                    "ignoring {path}: found binary data at \
+                     offset {binary_byte_offset}",
+                    path = path.display(),
+                );
+            }
+        }
+        Ok(true)
+    }
+
+    fn begin(&mut self, _searcher: &Searcher) -> Result<bool, io::Error> {
+        self.csv.wtr.reset_count();
+        self.match_count = 0;
+        self.binary_byte_offset = None;
+        Ok(true)
+    }
+
+    fn finish(
+        &mut self,
+        _searcher: &Searcher,
+        finish: &SinkFinish,
+    ) -> Result<(), io::Error> {
+        self.binary_byte_offset = finish.binary_byte_offset();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grep_regex::RegexMatcher;
+    use grep_searcher::SearcherBuilder;
+
+    use super::{CSV, CSVBuilder};
+
+    const SHERLOCK: &'static [u8] = b"\
+For the Doctor Watsons of this world, as opposed to the Sherlock
+Holmeses, success in the province of detective work must always
+be, to a very large extent, the result of luck. Sherlock Holmes
+can extract a clew from a wisp of straw or a flake of cigar ash;
+but Doctor Watson has to have it taken out for him and dusted,
+and exhibited clearly, with a label attached.
+";
+
+    fn printer_contents(printer: &mut CSV<Vec<u8>>) -> String {
+        String::from_utf8(printer.get_mut().to_owned()).unwrap()
+    }
+
+    #[test]
+    fn basic() {
+        let matcher = RegexMatcher::new(r"Watson").unwrap();
+        let mut printer = CSVBuilder::new().build(vec![]);
+        SearcherBuilder::new()
+            .line_number(true)
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK,
+                printer.sink_with_path(&matcher, "sherlock"),
+            )
+            .unwrap();
+        let got = printer_contents(&mut printer);
+
+        let expected = "\
+sherlock,1,16,Watson
+sherlock,5,12,Watson
+";
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn header() {
+        let matcher = RegexMatcher::new(r"Watson").unwrap();
+        let mut printer = CSVBuilder::new().header(true).build(vec![]);
+        SearcherBuilder::new()
+            .line_number(true)
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK,
+                printer.sink_with_path(&matcher, "sherlock"),
+            )
+            .unwrap();
+        let got = printer_contents(&mut printer);
+
+        assert_eq!(
+            Some("path,line_number,column,match_text"),
+            got.lines().next(),
+        );
+        assert_eq!(got.lines().count(), 3);
+    }
+
+    #[test]
+    fn escaping() {
+        let matcher = RegexMatcher::new(r#""[^"]+""#).unwrap();
+        let mut printer = CSVBuilder::new().build(vec![]);
+        SearcherBuilder::new()
+            .line_number(true)
+            .build()
+            .search_reader(
+                &matcher,
+                &b"say \"hello, world\" now\n"[..],
+                printer.sink_with_path(&matcher, "a,b"),
+            )
+            .unwrap();
+        let got = printer_contents(&mut printer);
+
+        let expected = "\"a,b\",1,5,\"\"\"hello, world\"\"\"\n";
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn delimiter() {
+        let matcher = RegexMatcher::new(r"Watson").unwrap();
+        let mut printer = CSVBuilder::new().delimiter(b';').build(vec![]);
+        SearcherBuilder::new()
+            .line_number(true)
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK,
+                printer.sink_with_path(&matcher, "sherlock"),
+            )
+            .unwrap();
+        let got = printer_contents(&mut printer);
+
+        let expected = "\
+sherlock;1;16;Watson
+sherlock;5;12;Watson
+";
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn no_match() {
+        let matcher = RegexMatcher::new(r"DOES NOT MATCH").unwrap();
+        let mut printer = CSVBuilder::new().header(true).build(vec![]);
+        SearcherBuilder::new()
+            .build()
+            .search_reader(&matcher, SHERLOCK, printer.sink(&matcher))
+            .unwrap();
+        let got = printer_contents(&mut printer);
+
+        assert!(got.is_empty());
+    }
+}
@@ -31,6 +31,17 @@ pub(super) const HYPERLINK_PATTERN_ALIASES: &[HyperlinkAlias] = &[
     ),
     // https://github.com/misaki-web/grepp
     alias("grep+", "grep+ scheme (grep+://)", "grep+://{path}:{line}"),
+    alias(
+        "helix",
+        "Helix scheme (helix://)",
+        "helix://{path}:{line}:{column}",
+    ),
+    // https://www.jetbrains.com/help/idea/opening-files-from-command-line.html
+    alias(
+        "idea",
+        "JetBrains IDE scheme (idea://)",
+        "idea://open?file={path}&line={line}&column={column}",
+    ),
     alias(
         "kitty",
         "kitty-style RFC 8089 scheme (file://) with line number",
@@ -248,10 +248,21 @@ impl HyperlinkAlias {
 /// времени жизни программы. То есть эти значения инвариантны.
 ///
 /// В настоящее время это включает имя хоста и префикс дистрибутива WSL.
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct HyperlinkEnvironment {
     host: Option<String>,
     wsl_prefix: Option<String>,
+    resolve_symlinks: bool,
+}
+
+impl Default for HyperlinkEnvironment {
+    fn default() -> HyperlinkEnvironment {
+        HyperlinkEnvironment {
+            host: None,
+            wsl_prefix: None,
+            resolve_symlinks: true,
+        }
+    }
 }
 
 impl HyperlinkEnvironment {
@@ -260,6 +271,45 @@ impl HyperlinkEnvironment {
         HyperlinkEnvironment::default()
     }
 
+    /// Создаёт окружение гиперссылок, автоматически заполненное из текущего
+    /// окружения.
+    ///
+    /// Переменная `{host}` заполняется через функцию `hostname` из крейта
+    /// `grep-cli`, а переменная `{wslprefix}` строится из переменной
+    /// окружения `WSL_DISTRO_NAME` (только в Unix), например `wsl$/Ubuntu`.
+    /// Если какое-либо значение получить не удалось, соответствующая
+    /// переменная остаётся незаполненной.
+    pub fn auto_detect() -> HyperlinkEnvironment {
+        let mut env = HyperlinkEnvironment::new();
+        match grep_cli::hostname() {
+            Ok(hostname_os) => match hostname_os.into_string() {
+                Ok(hostname) => {
+                    log::debug!(
+                        "found hostname for hyperlink environment: \
+                         {hostname}",
+                    );
+                    env.host(Some(hostname));
+                }
+                Err(hostname_os) => {
+                    log::debug!(
+                        "got hostname {hostname_os:?}, \
+                         but it's not valid UTF-8",
+                    );
+                }
+            },
+            Err(err) => {
+                log::debug!("could not get hostname: {err}");
+            }
+        }
+        if let Some(wsl_prefix) = wsl_prefix() {
+            log::debug!(
+                "found wsl_prefix for hyperlink environment: {wsl_prefix}",
+            );
+            env.wsl_prefix(Some(wsl_prefix));
+        }
+        env
+    }
+
     /// Устанавливает переменную `{host}`, которая заполняет любые компоненты
     /// имени хоста гиперссылки.
     ///
@@ -280,6 +330,55 @@ impl HyperlinkEnvironment {
         self.wsl_prefix = wsl_prefix;
         self
     }
+
+    /// Устанавливает, должны ли симлинки разрешаться при создании пути
+    /// гиперссылки.
+    ///
+    /// Когда включено, путь канонизируется, что разрешает симлинки и
+    /// касается файловой системы. Когда отключено, путь только делается
+    /// абсолютным, что не требует доступа к файловой системе, но может
+    /// дать гиперссылку, отличающуюся от фактического расположения файла.
+    ///
+    /// Это влияет только на Unix: в Windows путь никогда не канонизируется.
+    ///
+    /// Это включено по умолчанию.
+    pub fn resolve_symlinks(
+        &mut self,
+        yes: bool,
+    ) -> &mut HyperlinkEnvironment {
+        self.resolve_symlinks = yes;
+        self
+    }
+
+    /// Возвращает, должны ли симлинки разрешаться при создании пути
+    /// гиперссылки.
+    pub(crate) fn is_resolve_symlinks(&self) -> bool {
+        self.resolve_symlinks
+    }
+}
+
+/// Возвращает значение для переменной `{wslprefix}` в формате гиперссылки.
+///
+/// Префикс WSL — это что-то вроде общего ресурса/сети, что предназначено для
+/// разрешения приложениям Windows открывать файлы, хранящиеся на диске WSL.
+///
+/// Если имя дистрибутива WSL недоступно, не является валидным UTF-8 или это
+/// не выполняется в окружении Unix, то это возвращает None.
+///
+/// См.: <https://learn.microsoft.com/en-us/windows/wsl/filesystems>
+fn wsl_prefix() -> Option<String> {
+    if !cfg!(unix) {
+        return None;
+    }
+    let distro_os = std::env::var_os("WSL_DISTRO_NAME")?;
+    let Some(distro) = distro_os.to_str() else {
+        log::debug!(
+            "found WSL_DISTRO_NAME={:?}, but value is not UTF-8",
+            distro_os
+        );
+        return None;
+    };
+    Some(format!("wsl$/{distro}"))
 }
 
 /// Ошибка, которая может возникнуть при парсинге формата гиперссылки.
@@ -297,6 +396,9 @@ enum HyperlinkFormatErrorKind {
     /// Это происходит, когда переменная {line} отсутствует, при этом
     /// переменная {column} присутствует.
     NoLineVariable,
+    /// Это происходит, когда переменная {column} отсутствует, при этом
+    /// переменная {column_end} присутствует.
+    NoColumnVariable,
     /// Это происходит, когда используется неизвестная переменная.
     InvalidVariable(String),
     /// Формат не начинается с допустимой схемы.
@@ -343,11 +445,19 @@ impl std::fmt::Display for HyperlinkFormatError {
                      но переменная {{line}} отсутствует",
                 )
             }
+            NoColumnVariable => {
+                write!(
+                    f,
+                    "формат гиперссылки содержит переменную {{column_end}}, \
+                     но переменная {{column}} отсутствует",
+                )
+            }
             InvalidVariable(ref name) => {
                 write!(
                     f,
                     "недопустимая переменная формата гиперссылки: '{name}', \
-                     выберите из: path, line, column, host, wslprefix",
+                     выберите из: path, line, column, column_end, match, \
+                     host, wslprefix",
                 )
             }
             InvalidScheme => {
@@ -416,6 +526,8 @@ impl FormatBuilder {
             "path" => Part::Path,
             "line" => Part::Line,
             "column" => Part::Column,
+            "column_end" => Part::ColumnEnd,
+            "match" => Part::Match,
             unknown => {
                 let err = HyperlinkFormatError {
                     kind: HyperlinkFormatErrorKind::InvalidVariable(
@@ -434,7 +546,8 @@ impl FormatBuilder {
         self.validate()?;
         Ok(HyperlinkFormat {
             parts: self.parts.clone(),
-            is_line_dependent: self.parts.contains(&Part::Line),
+            is_line_dependent: self.parts.contains(&Part::Line)
+                || self.parts.contains(&Part::Match),
         })
     }
 
@@ -465,6 +578,13 @@ impl FormatBuilder {
         {
             return Err(err(NoLineVariable));
         }
+        // Аналогично, переменная {column_end} требует переменной {column}
+        // (и, транзитивно, переменной {line}).
+        if self.parts.contains(&Part::ColumnEnd)
+            && !self.parts.contains(&Part::Column)
+        {
+            return Err(err(NoColumnVariable));
+        }
         self.validate_scheme()
     }
 
@@ -528,6 +648,10 @@ enum Part {
     Line,
     /// Переменная для номера столбца.
     Column,
+    /// Переменная для номера столбца конца совпадения.
+    ColumnEnd,
+    /// Переменная для текста совпадения в URL-кодировке.
+    Match,
 }
 
 impl Part {
@@ -556,6 +680,25 @@ impl Part {
                 let column = DecimalFormatter::new(values.column.unwrap_or(1));
                 dest.extend_from_slice(column.as_bytes());
             }
+            Part::ColumnEnd => {
+                let column_end =
+                    DecimalFormatter::new(values.column_end.unwrap_or(1));
+                dest.extend_from_slice(column_end.as_bytes());
+            }
+            Part::Match => {
+                let bytes = values.match_bytes.unwrap_or(b"");
+                for &byte in bytes.iter() {
+                    if byte.is_ascii_alphanumeric()
+                        || matches!(byte, b'-' | b'.' | b'_' | b'~')
+                    {
+                        dest.push(byte);
+                    } else {
+                        dest.extend_from_slice(
+                            format!("%{byte:02X}").as_bytes(),
+                        );
+                    }
+                }
+            }
         }
     }
 }
@@ -569,6 +712,8 @@ impl std::fmt::Display for Part {
             Part::Path => write!(f, "{{path}}"),
             Part::Line => write!(f, "{{line}}"),
             Part::Column => write!(f, "{{column}}"),
+            Part::ColumnEnd => write!(f, "{{column_end}}"),
+            Part::Match => write!(f, "{{match}}"),
         }
     }
 }
@@ -583,6 +728,8 @@ pub(crate) struct Values<'a> {
     path: &'a HyperlinkPath,
     line: Option<u64>,
     column: Option<u64>,
+    column_end: Option<u64>,
+    match_bytes: Option<&'a [u8]>,
 }
 
 impl<'a> Values<'a> {
@@ -591,7 +738,13 @@ impl<'a> Values<'a> {
     /// Вызывающие могут также установить номер строки и столбца, используя
     /// методы-мутаторы.
     pub(crate) fn new(path: &'a HyperlinkPath) -> Values<'a> {
-        Values { path, line: None, column: None }
+        Values {
+            path,
+            line: None,
+            column: None,
+            column_end: None,
+            match_bytes: None,
+        }
     }
 
     /// Устанавливает номер строки для этих значений.
@@ -613,6 +766,29 @@ impl<'a> Values<'a> {
         self.column = column;
         self
     }
+
+    /// Устанавливает номер столбца конца совпадения для этих значений.
+    ///
+    /// Это 1-основанный столбец последнего байта совпадения. Если он не
+    /// установлен и формат гиперссылки содержит переменную `{column_end}`,
+    /// то она автоматически интерполируется значением `1`.
+    pub(crate) fn column_end(mut self, column_end: Option<u64>) -> Values<'a> {
+        self.column_end = column_end;
+        self
+    }
+
+    /// Устанавливает байты совпадения для этих значений.
+    ///
+    /// Байты интерполируются в URL-кодировке. Если байты совпадения не
+    /// установлены, то переменная `{match}` интерполируется пустой
+    /// строкой.
+    pub(crate) fn match_bytes(
+        mut self,
+        match_bytes: Option<&'a [u8]>,
+    ) -> Values<'a> {
+        self.match_bytes = match_bytes;
+        self
+    }
 }
 
 /// Абстракция для интерполяции формата гиперссылки со значениями для
@@ -715,9 +891,30 @@ pub(crate) struct HyperlinkPath(Vec<u8>);
 impl HyperlinkPath {
     /// Возвращает путь гиперссылки из пути ОС.
     #[cfg(unix)]
-    pub(crate) fn from_path(original_path: &Path) -> Option<HyperlinkPath> {
+    pub(crate) fn from_path(
+        original_path: &Path,
+        resolve_symlinks: bool,
+    ) -> Option<HyperlinkPath> {
         use std::os::unix::ffi::OsStrExt;
 
+        // Когда разрешение симлинков отключено, мы используем
+        // `std::path::absolute`, который не касается файловой системы.
+        if !resolve_symlinks {
+            let path = match std::path::absolute(original_path) {
+                Ok(path) => path,
+                Err(err) => {
+                    log::debug!(
+                        "hyperlink creation for {:?} failed, error occurred \
+                         during conversion to absolute path: {}",
+                        original_path,
+                        err,
+                    );
+                    return None;
+                }
+            };
+            return Some(HyperlinkPath::encode(path.as_os_str().as_bytes()));
+        }
+
         // Мы канонизируем путь, чтобы получить его абсолютную версию
         // без каких-либо `.`, `..` или лишних разделителей. К сожалению,
         // это также удаляет симлинки, и в теории было бы неплохо их
@@ -770,7 +967,10 @@ impl HyperlinkPath {
 
     /// Возвращает путь гиперссылки из пути ОС.
     #[cfg(windows)]
-    pub(crate) fn from_path(original_path: &Path) -> Option<HyperlinkPath> {
+    pub(crate) fn from_path(
+        original_path: &Path,
+        _resolve_symlinks: bool,
+    ) -> Option<HyperlinkPath> {
         // В Windows мы используем `std::path::absolute` вместо `Path::canonicalize`,
         // так как это может быть намного быстрее, поскольку не касается
         // файловой системы. Это обёртывает API [`GetFullPathNameW`][1],
@@ -885,7 +1085,10 @@ impl HyperlinkPath {
 
     /// Для других платформ (не windows, не unix), возвращает None и логирует отладочное сообщение.
     #[cfg(not(any(windows, unix)))]
-    pub(crate) fn from_path(original_path: &Path) -> Option<HyperlinkPath> {
+    pub(crate) fn from_path(
+        original_path: &Path,
+        _resolve_symlinks: bool,
+    ) -> Option<HyperlinkPath> {
         log::debug!("гиперссылки не поддерживаются на этой платформе");
         None
     }
@@ -1053,6 +1256,11 @@ mod tests {
             HyperlinkFormat::from_str("foo://{path}:{column}").unwrap_err(),
             err(NoLineVariable),
         );
+        assert_eq!(
+            HyperlinkFormat::from_str("foo://{path}:{line}:{column_end}")
+                .unwrap_err(),
+            err(NoColumnVariable),
+        );
         assert_eq!(
             HyperlinkFormat::from_str("{path}").unwrap_err(),
             err(InvalidScheme),
@@ -1105,7 +1313,7 @@ mod tests {
     fn convert_to_hyperlink_path() {
         let convert = |path| {
             String::from_utf8(
-                HyperlinkPath::from_path(Path::new(path)).unwrap().0,
+                HyperlinkPath::from_path(Path::new(path), true).unwrap().0,
             )
             .unwrap()
         };
@@ -25,6 +25,8 @@ use crate::{
 struct Config {
     pretty: bool,
     always_begin_end: bool,
+    emit_context: bool,
+    array_mode: bool,
     replacement: Arc<Option<Vec<u8>>>,
 }
 
@@ -33,6 +35,8 @@ impl Default for Config {
         Config {
             pretty: false,
             always_begin_end: false,
+            emit_context: true,
+            array_mode: false,
             replacement: Arc::new(None),
         }
     }
@@ -93,6 +97,33 @@ impl JSONBuilder {
         self
     }
 
+    /// Когда отключено, сообщения `context` подавляются, даже если searcher
+    /// настроен сообщать контекстные строки.
+    ///
+    /// Контекстное окно по-прежнему отслеживается searcher, так что
+    /// семантика совпадений (например, в многострочном режиме) не меняется;
+    /// подавляется только вывод.
+    ///
+    /// Это включено по умолчанию.
+    pub fn emit_context(&mut self, yes: bool) -> &mut JSONBuilder {
+        self.config.emit_context = yes;
+        self
+    }
+
+    /// Когда включено, все сообщения оборачиваются в единый JSON массив
+    /// вместо формата "JSON lines".
+    ///
+    /// Открывающая скобка `[` выводится перед первым сообщением, сообщения
+    /// разделяются запятыми, а закрывающая скобка `]` выводится вызовом
+    /// [`JSON::finish`] после завершения всех поисков. Если ни одно
+    /// сообщение не было выведено, `finish` выводит пустой массив `[]`.
+    ///
+    /// Это отключено по умолчанию, что сохраняет формат "JSON lines".
+    pub fn array_mode(&mut self, yes: bool) -> &mut JSONBuilder {
+        self.config.array_mode = yes;
+        self
+    }
+
     /// Устанавливает байты, которые будут использоваться для замены каждого вхождения найденного совпадения.
     ///
     /// Байты замены могут включать ссылки на группы захвата,
@@ -554,12 +585,45 @@ impl<W: io::Write> JSON<W> {
         &mut self,
         message: &jsont::Message<'_>,
     ) -> io::Result<()> {
+        if self.config.array_mode {
+            // Открывающая скобка перед первым сообщением, запятая перед
+            // каждым последующим. Перевод строки после сообщения не
+            // выводится: его выводит либо разделитель перед следующим
+            // сообщением, либо `finish`.
+            if self.has_written() {
+                let _ = self.wtr.write(b",\n")?;
+            } else {
+                let _ = self.wtr.write(b"[\n")?;
+            }
+        }
         if self.config.pretty {
             json::to_writer_pretty(&mut self.wtr, message)?;
         } else {
             json::to_writer(&mut self.wtr, message)?;
         }
-        let _ = self.wtr.write(b"\n")?; // Это всегда будет Ok(1) при успехе.
+        if !self.config.array_mode {
+            // Это всегда будет Ok(1) при успехе.
+            let _ = self.wtr.write(b"\n")?;
+        }
+        Ok(())
+    }
+
+    /// Завершает вывод этого принтера.
+    ///
+    /// Когда включён [`array_mode`](JSONBuilder::array_mode), это выводит
+    /// закрывающую скобку JSON массива (или пустой массив `[]`, если ни
+    /// одно сообщение не было выведено). Этот метод следует вызвать ровно
+    /// один раз после завершения всех поисков. Без `array_mode` это
+    /// не делает ничего.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if !self.config.array_mode {
+            return Ok(());
+        }
+        if self.has_written() {
+            let _ = self.wtr.write(b"\n]\n")?;
+        } else {
+            let _ = self.wtr.write(b"[]\n")?;
+        }
         Ok(())
     }
 }
@@ -764,6 +828,9 @@ impl<'p, 's, M: Matcher, W: io::Write> Sink for JSONSink<'p, 's, M, W> {
         searcher: &Searcher,
         ctx: &SinkContext<'_>,
     ) -> Result<bool, io::Error> {
+        if !self.json.config.emit_context {
+            return Ok(true);
+        }
         self.write_begin_message()?;
         self.json.matches.clear();
 
@@ -831,6 +898,7 @@ impl<'p, 's, M: Matcher, W: io::Write> Sink for JSONSink<'p, 's, M, W> {
             self.stats.add_searches_with_match(1);
         }
         self.stats.add_bytes_searched(finish.byte_count());
+        self.stats.add_lines_searched(finish.lines_scanned());
         self.stats.add_bytes_printed(self.json.wtr.count());
 
         if !self.begin_printed {
@@ -955,6 +1023,42 @@ and exhibited clearly, with a label attached.\
         assert!(last.contains(r#""binary_offset":212,"#));
     }
 
+    #[test]
+    fn array_mode() {
+        let matcher = RegexMatcher::new(r"Watson").unwrap();
+        let mut printer = JSONBuilder::new().array_mode(true).build(vec![]);
+        SearcherBuilder::new()
+            .build()
+            .search_reader(&matcher, SHERLOCK, printer.sink(&matcher))
+            .unwrap();
+        printer.finish().unwrap();
+        let got = printer_contents(&mut printer);
+
+        assert!(got.starts_with("[\n"));
+        assert!(got.ends_with("\n]\n"));
+        // Вывод в целом должен быть валидным JSON массивом с четырьмя
+        // сообщениями: begin, два match и end.
+        let msgs: Vec<serde_json::Value> =
+            serde_json::from_str(&got).unwrap();
+        assert_eq!(4, msgs.len());
+        assert_eq!("begin", msgs[0]["type"]);
+        assert_eq!("end", msgs[3]["type"]);
+    }
+
+    #[test]
+    fn array_mode_empty() {
+        let matcher = RegexMatcher::new(r"NadaNada").unwrap();
+        let mut printer = JSONBuilder::new().array_mode(true).build(vec![]);
+        SearcherBuilder::new()
+            .build()
+            .search_reader(&matcher, SHERLOCK, printer.sink(&matcher))
+            .unwrap();
+        printer.finish().unwrap();
+        let got = printer_contents(&mut printer);
+
+        assert_eq!("[]\n", got);
+    }
+
     #[test]
     fn max_matches() {
         let matcher = RegexMatcher::new(r"Watson").unwrap();
@@ -61,6 +61,7 @@ assert_eq!(output, expected);
 
 pub use crate::{
     color::{ColorError, ColorSpecs, UserColorSpec, default_color_specs},
+    csv::{CSV, CSVBuilder, CSVSink},
     hyperlink::{
         HyperlinkAlias, HyperlinkConfig, HyperlinkEnvironment,
         HyperlinkFormat, HyperlinkFormatError, hyperlink_aliases,
@@ -90,6 +91,7 @@ mod macros;
 
 mod color;
 mod counter;
+mod csv;
 mod hyperlink;
 #[cfg(feature = "serde")]
 mod json;
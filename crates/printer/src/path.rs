@@ -167,7 +167,9 @@ impl<W: WriteColor> PathPrinter<W> {
         &mut self,
         path: &PrinterPath,
     ) -> io::Result<hyperlink::InterpolatorStatus> {
-        let Some(hyperpath) = path.as_hyperlink() else {
+        let resolve_symlinks =
+            self.config.hyperlink.environment().is_resolve_symlinks();
+        let Some(hyperpath) = path.as_hyperlink(resolve_symlinks) else {
             return Ok(hyperlink::InterpolatorStatus::inactive());
         };
         let values = hyperlink::Values::new(hyperpath);
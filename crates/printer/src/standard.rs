@@ -40,14 +40,21 @@ struct Config {
     heading: bool,
     path: bool,
     only_matching: bool,
+    only_matching_context: bool,
     per_match: bool,
     per_match_one_line: bool,
     replacement: Arc<Option<Vec<u8>>>,
     max_columns: Option<u64>,
     max_columns_preview: bool,
     column: bool,
+    column_end: bool,
     byte_offset: bool,
+    line_number_width: usize,
+    prepend_pattern: bool,
+    print_match_count_at_end: bool,
     trim_ascii: bool,
+    trim_crlf: bool,
+    separator_heading: Arc<Option<Vec<u8>>>,
     separator_search: Arc<Option<Vec<u8>>>,
     separator_context: Arc<Option<Vec<u8>>>,
     separator_field_match: Arc<Vec<u8>>,
@@ -65,14 +72,21 @@ impl Default for Config {
             heading: false,
             path: true,
             only_matching: false,
+            only_matching_context: false,
             per_match: false,
             per_match_one_line: false,
             replacement: Arc::new(None),
             max_columns: None,
             max_columns_preview: false,
             column: false,
+            column_end: false,
             byte_offset: false,
+            line_number_width: 0,
+            prepend_pattern: false,
+            print_match_count_at_end: false,
             trim_ascii: false,
+            trim_crlf: false,
+            separator_heading: Arc::new(None),
             separator_search: Arc::new(None),
             separator_context: Arc::new(Some(b"--".to_vec())),
             separator_field_match: Arc::new(b":".to_vec()),
@@ -249,6 +263,28 @@ impl StandardBuilder {
         self
     }
 
+    /// Печатать в контекстных строках только конкретные совпадения вместо
+    /// всей строки.
+    ///
+    /// Когда включено, каждая контекстная строка усечена так, что отображаются
+    /// только совпадающие части. Контекстные строки, не содержащие ни одного
+    /// совпадения, полностью опускаются. Это не влияет на печать строк
+    /// совпадений, которой управляет опция `only_matching`.
+    ///
+    /// Обратите внимание, что при обычном поиске контекстные строки по
+    /// определению не содержат совпадений, и эта опция приводит к их
+    /// опусканию. Она в основном полезна в сочетании с инвертированным
+    /// поиском, при котором контекстные строки могут содержать совпадения.
+    ///
+    /// По умолчанию отключено; контекстные строки печатаются целиком.
+    pub fn only_matching_context(
+        &mut self,
+        yes: bool,
+    ) -> &mut StandardBuilder {
+        self.config.only_matching_context = yes;
+        self
+    }
+
     /// Печатать как минимум одну строку для каждого совпадения.
     ///
     /// Это похоже на опцию `only_matching`, за исключением того, что для
@@ -354,6 +390,66 @@ impl StandardBuilder {
         self
     }
 
+    /// Дополнительно печатать конечный номер столбца совпадения.
+    ///
+    /// Когда это включено вместе с `column`, поле столбца печатается как
+    /// `начало-конец`, где конечный номер столбца является 1-основанным и
+    /// включающим. Например, `3:10-20:строка`. Это удобно для редакторов,
+    /// которым для подсветки нужен весь диапазон совпадения.
+    ///
+    /// Без `column` эта опция не имеет эффекта.
+    ///
+    /// По умолчанию отключено.
+    pub fn column_end(&mut self, yes: bool) -> &mut StandardBuilder {
+        self.config.column_end = yes;
+        self
+    }
+
+    /// Установить минимальную ширину поля номера строки.
+    ///
+    /// Номера строк, содержащие меньше цифр, дополняются нулями слева до
+    /// данной ширины. Значение `0` (по умолчанию) отключает дополнение.
+    ///
+    /// Это полезно, когда вывод обрабатывается инструментами, которые
+    /// выравнивают столбцы.
+    pub fn line_number_width(
+        &mut self,
+        width: usize,
+    ) -> &mut StandardBuilder {
+        self.config.line_number_width = width;
+        self
+    }
+
+    /// Печатать паттерн, который произвёл совпадение, перед каждой строкой
+    /// совпадения.
+    ///
+    /// Паттерн отделяется от остальной прелюдии разделителем полей. Это
+    /// полезно при поиске с несколькими паттернами, чтобы узнать, какой
+    /// из них совпал на каждой строке. Если matcher не может определить,
+    /// какой паттерн произвёл совпадение, то поле паттерна опускается.
+    ///
+    /// По умолчанию отключено.
+    pub fn prepend_pattern(&mut self, yes: bool) -> &mut StandardBuilder {
+        self.config.prepend_pattern = yes;
+        self
+    }
+
+    /// Печатать строку с итоговым количеством совпавших строк после
+    /// совпадений каждого файла.
+    ///
+    /// Когда включено, после вывода совпадений каждого файла печатается
+    /// строка вида `N matches`, где `N` — количество совпавших
+    /// строк. Для файлов без совпадений итоговая строка не печатается.
+    ///
+    /// По умолчанию отключено.
+    pub fn print_match_count_at_end(
+        &mut self,
+        yes: bool,
+    ) -> &mut StandardBuilder {
+        self.config.print_match_count_at_end = yes;
+        self
+    }
+
     /// Печатать абсолютное смещение в байтах начала каждой напечатанной
     /// строки.
     ///
@@ -376,6 +472,19 @@ impl StandardBuilder {
         self
     }
 
+    /// Когда включено, завершающие символы возврата каретки (`\r`) будут
+    /// обрезаны из каждой печатаемой строки.
+    ///
+    /// Это полезно при поиске в файлах с окончаниями строк в стиле Windows
+    /// (`\r\n`) без настройки терминатора строки searcher на CRLF: сами
+    /// строки разбираются по `\n`, но `\r` не попадает в вывод.
+    ///
+    /// По умолчанию отключено.
+    pub fn trim_crlf(&mut self, yes: bool) -> &mut StandardBuilder {
+        self.config.trim_crlf = yes;
+        self
+    }
+
     /// Установить разделитель, используемый между наборами результатов
     /// поиска.
     ///
@@ -399,6 +508,19 @@ impl StandardBuilder {
         self
     }
 
+    /// Установить разделитель, печатаемый на отдельной строке после
+    /// заголовка с путём к файлу, но только когда включён режим `heading`.
+    ///
+    /// Если разделитель не установлен или пуст, то после заголовка ничего
+    /// не печатается. Это поведение по умолчанию.
+    pub fn heading_separator(
+        &mut self,
+        sep: Option<Vec<u8>>,
+    ) -> &mut StandardBuilder {
+        self.config.separator_heading = Arc::new(sep);
+        self
+    }
+
     /// Установить разделитель, используемый между несмежными прогонами
     /// контекста поиска, но только когда searcher настроен на сообщение
     /// о контекстных строках.
@@ -612,14 +734,21 @@ impl<W: WriteColor> Standard<W> {
         (supports_color && match_colored)
         // Функция column требует нахождения позиции первого совпадения.
         || self.config.column
+        // Конечный номер столбца требует нахождения конца совпадения.
+        || self.config.column_end
         // Требуется нахождение каждого совпадения для выполнения замены.
         || self.config.replacement.is_some()
         // Вывод строки для каждого совпадения требует нахождения каждого совпадения.
         || self.config.per_match
         // Вывод только совпадения требует нахождения каждого совпадения.
         || self.config.only_matching
+        // Усечение контекстных строк требует нахождения каждого совпадения.
+        || self.config.only_matching_context
         // Вычисление определённой статистики требует нахождения каждого совпадения.
         || self.config.stats
+        // Определение паттерна, который произвёл совпадение, требует
+        // нахождения каждого совпадения.
+        || self.config.prepend_pattern
     }
 }
 
@@ -831,7 +960,9 @@ impl<'p, 's, M: Matcher, W: WriteColor> Sink for StandardSink<'p, 's, M, W> {
         self.standard.matches.clear();
         self.replacer.clear();
 
-        if searcher.invert_match() {
+        if searcher.invert_match()
+            || self.standard.config.only_matching_context
+        {
             self.record_matches(searcher, ctx.bytes(), 0..ctx.bytes().len())?;
             self.replace(searcher, ctx.bytes(), 0..ctx.bytes().len())?;
         }
@@ -887,6 +1018,17 @@ impl<'p, 's, M: Matcher, W: WriteColor> Sink for StandardSink<'p, 's, M, W> {
         if let Some(offset) = self.binary_byte_offset {
             StandardImpl::new(searcher, self).write_binary_message(offset)?;
         }
+        if self.standard.config.print_match_count_at_end
+            && self.match_count > 0
+        {
+            let line = format!(
+                "{count} {noun}\n",
+                count = self.match_count,
+                noun =
+                    if self.match_count == 1 { "match" } else { "matches" },
+            );
+            self.standard.wtr.borrow_mut().write_all(line.as_bytes())?;
+        }
         if let Some(stats) = self.stats.as_mut() {
             stats.add_elapsed(self.start_time.elapsed());
             stats.add_searches(1);
@@ -894,6 +1036,7 @@ impl<'p, 's, M: Matcher, W: WriteColor> Sink for StandardSink<'p, 's, M, W> {
                 stats.add_searches_with_match(1);
             }
             stats.add_bytes_searched(finish.byte_count());
+            stats.add_lines_searched(finish.lines_scanned());
             stats.add_bytes_printed(self.standard.wtr.borrow().count());
         }
         Ok(())
@@ -962,7 +1105,10 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
     fn sink(&self) -> io::Result<()> {
         self.write_search_prelude()?;
         if self.sunk.matches().is_empty() {
-            if self.multi_line() && !self.is_context() {
+            if self.config().only_matching_context && self.is_context() {
+                // Контекстная строка без совпадений опускается целиком.
+                Ok(())
+            } else if self.multi_line() && !self.is_context() {
                 self.sink_fast_multi_line()
             } else {
                 self.sink_fast()
@@ -991,6 +1137,7 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
             self.sunk.absolute_byte_offset(),
             self.sunk.line_number(),
             None,
+            None,
         )?;
         self.write_line(self.sunk.bytes())
     }
@@ -1017,6 +1164,7 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
                 absolute_byte_offset,
                 self.sunk.line_number().map(|n| n + i as u64),
                 None,
+                None,
             )?;
             absolute_byte_offset += line.len() as u64;
 
@@ -1031,12 +1179,15 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
         debug_assert!(!self.sunk.matches().is_empty());
         debug_assert!(!self.multi_line() || self.is_context());
 
-        if self.config().only_matching {
+        let only_matching = self.config().only_matching
+            || (self.config().only_matching_context && self.is_context());
+        if only_matching {
             for &m in self.sunk.matches() {
                 self.write_prelude(
                     self.sunk.absolute_byte_offset() + m.start() as u64,
                     self.sunk.line_number(),
                     Some(m.start() as u64 + 1),
+                    Some(m.end() as u64),
                 )?;
 
                 let buf = &self.sunk.bytes()[m];
@@ -1048,6 +1199,7 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
                     self.sunk.absolute_byte_offset() + m.start() as u64,
                     self.sunk.line_number(),
                     Some(m.start() as u64 + 1),
+                    Some(m.end() as u64),
                 )?;
                 self.write_colored_line(&[m], self.sunk.bytes())?;
             }
@@ -1056,6 +1208,7 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
                 self.sunk.absolute_byte_offset(),
                 self.sunk.line_number(),
                 Some(self.sunk.matches()[0].start() as u64 + 1),
+                Some(self.sunk.matches()[0].end() as u64),
             )?;
             self.write_colored_line(self.sunk.matches(), self.sunk.bytes())?;
         }
@@ -1084,6 +1237,7 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
                 self.sunk.absolute_byte_offset() + line.start() as u64,
                 self.sunk.line_number().map(|n| n + count),
                 Some(matches[0].start() as u64 + 1),
+                Some(matches[0].end() as u64),
             )?;
             count += 1;
             self.trim_ascii_prefix(bytes, &mut line);
@@ -1129,6 +1283,7 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
                         self.sunk.absolute_byte_offset() + m.start() as u64,
                         self.sunk.line_number().map(|n| n + count),
                         Some(m.start() as u64 + 1),
+                        Some(m.end() as u64),
                     )?;
 
                     let this_line = line.with_end(upto);
@@ -1167,6 +1322,11 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
                     self.sunk.absolute_byte_offset() + line.start() as u64,
                     self.sunk.line_number().map(|n| n + count),
                     Some(m.start().saturating_sub(line.start()) as u64 + 1),
+                    Some(
+                        cmp::min(m.end(), line.end())
+                            .saturating_sub(line.start())
+                            as u64,
+                    ),
                 )?;
                 count += 1;
                 self.trim_line_terminator(bytes, &mut line);
@@ -1214,12 +1374,14 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
         absolute_byte_offset: u64,
         line_number: Option<u64>,
         column: Option<u64>,
+        column_end: Option<u64>,
     ) -> io::Result<()> {
         let mut prelude = PreludeWriter::new(self);
         prelude.start(line_number, column)?;
+        prelude.write_pattern()?;
         prelude.write_path()?;
         prelude.write_line_number(line_number)?;
-        prelude.write_column_number(column)?;
+        prelude.write_column_number(column, column_end)?;
         prelude.write_byte_offset(absolute_byte_offset)?;
         prelude.end()
     }
@@ -1242,6 +1404,11 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
                 self.sunk.matches(),
                 &mut 0,
             )?;
+        } else if self.config().trim_crlf {
+            let mut range = Match::new(0, line.len());
+            self.trim_line_terminator(line, &mut range);
+            self.write(&line[range])?;
+            self.write_line_term()?;
         } else {
             // self.write_trim(line)?;
             self.write(line)?;
@@ -1418,6 +1585,14 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
         }
         if self.config().heading {
             self.write_path_line()?;
+            if self.path().is_some() {
+                if let Some(ref sep) = *self.config().separator_heading {
+                    if !sep.is_empty() {
+                        self.write(sep)?;
+                        self.write_line_term()?;
+                    }
+                }
+            }
         }
         Ok(())
     }
@@ -1483,7 +1658,7 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
     }
 
     fn write_path_hyperlink(&self, path: &PrinterPath) -> io::Result<()> {
-        let status = self.start_hyperlink(path, None, None)?;
+        let status = self.start_hyperlink(path, None, None, None, None)?;
         self.write_path(path)?;
         self.end_hyperlink(status)
     }
@@ -1493,12 +1668,22 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
         path: &PrinterPath,
         line_number: Option<u64>,
         column: Option<u64>,
+        column_end: Option<u64>,
+        match_bytes: Option<&[u8]>,
     ) -> io::Result<hyperlink::InterpolatorStatus> {
-        let Some(hyperpath) = path.as_hyperlink() else {
+        let resolve_symlinks = self
+            .config()
+            .hyperlink
+            .environment()
+            .is_resolve_symlinks();
+        let Some(hyperpath) = path.as_hyperlink(resolve_symlinks) else {
             return Ok(hyperlink::InterpolatorStatus::inactive());
         };
-        let values =
-            hyperlink::Values::new(hyperpath).line(line_number).column(column);
+        let values = hyperlink::Values::new(hyperpath)
+            .line(line_number)
+            .column(column)
+            .column_end(column_end)
+            .match_bytes(match_bytes);
         self.sink.interpolator.begin(&values, &mut *self.wtr().borrow_mut())
     }
 
@@ -1559,6 +1744,11 @@ impl<'a, M: Matcher, W: WriteColor> StandardImpl<'a, M, W> {
 
     fn trim_line_terminator(&self, buf: &[u8], line: &mut Match) {
         trim_line_terminator(&self.searcher, buf, line);
+        if self.config().trim_crlf {
+            while buf[*line].last() == Some(&b'\r') {
+                *line = line.with_end(line.end() - 1);
+            }
+        }
     }
 
     fn has_line_terminator(&self, buf: &[u8]) -> bool {
@@ -1677,8 +1867,19 @@ impl<'a, M: Matcher, W: WriteColor> PreludeWriter<'a, M, W> {
         if self.config().hyperlink.format().is_line_dependent()
             || !self.config().heading
         {
-            self.interp_status =
-                self.std.start_hyperlink(path, line_number, column)?;
+            // Для переменной {match} интерполируется первое совпадение
+            // на строке, если оно есть.
+            let first = self.std.sunk.matches().first();
+            let column_end = first.map(|m| m.end() as u64);
+            let match_bytes = first
+                .and_then(|m| self.std.sunk.bytes().get(m.start()..m.end()));
+            self.interp_status = self.std.start_hyperlink(
+                path,
+                line_number,
+                column,
+                column_end,
+                match_bytes,
+            )?;
         }
         Ok(())
     }
@@ -1717,12 +1918,46 @@ impl<'a, M: Matcher, W: WriteColor> PreludeWriter<'a, M, W> {
         Ok(())
     }
 
+    /// Записать поле паттерна, который произвёл совпадение, если настроено
+    /// для этого.
+    ///
+    /// Это ничего не записывает для контекстных строк или когда matcher не
+    /// может определить, какой паттерн произвёл совпадение.
+    #[inline(always)]
+    fn write_pattern(&mut self) -> io::Result<()> {
+        if !self.config().prepend_pattern {
+            return Ok(());
+        }
+        let Some(&m) = self.std.sunk.matches().first() else {
+            return Ok(());
+        };
+        let Some(index) = self
+            .std
+            .sink
+            .matcher
+            .which_pattern_matched(self.std.sunk.bytes(), m)
+        else {
+            return Ok(());
+        };
+        let Some(pattern) = self.std.sink.matcher.pattern_str(index) else {
+            return Ok(());
+        };
+        let pattern = pattern.to_string();
+        self.write_separator()?;
+        self.std.write(pattern.as_bytes())?;
+        self.next_separator = PreludeSeparator::FieldSeparator;
+        Ok(())
+    }
+
     /// Записать поле номера строки, если оно присутствует.
     #[inline(always)]
     fn write_line_number(&mut self, line: Option<u64>) -> io::Result<()> {
         let Some(line_number) = line else { return Ok(()) };
         self.write_separator()?;
-        let n = DecimalFormatter::new(line_number);
+        let n = DecimalFormatter::with_min_width(
+            line_number,
+            self.config().line_number_width,
+        );
         self.std.write_spec(self.config().colors.line(), n.as_bytes())?;
         self.next_separator = PreludeSeparator::FieldSeparator;
         Ok(())
@@ -1731,7 +1966,11 @@ impl<'a, M: Matcher, W: WriteColor> PreludeWriter<'a, M, W> {
     /// Записать поле номера столбца, если оно присутствует и настроено
     /// для этого.
     #[inline(always)]
-    fn write_column_number(&mut self, column: Option<u64>) -> io::Result<()> {
+    fn write_column_number(
+        &mut self,
+        column: Option<u64>,
+        column_end: Option<u64>,
+    ) -> io::Result<()> {
         if !self.config().column {
             return Ok(());
         }
@@ -1739,6 +1978,14 @@ impl<'a, M: Matcher, W: WriteColor> PreludeWriter<'a, M, W> {
         self.write_separator()?;
         let n = DecimalFormatter::new(column_number);
         self.std.write_spec(self.config().colors.column(), n.as_bytes())?;
+        if self.config().column_end
+            && let Some(column_end) = column_end
+        {
+            self.std.write(b"-")?;
+            let n = DecimalFormatter::new(column_end);
+            self.std
+                .write_spec(self.config().colors.column(), n.as_bytes())?;
+        }
         self.next_separator = PreludeSeparator::FieldSeparator;
         Ok(())
     }
@@ -2188,6 +2435,78 @@ but Doctor Watson has to have it taken out for him and dusted,
         assert_eq_printed!(expected, got);
     }
 
+    #[test]
+    fn heading_separator() {
+        let matcher = RegexMatcher::new("Watson").unwrap();
+        let mut printer = StandardBuilder::new()
+            .heading(true)
+            .heading_separator(Some(b"---".to_vec()))
+            .build(NoColor::new(vec![]));
+        SearcherBuilder::new()
+            .line_number(false)
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK.as_bytes(),
+                printer.sink_with_path(&matcher, "sherlock"),
+            )
+            .unwrap();
+
+        let got = printer_contents(&mut printer);
+        let expected = "\
+sherlock
+---
+For the Doctor Watsons of this world, as opposed to the Sherlock
+but Doctor Watson has to have it taken out for him and dusted,
+";
+        assert_eq_printed!(expected, got);
+    }
+
+    #[test]
+    fn print_match_count_at_end() {
+        let matcher = RegexMatcher::new("Watson").unwrap();
+        let mut printer = StandardBuilder::new()
+            .print_match_count_at_end(true)
+            .build(NoColor::new(vec![]));
+        SearcherBuilder::new()
+            .line_number(false)
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK.as_bytes(),
+                printer.sink(&matcher),
+            )
+            .unwrap();
+
+        let got = printer_contents(&mut printer);
+        let expected = "\
+For the Doctor Watsons of this world, as opposed to the Sherlock
+but Doctor Watson has to have it taken out for him and dusted,
+2 matches
+";
+        assert_eq_printed!(expected, got);
+    }
+
+    #[test]
+    fn print_match_count_at_end_no_match() {
+        let matcher = RegexMatcher::new("Moriarty").unwrap();
+        let mut printer = StandardBuilder::new()
+            .print_match_count_at_end(true)
+            .build(NoColor::new(vec![]));
+        SearcherBuilder::new()
+            .line_number(false)
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK.as_bytes(),
+                printer.sink(&matcher),
+            )
+            .unwrap();
+
+        let got = printer_contents(&mut printer);
+        assert_eq_printed!("", got);
+    }
+
     #[test]
     fn no_heading() {
         let matcher = RegexMatcher::new("Watson").unwrap();
@@ -2358,6 +2677,31 @@ Watson
         assert_eq_printed!(expected, got);
     }
 
+    #[test]
+    fn trim_crlf() {
+        let matcher = RegexMatcher::new("Watson").unwrap();
+        let mut printer = StandardBuilder::new()
+            .trim_crlf(true)
+            .build(NoColor::new(vec![]));
+        SearcherBuilder::new()
+            .line_number(true)
+            .before_context(1)
+            .build()
+            .search_reader(
+                &matcher,
+                "Holmes\r\nWatson\r\n".as_bytes(),
+                printer.sink(&matcher),
+            )
+            .unwrap();
+
+        let got = printer_contents(&mut printer);
+        let expected = "\
+1-Holmes
+2:Watson
+";
+        assert_eq_printed!(expected, got);
+    }
+
     #[test]
     fn line_number() {
         let matcher = RegexMatcher::new("Watson").unwrap();
@@ -2456,6 +2800,57 @@ Watson
         assert_eq_printed!(expected, got);
     }
 
+    #[test]
+    fn column_number_with_end() {
+        let matcher = RegexMatcher::new("Watson").unwrap();
+        let mut printer = StandardBuilder::new()
+            .column(true)
+            .column_end(true)
+            .build(NoColor::new(vec![]));
+        SearcherBuilder::new()
+            .line_number(false)
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK.as_bytes(),
+                printer.sink(&matcher),
+            )
+            .unwrap();
+
+        let got = printer_contents(&mut printer);
+        let expected = "\
+16-21:For the Doctor Watsons of this world, as opposed to the Sherlock
+12-17:but Doctor Watson has to have it taken out for him and dusted,
+";
+        assert_eq_printed!(expected, got);
+    }
+
+    #[test]
+    fn column_number_with_end_per_match() {
+        let matcher = RegexMatcher::new("Watson").unwrap();
+        let mut printer = StandardBuilder::new()
+            .per_match(true)
+            .column(true)
+            .column_end(true)
+            .build(NoColor::new(vec![]));
+        SearcherBuilder::new()
+            .line_number(true)
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK.as_bytes(),
+                printer.sink(&matcher),
+            )
+            .unwrap();
+
+        let got = printer_contents(&mut printer);
+        let expected = "\
+1:16-21:For the Doctor Watsons of this world, as opposed to the Sherlock
+5:12-17:but Doctor Watson has to have it taken out for him and dusted,
+";
+        assert_eq_printed!(expected, got);
+    }
+
     #[test]
     fn byte_offset() {
         let matcher = RegexMatcher::new("Watson").unwrap();
@@ -3122,6 +3517,64 @@ line 3 x
         assert_eq_printed!(expected, got);
     }
 
+    #[test]
+    fn only_matching_context() {
+        let matcher = RegexMatcher::new("Sherlock").unwrap();
+        let mut printer = StandardBuilder::new()
+            .only_matching_context(true)
+            .build(NoColor::new(vec![]));
+        SearcherBuilder::new()
+            .line_number(true)
+            .invert_match(true)
+            .before_context(1)
+            .after_context(1)
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK.as_bytes(),
+                printer.sink(&matcher),
+            )
+            .unwrap();
+
+        let got = printer_contents(&mut printer);
+        let expected = "\
+1-Sherlock
+2:Holmeses, success in the province of detective work must always
+3-Sherlock
+4:can extract a clew from a wisp of straw or a flake of cigar ash;
+5:but Doctor Watson has to have it taken out for him and dusted,
+6:and exhibited clearly, with a label attached.
+";
+        assert_eq_printed!(expected, got);
+    }
+
+    #[test]
+    fn only_matching_context_no_matches() {
+        let matcher = RegexMatcher::new("Sherlock").unwrap();
+        let mut printer = StandardBuilder::new()
+            .only_matching_context(true)
+            .build(NoColor::new(vec![]));
+        SearcherBuilder::new()
+            .line_number(true)
+            .before_context(1)
+            .after_context(1)
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK.as_bytes(),
+                printer.sink(&matcher),
+            )
+            .unwrap();
+
+        // Контекстные строки не содержат совпадений и поэтому опускаются.
+        let got = printer_contents(&mut printer);
+        let expected = "\
+1:For the Doctor Watsons of this world, as opposed to the Sherlock
+3:be, to a very large extent, the result of luck. Sherlock Holmes
+";
+        assert_eq_printed!(expected, got);
+    }
+
     #[test]
     fn only_matching_max_columns() {
         let matcher = RegexMatcher::new("Doctor Watsons|Sherlock").unwrap();
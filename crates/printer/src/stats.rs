@@ -12,10 +12,13 @@ use crate::util::NiceDuration;
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Stats {
     elapsed: NiceDuration,
+    elapsed_max: Option<NiceDuration>,
+    elapsed_min: Option<NiceDuration>,
     searches: u64,
     searches_with_match: u64,
     bytes_searched: u64,
     bytes_printed: u64,
+    lines_searched: u64,
     matched_lines: u64,
     matches: u64,
 }
@@ -33,6 +36,29 @@ impl Stats {
         self.elapsed.0
     }
 
+    /// Возвращает среднее прошедшее время на один поиск, если был выполнен
+    /// хотя бы один поиск.
+    ///
+    /// Это общее прошедшее время, делённое на количество поисков.
+    pub fn elapsed_search_per_file_avg(&self) -> Option<Duration> {
+        if self.searches == 0 {
+            return None;
+        }
+        Some(self.elapsed.0 / u32::try_from(self.searches).unwrap_or(u32::MAX))
+    }
+
+    /// Возвращает наибольшее прошедшее время среди отдельных поисков, если
+    /// был выполнен хотя бы один поиск.
+    pub fn elapsed_max(&self) -> Option<Duration> {
+        self.elapsed_max.map(|d| d.0)
+    }
+
+    /// Возвращает наименьшее прошедшее время среди отдельных поисков, если
+    /// был выполнен хотя бы один поиск.
+    pub fn elapsed_min(&self) -> Option<Duration> {
+        self.elapsed_min.map(|d| d.0)
+    }
+
     /// Возвращает общее количество выполненных поисков.
     pub fn searches(&self) -> u64 {
         self.searches
@@ -53,6 +79,12 @@ impl Stats {
         self.bytes_printed
     }
 
+    /// Возвращает общее количество строк, которые были просмотрены,
+    /// независимо от того, совпали они или нет.
+    pub fn lines_searched(&self) -> u64 {
+        self.lines_searched
+    }
+
     /// Возвращает общее количество строк, которые участвовали в совпадении.
     ///
     /// Когда совпадения могут содержать несколько строк, это включает каждую строку,
@@ -69,8 +101,17 @@ impl Stats {
     }
 
     /// Добавляет к прошедшему времени.
+    ///
+    /// Каждый вызов рассматривается как время одного поиска и учитывается
+    /// при вычислении наибольшего и наименьшего прошедшего времени.
     pub fn add_elapsed(&mut self, duration: Duration) {
         self.elapsed.0 += duration;
+        if self.elapsed_max.map_or(true, |max| duration > max.0) {
+            self.elapsed_max = Some(NiceDuration(duration));
+        }
+        if self.elapsed_min.map_or(true, |min| duration < min.0) {
+            self.elapsed_min = Some(NiceDuration(duration));
+        }
     }
 
     /// Добавляет к количеству выполненных поисков.
@@ -93,6 +134,11 @@ impl Stats {
         self.bytes_printed += n;
     }
 
+    /// Добавляет к общему количеству просмотренных строк.
+    pub fn add_lines_searched(&mut self, n: u64) {
+        self.lines_searched += n;
+    }
+
     /// Добавляет к общему количеству строк, которые участвовали в совпадении.
     pub fn add_matched_lines(&mut self, n: u64) {
         self.matched_lines += n;
@@ -118,11 +164,18 @@ impl<'a> Add<&'a Stats> for Stats {
     fn add(self, rhs: &'a Stats) -> Stats {
         Stats {
             elapsed: NiceDuration(self.elapsed.0 + rhs.elapsed.0),
+            elapsed_max: combine(self.elapsed_max, rhs.elapsed_max, |a, b| {
+                a.max(b)
+            }),
+            elapsed_min: combine(self.elapsed_min, rhs.elapsed_min, |a, b| {
+                a.min(b)
+            }),
             searches: self.searches + rhs.searches,
             searches_with_match: self.searches_with_match
                 + rhs.searches_with_match,
             bytes_searched: self.bytes_searched + rhs.bytes_searched,
             bytes_printed: self.bytes_printed + rhs.bytes_printed,
+            lines_searched: self.lines_searched + rhs.lines_searched,
             matched_lines: self.matched_lines + rhs.matched_lines,
             matches: self.matches + rhs.matches,
         }
@@ -138,15 +191,33 @@ impl AddAssign for Stats {
 impl<'a> AddAssign<&'a Stats> for Stats {
     fn add_assign(&mut self, rhs: &'a Stats) {
         self.elapsed.0 += rhs.elapsed.0;
+        self.elapsed_max =
+            combine(self.elapsed_max, rhs.elapsed_max, |a, b| a.max(b));
+        self.elapsed_min =
+            combine(self.elapsed_min, rhs.elapsed_min, |a, b| a.min(b));
         self.searches += rhs.searches;
         self.searches_with_match += rhs.searches_with_match;
         self.bytes_searched += rhs.bytes_searched;
         self.bytes_printed += rhs.bytes_printed;
+        self.lines_searched += rhs.lines_searched;
         self.matched_lines += rhs.matched_lines;
         self.matches += rhs.matches;
     }
 }
 
+/// Объединяет два опциональных значения длительности данной функцией,
+/// возвращая присутствующее значение, когда присутствует только одно.
+fn combine(
+    lhs: Option<NiceDuration>,
+    rhs: Option<NiceDuration>,
+    f: impl Fn(Duration, Duration) -> Duration,
+) -> Option<NiceDuration> {
+    match (lhs, rhs) {
+        (Some(a), Some(b)) => Some(NiceDuration(f(a.0, b.0))),
+        (a, b) => a.or(b),
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for Stats {
     fn serialize<S: serde::Serializer>(
@@ -155,7 +226,7 @@ impl serde::Serialize for Stats {
     ) -> Result<S::Ok, S::Error> {
         use serde::ser::SerializeStruct;
 
-        let mut state = s.serialize_struct("Stats", 7)?;
+        let mut state = s.serialize_struct("Stats", 8)?;
         state.serialize_field("elapsed", &self.elapsed)?;
         state.serialize_field("searches", &self.searches)?;
         state.serialize_field(
@@ -164,6 +235,7 @@ impl serde::Serialize for Stats {
         )?;
         state.serialize_field("bytes_searched", &self.bytes_searched)?;
         state.serialize_field("bytes_printed", &self.bytes_printed)?;
+        state.serialize_field("lines_searched", &self.lines_searched)?;
         state.serialize_field("matched_lines", &self.matched_lines)?;
         state.serialize_field("matches", &self.matches)?;
         state.end()
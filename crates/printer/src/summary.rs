@@ -1,5 +1,6 @@
 use std::{
     cell::RefCell,
+    collections::BTreeSet,
     io::{self, Write},
     path::Path,
     sync::Arc,
@@ -69,6 +70,13 @@ pub enum SummaryKind {
     /// Если настройка `path` включена, то количество предваряется
     /// соответствующим путём к файлу.
     CountMatches,
+    /// Показать только количество различных по содержимому совпадающих
+    /// строк, которые были найдены. Идентичные совпадающие строки
+    /// считаются один раз.
+    ///
+    /// Если настройка `path` включена, то количество предваряется
+    /// соответствующим путём к файлу.
+    CountUniqueLines,
     /// Показать только путь к файлу, если и только если было найдено
     /// совпадение.
     ///
@@ -83,6 +91,15 @@ pub enum SummaryKind {
     /// Если путь к файлу не предоставлен, то поиск немедленно остановится
     /// и вернёт ошибку.
     PathWithoutMatch,
+    /// Показать количество совпадающих строк, за которым следует путь к
+    /// файлу, для каждого искомого файла, как `grep -c`.
+    ///
+    /// Количество и путь разделяются разделителем полей.
+    ///
+    /// Это игнорирует настройку `path` и всегда показывает путь к файлу.
+    /// Если путь к файлу не предоставлен, то поиск немедленно остановится
+    /// и вернёт ошибку.
+    PathsWithMatchCount,
     /// Не показывать никакого вывода и остановить поиск, как только
     /// найдено совпадение.
     ///
@@ -108,8 +125,9 @@ impl SummaryKind {
         use self::SummaryKind::*;
 
         match *self {
-            PathWithMatch | PathWithoutMatch => true,
-            Count | CountMatches | QuietWithMatch | QuietWithoutMatch => false,
+            PathWithMatch | PathWithoutMatch | PathsWithMatchCount => true,
+            Count | CountMatches | CountUniqueLines | QuietWithMatch
+            | QuietWithoutMatch => false,
         }
     }
 
@@ -121,8 +139,10 @@ impl SummaryKind {
 
         match *self {
             CountMatches => true,
-            Count | PathWithMatch | PathWithoutMatch | QuietWithMatch
-            | QuietWithoutMatch => false,
+            Count | CountUniqueLines | PathWithMatch | PathWithoutMatch
+            | PathsWithMatchCount | QuietWithMatch | QuietWithoutMatch => {
+                false
+            }
         }
     }
 
@@ -134,9 +154,8 @@ impl SummaryKind {
 
         match *self {
             PathWithMatch | QuietWithMatch => true,
-            Count | CountMatches | PathWithoutMatch | QuietWithoutMatch => {
-                false
-            }
+            Count | CountMatches | CountUniqueLines | PathWithoutMatch
+            | PathsWithMatchCount | QuietWithoutMatch => false,
         }
     }
 }
@@ -426,6 +445,7 @@ impl<W: WriteColor> Summary<W> {
             path: None,
             start_time: Instant::now(),
             match_count: 0,
+            unique_lines: BTreeSet::new(),
             binary_byte_offset: None,
             stats,
         }
@@ -463,6 +483,7 @@ impl<W: WriteColor> Summary<W> {
             path: Some(ppath),
             start_time: Instant::now(),
             match_count: 0,
+            unique_lines: BTreeSet::new(),
             binary_byte_offset: None,
             stats,
         }
@@ -510,6 +531,7 @@ pub struct SummarySink<'p, 's, M: Matcher, W> {
     path: Option<PrinterPath<'p>>,
     start_time: Instant,
     match_count: u64,
+    unique_lines: BTreeSet<Vec<u8>>,
     binary_byte_offset: Option<u64>,
     stats: Option<Stats>,
 }
@@ -612,8 +634,14 @@ impl<'p, 's, M: Matcher, W: WriteColor> SummarySink<'p, 's, M, W> {
     fn start_hyperlink(
         &mut self,
     ) -> io::Result<hyperlink::InterpolatorStatus> {
+        let resolve_symlinks = self
+            .summary
+            .config
+            .hyperlink
+            .environment()
+            .is_resolve_symlinks();
         let Some(hyperpath) =
-            self.path.as_ref().and_then(|p| p.as_hyperlink())
+            self.path.as_ref().and_then(|p| p.as_hyperlink(resolve_symlinks))
         else {
             return Ok(hyperlink::InterpolatorStatus::inactive());
         };
@@ -687,6 +715,19 @@ impl<'p, 's, M: Matcher, W: WriteColor> Sink for SummarySink<'p, 's, M, W> {
         } else {
             self.match_count += 1;
         }
+        if self.summary.config.kind == SummaryKind::CountUniqueLines {
+            let lineterm = searcher.line_terminator();
+            for line in mat.lines() {
+                let mut line = line;
+                if lineterm.is_suffix(line) {
+                    line = &line[..line.len() - 1];
+                    if lineterm.is_crlf() && line.last() == Some(&b'\r') {
+                        line = &line[..line.len() - 1];
+                    }
+                }
+                self.unique_lines.insert(line.to_vec());
+            }
+        }
         if let Some(ref mut stats) = self.stats {
             stats.add_matches(sink_match_count);
             stats.add_matched_lines(mat.lines().count() as u64);
@@ -723,6 +764,7 @@ impl<'p, 's, M: Matcher, W: WriteColor> Sink for SummarySink<'p, 's, M, W> {
         self.summary.wtr.borrow_mut().reset_count();
         self.start_time = Instant::now();
         self.match_count = 0;
+        self.unique_lines.clear();
         self.binary_byte_offset = None;
         Ok(true)
     }
@@ -740,6 +782,7 @@ impl<'p, 's, M: Matcher, W: WriteColor> Sink for SummarySink<'p, 's, M, W> {
                 stats.add_searches_with_match(1);
             }
             stats.add_bytes_searched(finish.byte_count());
+            stats.add_lines_searched(finish.lines_scanned());
             stats.add_bytes_printed(self.summary.wtr.borrow().count());
         }
         // Если наш метод обнаружения бинарных данных говорит завершить
@@ -772,6 +815,7 @@ impl<'p, 's, M: Matcher, W: WriteColor> Sink for SummarySink<'p, 's, M, W> {
             // ещё будет содержать количество совпадений, но «официальное»
             // количество совпадений должно быть нулевым.
             self.match_count = 0;
+            self.unique_lines.clear();
             return Ok(());
         }
 
@@ -785,6 +829,14 @@ impl<'p, 's, M: Matcher, W: WriteColor> Sink for SummarySink<'p, 's, M, W> {
                     self.write_line_term(searcher)?;
                 }
             }
+            SummaryKind::CountUniqueLines => {
+                if show_count {
+                    self.write_path_field()?;
+                    let count = self.unique_lines.len().to_string();
+                    self.write(count.as_bytes())?;
+                    self.write_line_term(searcher)?;
+                }
+            }
             SummaryKind::CountMatches => {
                 if show_count {
                     self.write_path_field()?;
@@ -806,6 +858,14 @@ impl<'p, 's, M: Matcher, W: WriteColor> Sink for SummarySink<'p, 's, M, W> {
                     self.write_path_line(searcher)?;
                 }
             }
+            SummaryKind::PathsWithMatchCount => {
+                if show_count {
+                    self.write(self.match_count.to_string().as_bytes())?;
+                    self.write(&self.summary.config.separator_field)?;
+                    self.write_path()?;
+                    self.write_line_term(searcher)?;
+                }
+            }
             SummaryKind::QuietWithMatch | SummaryKind::QuietWithoutMatch => {}
         }
         Ok(())
@@ -1088,6 +1148,40 @@ and exhibited clearly, with a label attached.
         assert_eq_printed!("", got);
     }
 
+    #[test]
+    fn paths_with_match_count() {
+        let matcher = RegexMatcher::new(r"Watson").unwrap();
+        let mut printer = SummaryBuilder::new()
+            .kind(SummaryKind::PathsWithMatchCount)
+            .build_no_color(vec![]);
+        SearcherBuilder::new()
+            .build()
+            .search_reader(
+                &matcher,
+                SHERLOCK,
+                printer.sink_with_path(&matcher, "sherlock"),
+            )
+            .unwrap();
+
+        let got = printer_contents(&mut printer);
+        assert_eq_printed!("2:sherlock
+", got);
+    }
+
+    #[test]
+    fn paths_with_match_count_error() {
+        let matcher = RegexMatcher::new(r"Watson").unwrap();
+        let mut printer = SummaryBuilder::new()
+            .kind(SummaryKind::PathsWithMatchCount)
+            .build_no_color(vec![]);
+        let res = SearcherBuilder::new().build().search_reader(
+            &matcher,
+            SHERLOCK,
+            printer.sink(&matcher),
+        );
+        assert!(res.is_err());
+    }
+
     #[test]
     fn path_without_match_found() {
         let matcher = RegexMatcher::new(r"ZZZZZZZZZ").unwrap();
@@ -341,9 +341,14 @@ impl<'a> PrinterPath<'a> {
     /// А именно, вычисление гиперссылки может потребовать касания файловой системы
     /// (например, для каноникализации пути), и это может завершиться ошибкой. Эта ошибка
     /// молчалива, но логируется.
-    pub(crate) fn as_hyperlink(&self) -> Option<&HyperlinkPath> {
+    pub(crate) fn as_hyperlink(
+        &self,
+        resolve_symlinks: bool,
+    ) -> Option<&HyperlinkPath> {
         self.hyperlink
-            .get_or_init(|| HyperlinkPath::from_path(self.as_path()))
+            .get_or_init(|| {
+                HyperlinkPath::from_path(self.as_path(), resolve_symlinks)
+            })
             .as_ref()
     }
 
@@ -420,7 +425,18 @@ impl DecimalFormatter {
     const MAX_U64_LEN: usize = 20;
 
     /// Создаёт новый десятичный форматтер для данного 64-битного беззнакового целого числа.
-    pub(crate) fn new(mut n: u64) -> DecimalFormatter {
+    pub(crate) fn new(n: u64) -> DecimalFormatter {
+        DecimalFormatter::with_min_width(n, 0)
+    }
+
+    /// Как `new`, но дополняет число нулями слева до как минимум `min_width`
+    /// цифр. Значения `min_width` больше максимальной длины `u64`
+    /// обрезаются до этой длины.
+    pub(crate) fn with_min_width(
+        mut n: u64,
+        min_width: usize,
+    ) -> DecimalFormatter {
+        let min_width = std::cmp::min(min_width, Self::MAX_U64_LEN);
         let mut buf = [0; Self::MAX_U64_LEN];
         let mut i = buf.len();
         loop {
@@ -429,7 +445,7 @@ impl DecimalFormatter {
             let digit = u8::try_from(n % 10).unwrap();
             n /= 10;
             buf[i] = b'0' + digit;
-            if n == 0 {
+            if n == 0 && buf.len() - i >= min_width {
                 break;
             }
         }
@@ -601,4 +617,21 @@ mod tests {
             assert_eq!(std(n), fmt(n));
         }
     }
+
+    #[test]
+    fn custom_decimal_format_min_width() {
+        let fmt = |n: u64, width: usize| {
+            let bytes =
+                DecimalFormatter::with_min_width(n, width).as_bytes().to_vec();
+            String::from_utf8(bytes).unwrap()
+        };
+
+        assert_eq!("7", fmt(7, 0));
+        assert_eq!("7", fmt(7, 1));
+        assert_eq!("007", fmt(7, 3));
+        assert_eq!("123", fmt(123, 3));
+        assert_eq!("0123", fmt(123, 4));
+        assert_eq!("0000", fmt(0, 4));
+        assert_eq!(u64::MAX.to_string(), fmt(u64::MAX, 30));
+    }
 }
@@ -40,6 +40,7 @@ pub(crate) struct Config {
     pub(crate) word: bool,
     pub(crate) fixed_strings: bool,
     pub(crate) whole_line: bool,
+    pub(crate) simd_acceleration: bool,
 }
 
 impl Default for Config {
@@ -64,6 +65,7 @@ impl Default for Config {
             word: false,
             fixed_strings: false,
             whole_line: false,
+            simd_acceleration: true,
         }
     }
 }
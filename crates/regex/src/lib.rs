@@ -5,7 +5,9 @@ An implementation of `grep-matcher`'s `Matcher` trait for Rust's regex engine.
 
 pub use crate::{
     error::{Error, ErrorKind},
-    matcher::{RegexCaptures, RegexMatcher, RegexMatcherBuilder},
+    matcher::{
+        CompilationInfo, RegexCaptures, RegexMatcher, RegexMatcherBuilder,
+    },
 };
 
 mod ast;
@@ -4,7 +4,7 @@ use {
         NoError,
     },
     regex_automata::{
-        Input, PatternID, meta::Regex,
+        Anchored, Input, PatternID, meta::Regex,
         util::captures::Captures as AutomataCaptures,
     },
 };
@@ -54,6 +54,8 @@ impl RegexMatcherBuilder {
         &self,
         patterns: &[P],
     ) -> Result<RegexMatcher, Error> {
+        let pattern_strings: Vec<String> =
+            patterns.iter().map(|p| p.as_ref().to_string()).collect();
         let mut chir = self.config.build_many(patterns)?;
         // 'whole_line' is a strict subset of 'word', so when it is enabled,
         // we don't need to both with any specific to word matching.
@@ -81,7 +83,15 @@ impl RegexMatcherBuilder {
         // support it.
         let mut config = self.config.clone();
         config.line_terminator = chir.line_terminator();
-        Ok(RegexMatcher { config, regex, fast_line_regex, non_matching_bytes })
+        Ok(RegexMatcher {
+            config,
+            patterns: pattern_strings,
+            regex,
+            fast_line_regex,
+            non_matching_bytes,
+            pattern_regexes: std::sync::OnceLock::new(),
+            compilation_info: std::sync::OnceLock::new(),
+        })
     }
 
     /// Build a new matcher from a plain alternation of literals.
@@ -359,6 +369,26 @@ impl RegexMatcherBuilder {
         self.config.whole_line = yes;
         self
     }
+
+    /// Whether SIMD acceleration should be used when searching, if it is
+    /// available.
+    ///
+    /// This is principally useful for debugging and benchmarking, where one
+    /// wants to compare searching with and without SIMD acceleration.
+    ///
+    /// Note that the underlying regex engine does not currently expose a
+    /// stable API for controlling SIMD usage, so disabling this is presently
+    /// a no-op. The setting exists so that it can be wired up if and when
+    /// such an API becomes available.
+    ///
+    /// This is enabled by default.
+    pub fn simd_acceleration(
+        &mut self,
+        yes: bool,
+    ) -> &mut RegexMatcherBuilder {
+        self.config.simd_acceleration = yes;
+        self
+    }
 }
 
 /// An implementation of the `Matcher` trait using Rust's standard regex
@@ -367,6 +397,9 @@ impl RegexMatcherBuilder {
 pub struct RegexMatcher {
     /// The configuration specified by the caller.
     config: Config,
+    /// The original pattern strings provided by the caller, in the order
+    /// given.
+    patterns: Vec<String>,
     /// The regular expression compiled from the pattern provided by the
     /// caller.
     regex: Regex,
@@ -377,6 +410,13 @@ pub struct RegexMatcher {
     fast_line_regex: Option<Regex>,
     /// A set of bytes that will never appear in a match.
     non_matching_bytes: ByteSet,
+    /// Lazily compiled per-pattern regexes, used only to attribute a match
+    /// to the original pattern that produced it. An entry is `None` when
+    /// the corresponding pattern failed to compile on its own (which should
+    /// be impossible, since the alternation of all patterns compiled).
+    pattern_regexes: std::sync::OnceLock<Vec<Option<Regex>>>,
+    /// Lazily computed diagnostics about the compiled regex.
+    compilation_info: std::sync::OnceLock<CompilationInfo>,
 }
 
 impl RegexMatcher {
@@ -401,6 +441,130 @@ impl RegexMatcher {
     pub fn new_line_matcher(pattern: &str) -> Result<RegexMatcher, Error> {
         RegexMatcherBuilder::new().line_terminator(Some(b'\n')).build(pattern)
     }
+
+    /// Return the original pattern string this matcher was built from.
+    ///
+    /// For a matcher built from multiple patterns, this returns the first
+    /// pattern. Use `patterns` to get all of them.
+    ///
+    /// This is useful for error reporting, where a caller holding a matcher
+    /// wants to say which pattern misbehaved.
+    pub fn pattern_str(&self) -> &str {
+        self.patterns.first().map(|p| p.as_str()).unwrap_or("")
+    }
+
+    /// Return all of the original pattern strings this matcher was built
+    /// from, in the order they were given.
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Return the lazily compiled per-pattern regexes, compiling them on
+    /// first use.
+    fn pattern_regexes(&self) -> &[Option<Regex>] {
+        self.pattern_regexes.get_or_init(|| {
+            self.patterns
+                .iter()
+                .map(|pattern| {
+                    let mut chir =
+                        self.config.build_many(&[pattern]).ok()?;
+                    if chir.config().whole_line {
+                        chir = chir.into_whole_line();
+                    } else if chir.config().word {
+                        chir = chir.into_word();
+                    }
+                    chir.to_regex().ok()
+                })
+                .collect()
+        })
+    }
+
+    /// Returns diagnostics about how this matcher's pattern was compiled.
+    ///
+    /// The diagnostics are computed on first use and cached. When the
+    /// pattern cannot be re-analyzed (which should be impossible, since it
+    /// compiled in the first place), the returned info reports zero NFA
+    /// states.
+    pub fn compilation_info(&self) -> CompilationInfo {
+        self.compilation_info
+            .get_or_init(|| {
+                let zero = CompilationInfo {
+                    nfa_states: 0,
+                    nfa_memory_usage: 0,
+                    determinized: false,
+                    uses_look_around: false,
+                };
+                let Ok(mut chir) = self.config.build_many(&self.patterns)
+                else {
+                    return zero;
+                };
+                if chir.config().whole_line {
+                    chir = chir.into_whole_line();
+                } else if chir.config().word {
+                    chir = chir.into_word();
+                }
+                let uses_look_around =
+                    !chir.hir().properties().look_set().is_empty();
+                let nfa = regex_automata::nfa::thompson::Compiler::new()
+                    .build_from_hir(chir.hir());
+                let Ok(nfa) = nfa else {
+                    return CompilationInfo { uses_look_around, ..zero };
+                };
+                CompilationInfo {
+                    nfa_states: nfa.states().len(),
+                    nfa_memory_usage: nfa.memory_usage(),
+                    determinized: !nfa
+                        .look_set_any()
+                        .contains_word_unicode(),
+                    uses_look_around,
+                }
+            })
+            .clone()
+    }
+}
+
+/// Summary diagnostics about how a regex matcher was compiled.
+///
+/// This is useful for understanding why a particular pattern may be slow to
+/// search with. For example, a pattern with many NFA states or one that uses
+/// look-around may prevent the faster DFA based engines from being used.
+#[derive(Clone, Debug)]
+pub struct CompilationInfo {
+    nfa_states: usize,
+    nfa_memory_usage: usize,
+    determinized: bool,
+    uses_look_around: bool,
+}
+
+impl CompilationInfo {
+    /// Returns the number of states in the Thompson NFA compiled from the
+    /// pattern. Bigger numbers generally correspond to slower searches.
+    pub fn nfa_states(&self) -> usize {
+        self.nfa_states
+    }
+
+    /// Returns the approximate amount of heap memory, in bytes, used by the
+    /// Thompson NFA compiled from the pattern.
+    pub fn nfa_memory_usage(&self) -> usize {
+        self.nfa_memory_usage
+    }
+
+    /// Returns true when the pattern is believed to be amenable to
+    /// determinization. That is, when the faster DFA based engines can be
+    /// used for searching.
+    ///
+    /// Note that this is a heuristic. In particular, the principal reason
+    /// why determinization fails in practice is the presence of a Unicode
+    /// word boundary, which is what this checks for.
+    pub fn determinized(&self) -> bool {
+        self.determinized
+    }
+
+    /// Returns true when the pattern makes use of look-around assertions,
+    /// such as `^`, `$` or `\b`.
+    pub fn uses_look_around(&self) -> bool {
+        self.uses_look_around
+    }
 }
 
 // This implementation just dispatches on the internal matcher impl except
@@ -435,6 +599,45 @@ impl Matcher for RegexMatcher {
         self.regex.group_info().to_index(PatternID::ZERO, name)
     }
 
+    #[inline]
+    fn which_pattern_matched(
+        &self,
+        haystack: &[u8],
+        m: Match,
+    ) -> Option<usize> {
+        if self.patterns.len() <= 1 {
+            return Some(0);
+        }
+        // The first pattern that matches at the position where the
+        // alternation's match began is the one that produced it.
+        for (i, regex) in self.pattern_regexes().iter().enumerate() {
+            let Some(ref regex) = *regex else { continue };
+            let input = Input::new(haystack)
+                .span(m.start()..m.end())
+                .anchored(Anchored::Yes);
+            if regex.search_half(&input).is_some() {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn pattern_str(&self, index: usize) -> Option<&str> {
+        self.patterns.get(index).map(|p| p.as_str())
+    }
+
+    #[inline]
+    fn capture_names(&self) -> Option<Vec<Option<String>>> {
+        Some(
+            self.regex
+                .group_info()
+                .pattern_names(PatternID::ZERO)
+                .map(|name| name.map(|n| n.to_string()))
+                .collect(),
+        )
+    }
+
     #[inline]
     fn try_find_iter<F, E>(
         &self,
@@ -667,4 +870,18 @@ mod tests {
         let m = matcher.find_candidate_line(b"afoo ").unwrap().unwrap();
         assert!(is_candidate(m));
     }
+    #[test]
+    fn compilation_info() {
+        let matcher = RegexMatcher::new(r"hello \w+").unwrap();
+        let info = matcher.compilation_info();
+        assert!(info.nfa_states() > 0);
+        assert!(info.nfa_memory_usage() > 0);
+        assert!(info.determinized());
+        assert!(!info.uses_look_around());
+
+        let matcher = RegexMatcher::new(r"\bfoo\b").unwrap();
+        let info = matcher.compilation_info();
+        assert!(info.uses_look_around());
+        assert!(!info.determinized());
+    }
 }
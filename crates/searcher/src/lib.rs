@@ -94,6 +94,8 @@ pub use crate::{
         sinks,
     },
 };
+#[cfg(feature = "tar")]
+pub use crate::tar::{TarEntryFilter, TarSearcher, TarSearcherBuilder};
 
 #[macro_use]
 mod macros;
@@ -102,5 +104,7 @@ mod line_buffer;
 mod lines;
 mod searcher;
 mod sink;
+#[cfg(feature = "tar")]
+mod tar;
 #[cfg(test)]
 mod testutil;
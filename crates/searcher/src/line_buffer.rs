@@ -60,6 +60,10 @@ pub(crate) enum BinaryDetection {
     /// он встречается, то он заменяется терминатором строки. Буфер строк
     /// гарантирует, что этот байт никогда не будет наблюдаем вызывающим кодом.
     Convert(u8),
+    /// Как `Quit`, но указанный байт ищется только в первых `usize` байтах
+    /// содержимого. Данные глубже этого префикса не проверяются, поэтому
+    /// двоичное содержимое там может остаться незамеченным.
+    HeuristicPrefix(usize, u8),
 }
 
 impl Default for BinaryDetection {
@@ -73,7 +77,8 @@ impl BinaryDetection {
     /// чтобы буфер строк прекратил чтение данных при обнаружении двоичных данных.
     fn is_quit(&self) -> bool {
         match *self {
-            BinaryDetection::Quit(_) => true,
+            BinaryDetection::Quit(_)
+            | BinaryDetection::HeuristicPrefix(_, _) => true,
             _ => false,
         }
     }
@@ -91,6 +96,9 @@ struct Config {
     buffer_alloc: BufferAllocation,
     /// Когда установлено, наличие указанного байта указывает на двоичное содержимое.
     binary: BinaryDetection,
+    /// Когда установлено, строки длиннее этого количества байтов молча
+    /// пропускаются.
+    max_line_length: Option<usize>,
 }
 
 impl Default for Config {
@@ -100,6 +108,7 @@ impl Default for Config {
             lineterm: b'\n',
             buffer_alloc: BufferAllocation::default(),
             binary: BinaryDetection::default(),
+            max_line_length: None,
         }
     }
 }
@@ -126,6 +135,7 @@ impl LineBufferBuilder {
             end: 0,
             absolute_byte_offset: 0,
             binary_byte_offset: None,
+            skipping_line: false,
         }
     }
 
@@ -186,6 +196,27 @@ impl LineBufferBuilder {
         self
     }
 
+    /// Установить максимальную длину строки (в байтах) для этого буфера.
+    ///
+    /// Строки, превышающие эту длину, молча пропускаются: их байты
+    /// отбрасываются, но их терминаторы строк сохраняются, так что
+    /// нумерация строк последующего содержимого не изменяется. Это полезно
+    /// при поиске в данных с очень длинными строками (например, журналы с
+    /// большими JSON-объектами), которые иначе потребовали бы расширения
+    /// буфера для размещения всей строки.
+    ///
+    /// Обратите внимание, что абсолютные смещения байтов, сообщаемые этим
+    /// буфером, не учитывают отброшенные байты.
+    ///
+    /// По умолчанию не установлено.
+    pub(crate) fn max_line_length(
+        &mut self,
+        limit: Option<usize>,
+    ) -> &mut LineBufferBuilder {
+        self.config.max_line_length = limit;
+        self
+    }
+
     /// Следует ли включать обнаружение двоичных данных или нет. В зависимости от настройки,
     /// это может привести к тому, что буфер строк сообщит о EOF раньше или
     /// заставит буфер строк очистить данные.
@@ -318,6 +349,10 @@ pub(crate) struct LineBuffer {
     /// Если двоичные данные были найдены, это записывает абсолютное смещение байта,
     /// при котором они были впервые обнаружены.
     binary_byte_offset: Option<u64>,
+    /// Истинно, когда текущая частичная строка превысила максимальную длину
+    /// строки и её оставшиеся байты отбрасываются до следующего терминатора
+    /// строки.
+    skipping_line: bool,
 }
 
 impl LineBuffer {
@@ -336,6 +371,7 @@ impl LineBuffer {
         self.end = 0;
         self.absolute_byte_offset = 0;
         self.binary_byte_offset = None;
+        self.skipping_line = false;
     }
 
     /// Абсолютное смещение байта, которое соответствует начальным смещениям
@@ -418,6 +454,7 @@ impl LineBuffer {
                 // Мы закончили чтение навсегда только после того, как вызывающий
                 // потребил всё.
                 self.last_lineterm = self.end;
+                self.skip_overlong_lines();
                 return Ok(!self.buffer().is_empty());
             }
 
@@ -457,15 +494,107 @@ impl LineBuffer {
                         }
                     }
                 }
+                BinaryDetection::HeuristicPrefix(prefix, byte) => {
+                    // Проверяется только та часть прочитанного, которая
+                    // попадает в первые `prefix` байтов содержимого.
+                    let absolute_start =
+                        self.absolute_byte_offset + oldend as u64;
+                    if absolute_start < prefix as u64 {
+                        let scanlen = std::cmp::min(
+                            newbytes.len() as u64,
+                            prefix as u64 - absolute_start,
+                        ) as usize;
+                        if let Some(i) = newbytes[..scanlen].find_byte(byte) {
+                            self.end = oldend + i;
+                            self.last_lineterm = self.end;
+                            self.binary_byte_offset = Some(
+                                self.absolute_byte_offset + self.end as u64,
+                            );
+                            return Ok(self.pos < self.end);
+                        }
+                    }
+                }
             }
 
             // Обновить наши позиции `last_lineterm`, если мы прочитали один.
             if let Some(i) = newbytes.rfind_byte(self.config.lineterm) {
                 self.last_lineterm = oldend + i + 1;
+                self.skip_overlong_lines();
                 return Ok(true);
             }
             // На этом этапе, если мы не смогли найти терминатор строки, то у нас
-            // нет полной строки. Поэтому мы пытаемся прочитать больше!
+            // нет полной строки. Поэтому мы пытаемся прочитать больше! Если
+            // частичная строка уже превысила максимальную длину строки, то её
+            // байты отбрасываются, чтобы буфер не рос без надобности.
+            self.discard_overlong_partial_line();
+        }
+    }
+
+    /// Удаляет из поискового содержимого буфера все строки, превышающие
+    /// максимальную длину строки, сохраняя их терминаторы строк.
+    ///
+    /// Если максимальная длина строки не установлена, то это ничего не
+    /// делает. Это должно вызываться только когда `pos` равен `0`, то есть
+    /// сразу после заполнения буфера.
+    fn skip_overlong_lines(&mut self) {
+        let Some(limit) = self.config.max_line_length else { return };
+        let mut scan = self.pos;
+        let mut write = self.pos;
+        while scan < self.last_lineterm {
+            let (end, has_term) = match self.buf
+                [scan..self.last_lineterm]
+                .find_byte(self.config.lineterm)
+            {
+                Some(i) => (scan + i + 1, true),
+                None => (self.last_lineterm, false),
+            };
+            let len = end - scan - usize::from(has_term);
+            if self.skipping_line || len > limit {
+                // Первый сегмент может быть хвостом строки, начало которой
+                // уже было отброшено. В любом случае сохраняется только
+                // терминатор строки, если он есть.
+                self.skipping_line = !has_term;
+                if has_term {
+                    self.buf[write] = self.config.lineterm;
+                    write += 1;
+                }
+            } else {
+                if write != scan {
+                    self.buf.copy_within(scan..end, write);
+                }
+                write += end - scan;
+            }
+            scan = end;
+        }
+        let tail = self.end - self.last_lineterm;
+        if write != self.last_lineterm {
+            self.buf.copy_within(self.last_lineterm..self.end, write);
+        }
+        self.last_lineterm = write;
+        self.end = write + tail;
+    }
+
+    /// Отбрасывает байты текущей частичной строки, если она превысила
+    /// максимальную длину строки (или если её начало уже было отброшено).
+    ///
+    /// Если максимальная длина строки не установлена, то это ничего не
+    /// делает.
+    fn discard_overlong_partial_line(&mut self) {
+        let Some(limit) = self.config.max_line_length else { return };
+        // Частичная строка начинается сразу после последнего терминатора
+        // строки. Если буфер не содержит терминатора (например, сразу после
+        // прокрутки), то она начинается в начале поискового содержимого.
+        let start = if self.last_lineterm > self.pos
+            && self.buf[self.last_lineterm - 1] == self.config.lineterm
+        {
+            self.last_lineterm
+        } else {
+            self.pos
+        };
+        if self.skipping_line || self.end - start > limit {
+            self.skipping_line = true;
+            self.last_lineterm = start;
+            self.end = start;
         }
     }
 
@@ -960,4 +1089,90 @@ Holmeses, s\
         assert_eq!(rdr.absolute_byte_offset(), bytes.len() as u64);
         assert_eq!(rdr.binary_byte_offset(), Some(bytes.len() as u64 - 2));
     }
+
+    #[test]
+    fn buffer_max_line_length1() {
+        let bytes = "homer\nlisa-is-very-chatty\nmaggie\n";
+        let mut linebuf =
+            LineBufferBuilder::new().max_line_length(Some(6)).build();
+        let mut rdr = LineBufferReader::new(bytes.as_bytes(), &mut linebuf);
+
+        assert!(rdr.fill().unwrap());
+        assert_eq!(rdr.bstr(), "homer\n\nmaggie\n");
+        rdr.consume_all();
+
+        assert!(!rdr.fill().unwrap());
+        assert_eq!(rdr.binary_byte_offset(), None);
+    }
+
+    #[test]
+    fn buffer_max_line_length2() {
+        // Длинная строка пересекает несколько заполнений буфера. Её байты
+        // должны отбрасываться без расширения буфера, а терминатор строки
+        // должен сохраняться.
+        let bytes = "aaaaaaaaaa\nbob\n";
+        let mut linebuf = LineBufferBuilder::new()
+            .capacity(4)
+            .max_line_length(Some(5))
+            .build();
+        let mut rdr = LineBufferReader::new(bytes.as_bytes(), &mut linebuf);
+
+        let mut got = vec![];
+        while rdr.fill().unwrap() {
+            got.push_str(rdr.buffer());
+            rdr.consume_all();
+        }
+        assert_eq!("\nbob\n", got.as_bstr());
+    }
+
+    #[test]
+    fn buffer_max_line_length3() {
+        // Незавершённая длинная строка в конце содержимого также
+        // пропускается. Терминатора для сохранения нет.
+        let bytes = "short\nloooooooong";
+        let mut linebuf =
+            LineBufferBuilder::new().max_line_length(Some(6)).build();
+        let mut rdr = LineBufferReader::new(bytes.as_bytes(), &mut linebuf);
+
+        assert!(rdr.fill().unwrap());
+        assert_eq!(rdr.bstr(), "short\n");
+        rdr.consume_all();
+
+        assert!(!rdr.fill().unwrap());
+    }
+
+    #[test]
+    fn buffer_binary_heuristic_prefix1() {
+        let bytes = "homer\nli\x00sa\nmaggie\n";
+        let mut linebuf = LineBufferBuilder::new()
+            .binary_detection(BinaryDetection::HeuristicPrefix(100, b'\x00'))
+            .build();
+        let mut rdr = LineBufferReader::new(bytes.as_bytes(), &mut linebuf);
+
+        assert!(rdr.fill().unwrap());
+        assert_eq!(rdr.bstr(), "homer\nli");
+        rdr.consume_all();
+
+        assert!(!rdr.fill().unwrap());
+        assert_eq!(rdr.absolute_byte_offset(), 8);
+        assert_eq!(rdr.binary_byte_offset(), Some(8));
+    }
+
+    #[test]
+    fn buffer_binary_heuristic_prefix2() {
+        // Двоичный байт находится за пределами проверяемого префикса,
+        // поэтому он не обнаруживается.
+        let bytes = "homer\nli\x00sa\nmaggie\n";
+        let mut linebuf = LineBufferBuilder::new()
+            .binary_detection(BinaryDetection::HeuristicPrefix(4, b'\x00'))
+            .build();
+        let mut rdr = LineBufferReader::new(bytes.as_bytes(), &mut linebuf);
+
+        assert!(rdr.fill().unwrap());
+        assert_eq!(rdr.bstr(), "homer\nli\x00sa\nmaggie\n");
+        rdr.consume_all();
+
+        assert!(!rdr.fill().unwrap());
+        assert_eq!(rdr.binary_byte_offset(), None);
+    }
 }
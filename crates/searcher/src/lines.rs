@@ -12,6 +12,9 @@ use {
 /// Терминаторы строк считаются частью строки, которую они завершают. Все строки,
 /// выдаваемые итератором, гарантированно непусты.
 ///
+/// Этот итератор реализует `DoubleEndedIterator`, так что строки можно
+/// обходить и с конца, например, через `rev`, `rfind` или `rposition`.
+///
 /// `'b` относится к времени жизни нижележащих байтов.
 #[derive(Debug)]
 pub struct LineIter<'b> {
@@ -36,6 +39,28 @@ impl<'b> Iterator for LineIter<'b> {
     }
 }
 
+impl<'b> DoubleEndedIterator for LineIter<'b> {
+    fn next_back(&mut self) -> Option<&'b [u8]> {
+        // `pos` и `end` шагателя служат передним и задним курсорами
+        // соответственно: переднее продвижение увеличивает `pos`, а
+        // обратное — уменьшает `end`, так что итерация с обоих концов
+        // никогда не выдаёт одну и ту же строку дважды.
+        let (start, end) = (self.stepper.pos, self.stepper.end);
+        if start >= end {
+            return None;
+        }
+        let mut search_end = end;
+        if self.bytes[end - 1] == self.stepper.line_term {
+            search_end -= 1;
+        }
+        let line_start = self.bytes[start..search_end]
+            .rfind_byte(self.stepper.line_term)
+            .map_or(start, |i| start + i + 1);
+        self.stepper.end = line_start;
+        Some(&self.bytes[line_start..end])
+    }
+}
+
 /// Явный итератор по строкам в конкретном срезе байтов.
 ///
 /// Этот итератор избегает заимствования самих байтов и вместо этого требует,
@@ -325,6 +350,49 @@ and exhibited clearly, with a label attached.\
         assert_eq!(lines(""), Vec::<&str>::new());
     }
 
+    #[test]
+    fn line_iter_rev() {
+        fn lines_rev(text: &str) -> Vec<&str> {
+            LineIter::new(b'\n', text.as_bytes())
+                .rev()
+                .map(|line| std::str::from_utf8(line).unwrap())
+                .collect()
+        }
+
+        assert_eq!(lines_rev("abc"), vec!["abc"]);
+
+        assert_eq!(lines_rev("abc\n"), vec!["abc\n"]);
+        assert_eq!(lines_rev("abc\nxyz"), vec!["xyz", "abc\n"]);
+        assert_eq!(lines_rev("abc\nxyz\n"), vec!["xyz\n", "abc\n"]);
+
+        assert_eq!(lines_rev("abc\n\n"), vec!["\n", "abc\n"]);
+        assert_eq!(lines_rev("abc\n\n\n"), vec!["\n", "\n", "abc\n"]);
+        assert_eq!(lines_rev("abc\n\nxyz"), vec!["xyz", "\n", "abc\n"]);
+        assert_eq!(lines_rev("\n\nabc"), vec!["abc", "\n", "\n"]);
+
+        assert_eq!(
+            lines_rev("abc\r\nxyz\r\n"),
+            vec!["xyz\r\n", "abc\r\n"]
+        );
+
+        assert_eq!(lines_rev("\n"), vec!["\n"]);
+        assert_eq!(lines_rev(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn line_iter_double_ended() {
+        let mut it = LineIter::new(b'\n', b"a\nb\nc\nd\n");
+        assert_eq!(it.next(), Some(&b"a\n"[..]));
+        assert_eq!(it.next_back(), Some(&b"d\n"[..]));
+        assert_eq!(it.next(), Some(&b"b\n"[..]));
+        assert_eq!(it.next_back(), Some(&b"c\n"[..]));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        let mut it = LineIter::new(b'\n', b"a\nb\nc");
+        assert_eq!(it.rfind(|line| *line == b"a\n"), Some(&b"a\n"[..]));
+    }
+
     #[test]
     fn line_iter_empty() {
         let mut it = LineStep::new(b'\n', 0, 0);
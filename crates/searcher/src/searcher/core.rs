@@ -3,7 +3,7 @@ use bstr::ByteSlice;
 use grep_matcher::{LineMatchKind, Matcher};
 
 use crate::{
-    line_buffer::BinaryDetection,
+    line_buffer::{BinaryDetection, DEFAULT_BUFFER_CAPACITY},
     lines::{self, LineStep},
     searcher::{Config, Range, Searcher},
     sink::{
@@ -28,9 +28,12 @@ pub(crate) struct Core<'s, M: 's, S> {
     absolute_byte_offset: u64,
     binary_byte_offset: Option<usize>,
     line_number: Option<u64>,
+    lines_scanned: u64,
+    bytes_printed: u64,
     last_line_counted: usize,
     last_line_visited: usize,
     after_context_left: usize,
+    deadline_countdown: u64,
     has_sunk: bool,
     has_matched: bool,
     count: u64,
@@ -55,9 +58,12 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
             absolute_byte_offset: 0,
             binary_byte_offset: None,
             line_number,
+            lines_scanned: 0,
+            bytes_printed: 0,
             last_line_counted: 0,
             last_line_visited: 0,
             after_context_left: 0,
+            deadline_countdown: searcher.config.deadline_poll_interval,
             has_sunk: false,
             has_matched: false,
             count: 0,
@@ -161,16 +167,67 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
         byte_count: u64,
         binary_byte_offset: Option<u64>,
     ) -> Result<(), S::Error> {
+        let lines_scanned = self.lines_scanned;
+        let bytes_printed = self.bytes_printed;
         self.sink.finish(
             &self.searcher,
-            &SinkFinish { byte_count, binary_byte_offset },
+            &SinkFinish {
+                byte_count,
+                lines_scanned,
+                bytes_printed,
+                binary_byte_offset,
+            },
         )
     }
 
+    /// Учитывает строки, которые были обработаны, но ещё не учтены.
+    ///
+    /// Это должно вызываться перед `finish`, чтобы строки после последнего
+    /// события sink (или весь вход, когда совпадений не было) попали в
+    /// счётчик просмотренных строк.
+    pub(crate) fn count_remaining_lines(&mut self, buf: &[u8]) {
+        // Позиция может указывать за конец буфера, когда поиск был
+        // остановлен досрочно, а остаток буфера уже был потреблён.
+        self.count_lines(buf, std::cmp::min(self.pos(), buf.len()));
+    }
+
+    /// Возвращает ошибку, если крайний срок поиска истёк.
+    ///
+    /// Проверка времени выполняется не чаще, чем раз в
+    /// `deadline_poll_interval` строк, чтобы амортизировать стоимость
+    /// запроса текущего времени.
+    #[inline]
+    fn check_deadline_by_line(&mut self) -> Result<(), S::Error> {
+        if self.config.deadline.is_none() {
+            return Ok(());
+        }
+        if self.deadline_countdown > 1 {
+            self.deadline_countdown -= 1;
+            return Ok(());
+        }
+        self.deadline_countdown = self.config.deadline_poll_interval;
+        self.check_deadline_now()
+    }
+
+    /// Возвращает ошибку, если крайний срок поиска истёк. Время
+    /// запрашивается безусловно.
+    fn check_deadline_now(&self) -> Result<(), S::Error> {
+        let Some(deadline) = self.config.deadline else { return Ok(()) };
+        if std::time::Instant::now() >= deadline {
+            Err(S::Error::error_io(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "превышен крайний срок поиска",
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
     pub(crate) fn match_by_line(
         &mut self,
         buf: &[u8],
     ) -> Result<bool, S::Error> {
+        self.check_deadline_now()?;
         if self.is_line_by_line_fast() {
             match self.match_by_line_fast(buf)? {
                 FastMatchResult::SwitchToSlow => self.match_by_line_slow(buf),
@@ -212,6 +269,16 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
         consumed
     }
 
+    /// Возвращает количество байтов от начала содержимого, которое должно
+    /// быть проверено на двоичные данные для поиска по срезу.
+    pub(crate) fn binary_prefix_upto(&self, len: usize) -> usize {
+        let limit = match self.config.binary.0 {
+            BinaryDetection::HeuristicPrefix(prefix, _) => prefix,
+            _ => DEFAULT_BUFFER_CAPACITY,
+        };
+        std::cmp::min(len, limit)
+    }
+
     pub(crate) fn detect_binary(
         &mut self,
         buf: &[u8],
@@ -223,6 +290,7 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
         let binary_byte = match self.config.binary.0 {
             BinaryDetection::Quit(b) => b,
             BinaryDetection::Convert(b) => b,
+            BinaryDetection::HeuristicPrefix(_, b) => b,
             _ => return Ok(false),
         };
         if let Some(i) = buf[*range].find_byte(binary_byte) {
@@ -337,6 +405,7 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
             range.end(),
         );
         while let Some(line) = stepper.next_match(buf) {
+            self.check_deadline_by_line()?;
             if self.has_exceeded_match_limit()
                 && !self.config.passthru
                 && self.after_context_left == 0
@@ -390,6 +459,7 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
 
         debug_assert!(!self.config.passthru);
         while !buf[self.pos()..].is_empty() {
+            self.check_deadline_by_line()?;
             if self.config.stop_on_nonmatch && self.has_matched {
                 return Ok(SwitchToSlow);
             }
@@ -544,6 +614,7 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
                 bytes_range_in_buffer: range.start()..range.end(),
             },
         )?;
+        self.bytes_printed += linebuf.len() as u64;
         if !keepgoing {
             return Ok(false);
         }
@@ -574,6 +645,7 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
                 line_number: self.line_number,
             },
         )?;
+        self.bytes_printed += range.len() as u64;
         if !keepgoing {
             return Ok(false);
         }
@@ -605,6 +677,7 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
                 line_number: self.line_number,
             },
         )?;
+        self.bytes_printed += range.len() as u64;
         if !keepgoing {
             return Ok(false);
         }
@@ -635,6 +708,7 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
                 line_number: self.line_number,
             },
         )?;
+        self.bytes_printed += range.len() as u64;
         if !keepgoing {
             return Ok(false);
         }
@@ -659,15 +733,16 @@ impl<'s, M: Matcher, S: Sink> Core<'s, M, S> {
     }
 
     fn count_lines(&mut self, buf: &[u8], upto: usize) {
+        if self.last_line_counted >= upto {
+            return;
+        }
+        let slice = &buf[self.last_line_counted..upto];
+        let count = lines::count(slice, self.config.line_term.as_byte());
+        self.lines_scanned += count;
         if let Some(ref mut line_number) = self.line_number {
-            if self.last_line_counted >= upto {
-                return;
-            }
-            let slice = &buf[self.last_line_counted..upto];
-            let count = lines::count(slice, self.config.line_term.as_byte());
             *line_number += count;
-            self.last_line_counted = upto;
         }
+        self.last_line_counted = upto;
     }
 
     fn is_line_by_line_fast(&self) -> bool {
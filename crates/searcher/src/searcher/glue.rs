@@ -1,7 +1,7 @@
 use grep_matcher::Matcher;
 
 use crate::{
-    line_buffer::{DEFAULT_BUFFER_CAPACITY, LineBufferReader},
+    line_buffer::LineBufferReader,
     lines::{self, LineStep},
     searcher::{Config, Range, Searcher, core::Core},
     sink::{Sink, SinkError},
@@ -44,6 +44,7 @@ where
                 }
             }
         }
+        self.core.count_remaining_lines(self.rdr.buffer());
         self.core.finish(
             self.rdr.absolute_byte_offset(),
             self.rdr.binary_byte_offset(),
@@ -117,8 +118,7 @@ impl<'s, M: Matcher, S: Sink> SliceByLine<'s, M, S> {
 
     pub(crate) fn run(mut self) -> Result<(), S::Error> {
         if self.core.begin()? {
-            let binary_upto =
-                std::cmp::min(self.slice.len(), DEFAULT_BUFFER_CAPACITY);
+            let binary_upto = self.core.binary_prefix_upto(self.slice.len());
             let binary_range = Range::new(0, binary_upto);
             if !self.core.detect_binary(self.slice, &binary_range)? {
                 while !self.slice[self.core.pos()..].is_empty()
@@ -128,6 +128,7 @@ impl<'s, M: Matcher, S: Sink> SliceByLine<'s, M, S> {
         }
         let byte_count = self.byte_count();
         let binary_byte_offset = self.core.binary_byte_offset();
+        self.core.count_remaining_lines(self.slice);
         self.core.finish(byte_count, binary_byte_offset)
     }
 
@@ -166,8 +167,7 @@ impl<'s, M: Matcher, S: Sink> MultiLine<'s, M, S> {
 
     pub(crate) fn run(mut self) -> Result<(), S::Error> {
         if self.core.begin()? {
-            let binary_upto =
-                std::cmp::min(self.slice.len(), DEFAULT_BUFFER_CAPACITY);
+            let binary_upto = self.core.binary_prefix_upto(self.slice.len());
             let binary_range = Range::new(0, binary_upto);
             if !self.core.detect_binary(self.slice, &binary_range)? {
                 let mut keepgoing = true;
@@ -204,6 +204,7 @@ impl<'s, M: Matcher, S: Sink> MultiLine<'s, M, S> {
         }
         let byte_count = self.byte_count();
         let binary_byte_offset = self.core.binary_byte_offset();
+        self.core.count_remaining_lines(self.slice);
         self.core.finish(byte_count, binary_byte_offset)
     }
 
@@ -359,12 +360,11 @@ impl<'s, M: Matcher, S: Sink> MultiLine<'s, M, S> {
 #[cfg(test)]
 mod tests {
     use crate::{
+        line_buffer::DEFAULT_BUFFER_CAPACITY,
         searcher::{BinaryDetection, SearcherBuilder},
         testutil::{KitchenSink, RegexMatcher, SearcherTester},
     };
 
-    use super::*;
-
     const SHERLOCK: &'static str = "\
 For the Doctor Watsons of this world, as opposed to the Sherlock
 Holmeses, success in the province of detective work must always
@@ -1552,4 +1552,77 @@ and exhibited clearly, with a label attached.\
             .unwrap();
         assert!(matched);
     }
+
+    #[test]
+    fn deadline_stops_search() {
+        use std::time::{Duration, Instant};
+
+        use crate::SearcherBuilder;
+
+        // Читатель, который никогда не исчерпывается, но отдаёт данные
+        // медленно. Без крайнего срока поиск по нему не завершился бы.
+        struct SlowReader;
+
+        impl std::io::Read for SlowReader {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                std::thread::sleep(Duration::from_millis(1));
+                let data = b"zzz\n";
+                let n = std::cmp::min(buf.len(), data.len());
+                buf[..n].copy_from_slice(&data[..n]);
+                Ok(n)
+            }
+        }
+
+        let matcher = RegexMatcher::new("a");
+        let mut searcher = SearcherBuilder::new().build();
+        let start = Instant::now();
+        let deadline = start + Duration::from_millis(50);
+        let result = searcher.search_with_deadline(
+            &matcher,
+            SlowReader,
+            crate::sinks::UTF8(|_, _| Ok(true)),
+            deadline,
+        );
+        let err = result.unwrap_err();
+        assert_eq!(std::io::ErrorKind::TimedOut, err.kind());
+        // Щедрый запас: важно лишь то, что поиск не продолжается
+        // бесконечно после крайнего срока.
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn limit_sink_stops_after_n_matches() {
+        use crate::SearcherBuilder;
+
+        let haystack = "a\nb\na\na\nc\na\n";
+        let matcher = RegexMatcher::new("a");
+        let mut searcher = SearcherBuilder::new().build();
+
+        let mut lines = vec![];
+        let sink = crate::sinks::Limit::new(
+            2,
+            crate::sinks::UTF8(|lineno, _| {
+                lines.push(lineno);
+                Ok(true)
+            }),
+        );
+        searcher
+            .search_reader(&matcher, haystack.as_bytes(), sink)
+            .unwrap();
+        assert_eq!(vec![1, 3], lines);
+
+        // Лимит 0 останавливает поиск до первого совпадения.
+        let mut count = 0;
+        let sink = crate::sinks::Limit::new(
+            0,
+            crate::sinks::UTF8(|_, _| {
+                count += 1;
+                Ok(true)
+            }),
+        );
+        searcher
+            .search_reader(&matcher, haystack.as_bytes(), sink)
+            .unwrap();
+        assert_eq!(0, count);
+    }
 }
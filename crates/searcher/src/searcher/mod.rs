@@ -3,7 +3,7 @@ use std::{
     cmp,
     fs::File,
     io::{self, Read},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 use {
@@ -102,12 +102,31 @@ impl BinaryDetection {
         BinaryDetection(line_buffer::BinaryDetection::Convert(binary_byte))
     }
 
+    /// Как [`BinaryDetection::quit`], но указанный байт ищется только в
+    /// первых `prefix_bytes` байтах содержимого, независимо от стратегии
+    /// поиска.
+    ///
+    /// Это делает проверку на двоичные данные заметно дешевле для очень
+    /// больших файлов (например, при поиске с отображением в память),
+    /// ценой возможных ложноотрицательных результатов: двоичные данные,
+    /// расположенные глубже префикса, не будут обнаружены.
+    pub fn heuristic_prefix(
+        prefix_bytes: usize,
+        binary_byte: u8,
+    ) -> BinaryDetection {
+        BinaryDetection(line_buffer::BinaryDetection::HeuristicPrefix(
+            prefix_bytes,
+            binary_byte,
+        ))
+    }
+
     /// Если это обнаружение двоичных данных использует стратегию "quit",
     /// то возвращается байт, который приведёт к завершению поиска.
     /// В любом другом случае возвращается `None`.
     pub fn quit_byte(&self) -> Option<u8> {
         match self.0 {
-            line_buffer::BinaryDetection::Quit(b) => Some(b),
+            line_buffer::BinaryDetection::Quit(b)
+            | line_buffer::BinaryDetection::HeuristicPrefix(_, b) => Some(b),
             _ => None,
         }
     }
@@ -189,6 +208,19 @@ pub struct Config {
     /// Максимальное количество совпадений, которое должен выдать этот
     /// поисковик.
     max_matches: Option<u64>,
+    /// Размер буфера чтения для инкрементного поиска.
+    read_buffer_size: usize,
+    /// Когда установлено, строки длиннее этого количества байтов молча
+    /// пропускаются.
+    max_line_length: Option<usize>,
+    /// Когда установлено, поиск прерывается, как только текущее время
+    /// превышает этот момент.
+    deadline: Option<std::time::Instant>,
+    /// Как часто (в строках) проверять крайний срок поиска.
+    deadline_poll_interval: u64,
+    /// Когда установлено, первые `n` байтов входа пропускаются перед
+    /// началом поиска.
+    skip_first_n_bytes: Option<u64>,
 }
 
 impl Default for Config {
@@ -208,6 +240,11 @@ impl Default for Config {
             bom_sniffing: true,
             stop_on_nonmatch: false,
             max_matches: None,
+            read_buffer_size: DEFAULT_BUFFER_CAPACITY,
+            max_line_length: None,
+            deadline: None,
+            deadline_poll_interval: 1024,
+            skip_first_n_bytes: None,
         }
     }
 }
@@ -226,13 +263,15 @@ impl Config {
         let mut builder = LineBufferBuilder::new();
         builder
             .line_terminator(self.line_term.as_byte())
+            .capacity(self.read_buffer_size)
+            .max_line_length(self.max_line_length)
             .binary_detection(self.binary.0);
 
         if let Some(limit) = self.heap_limit {
-            let (capacity, additional) = if limit <= DEFAULT_BUFFER_CAPACITY {
+            let (capacity, additional) = if limit <= self.read_buffer_size {
                 (limit, 0)
             } else {
-                (DEFAULT_BUFFER_CAPACITY, limit - DEFAULT_BUFFER_CAPACITY)
+                (self.read_buffer_size, limit - self.read_buffer_size)
             };
             builder
                 .capacity(capacity)
@@ -339,6 +378,7 @@ impl SearcherBuilder {
             config,
             decode_builder,
             decode_buffer: RefCell::new(vec![0; 8 * (1 << 10)]),
+            current_path: None,
             line_buffer: RefCell::new(self.config.line_buffer()),
             multi_line_buffer: RefCell::new(vec![]),
         }
@@ -474,6 +514,121 @@ impl SearcherBuilder {
         self
     }
 
+    /// Пропустить первые `n` байтов входа перед началом поиска.
+    ///
+    /// Это полезно для возобновляемых поисков (например, инкрементного
+    /// сканирования журналов), когда известно, что начало входа уже было
+    /// обработано. Для `search_path` и `search_file` пропуск выполняется
+    /// через `seek`, так что пропущенные байты вообще не читаются; при
+    /// поиске с отображением в память срез просто начинается со смещения.
+    /// Для `search_reader` пропущенные байты читаются и отбрасываются.
+    /// `search_slice` этой настройкой не затрагивается.
+    ///
+    /// Обратите внимание, что пропущенные байты не читаются, поэтому номера
+    /// строк отсчитываются от позиции возобновления, а не от начала входа.
+    ///
+    /// По умолчанию не установлено.
+    pub fn skip_first_n_bytes(
+        &mut self,
+        n: Option<u64>,
+    ) -> &mut SearcherBuilder {
+        self.config.skip_first_n_bytes = n;
+        self
+    }
+
+    /// Установить размер буфера чтения (в байтах), используемого для
+    /// инкрементного поиска.
+    ///
+    /// Это начальная ёмкость внутреннего построчного буфера. Буфер может
+    /// расти сверх этого размера, чтобы вместить строки длиннее ёмкости
+    /// (с учётом ограничения кучи, если оно установлено). Больший буфер
+    /// уменьшает количество вызовов чтения и может заметно улучшить
+    /// пропускную способность на быстрых накопителях, ценой большего
+    /// использования памяти на каждый поисковик.
+    ///
+    /// Значения меньше `4096` поднимаются до `4096`, чтобы буфер всегда
+    /// мог вместить хотя бы строку разумной длины без немедленного
+    /// перераспределения.
+    ///
+    /// По умолчанию 64 КБ.
+    pub fn read_buffer_size(
+        &mut self,
+        bytes: usize,
+    ) -> &mut SearcherBuilder {
+        self.config.read_buffer_size = cmp::max(4096, bytes);
+        self
+    }
+
+    /// Установить максимальную длину строки (в байтах).
+    ///
+    /// Строки, превышающие эту длину, молча пропускаются: они не читаются
+    /// в память целиком, не ищутся и не сообщаются. Это полезно при поиске
+    /// в журналах, где отдельная строка (например, большой JSON-объект или
+    /// содержимое в base64) может занимать сотни мегабайт. В отличие от
+    /// ограничения кучи, превышение этой длины не считается ошибкой, и в
+    /// отличие от обнаружения двоичных данных, остальная часть содержимого
+    /// продолжает искаться как обычно.
+    ///
+    /// Терминаторы пропущенных строк сохраняются, так что номера строк
+    /// последующих совпадений не изменяются. Однако сообщаемые абсолютные
+    /// смещения байтов не учитывают отброшенные байты.
+    ///
+    /// Обратите внимание, что это отличается от `--max-columns`: тот флаг
+    /// влияет только на печать — длинные строки всё равно читаются и
+    /// ищутся, но их содержимое опускается в выводе. Эта настройка
+    /// предотвращает сам поиск таких строк. Также обратите внимание, что
+    /// эта настройка применяется только при инкрементном поиске с
+    /// построчным буфером; она не влияет на поиск по нескольким строкам
+    /// или поиск с отображением в память.
+    ///
+    /// По умолчанию не установлено.
+    pub fn max_line_length(
+        &mut self,
+        bytes: Option<usize>,
+    ) -> &mut SearcherBuilder {
+        self.config.max_line_length = bytes;
+        self
+    }
+
+    /// Установить крайний срок для всех поисков, выполняемых этим
+    /// поисковиком.
+    ///
+    /// Когда крайний срок истекает во время поиска, поиск останавливается,
+    /// и возвращается ошибка `std::io::Error` с типом
+    /// `std::io::ErrorKind::TimedOut`.
+    ///
+    /// В отличие от [`Searcher::search_with_deadline`], который
+    /// устанавливает крайний срок для одного вызова, эта настройка
+    /// применяется к каждому поиску, выполняемому построенным поисковиком.
+    /// Это полезно, когда один крайний срок должен охватывать поиск по
+    /// многим файлам.
+    ///
+    /// По умолчанию крайний срок не установлен.
+    pub fn deadline(
+        &mut self,
+        deadline: Option<std::time::Instant>,
+    ) -> &mut SearcherBuilder {
+        self.config.deadline = deadline;
+        self
+    }
+
+    /// Установить, как часто (в строках) проверяется крайний срок поиска.
+    ///
+    /// Это применяется только когда установлен крайний срок, через
+    /// [`SearcherBuilder::deadline`] или [`Searcher::search_with_deadline`].
+    /// Меньший интервал делает прерывание более точным, но увеличивает
+    /// накладные расходы на запросы текущего времени. Значение `0`
+    /// поднимается до `1`.
+    ///
+    /// По умолчанию 1024 строки.
+    pub fn deadline_poll_interval(
+        &mut self,
+        lines: u64,
+    ) -> &mut SearcherBuilder {
+        self.config.deadline_poll_interval = cmp::max(1, lines);
+        self
+    }
+
     /// Установить стратегию использования отображений памяти.
     ///
     /// В настоящее время можно использовать только две стратегии:
@@ -638,6 +793,10 @@ pub struct Searcher {
     /// для выполнения поиска, поэтому мы статически предотвращаем вызов
     /// паники RefCell во время выполнения из-за нарушения заимствования.
     line_buffer: RefCell<LineBuffer>,
+    /// Путь к файлу, поиск по которому выполняется в данный момент, если
+    /// он известен. Это устанавливается только при поиске, начатом через
+    /// `search_path`, и доступно реализациям `Sink` через `current_path`.
+    current_path: Option<PathBuf>,
     /// Буфер, в котором хранится содержимое читателя при выполнении
     /// поиска по нескольким строкам. В частности, поиск по нескольким
     /// строкам не может выполняться инкрементально и требует, чтобы
@@ -679,6 +838,107 @@ impl Searcher {
         self.search_file_maybe_path(matcher, Some(path), &file, write_to)
     }
 
+    /// Выполнить поиск по каждой файловой записи внутри архива `tar` с
+    /// данным путём и записать результаты в данный sink.
+    ///
+    /// Поиск выполняется по каждой обычной файловой записи архива через
+    /// `search_reader`, при этом путь записи внутри архива доступен
+    /// реализациям `Sink` через [`Searcher::current_path`]. Для фильтрации
+    /// записей архива используйте [`TarSearcherBuilder`](crate::TarSearcherBuilder).
+    ///
+    /// Обратите внимание, что сжатые архивы (например, `.tar.gz`) должны
+    /// быть распакованы до передачи сюда.
+    #[cfg(feature = "tar")]
+    pub fn search_tar_path<P, M, S>(
+        &mut self,
+        matcher: M,
+        path: P,
+        write_to: S,
+    ) -> Result<(), S::Error>
+    where
+        P: AsRef<Path>,
+        M: Matcher,
+        S: Sink,
+    {
+        crate::tar::TarSearcherBuilder::new().build().search_path(
+            self,
+            matcher,
+            path,
+            write_to,
+        )
+    }
+
+    /// Выполнить поиск по каждой файловой записи внутри архива `zip` с
+    /// данным путём и записать результаты в данный sink.
+    ///
+    /// Содержимое каждой обычной файловой записи архива полностью
+    /// распаковывается в память, после чего поиск выполняется через
+    /// `search_slice`. Путь записи внутри архива доступен реализациям
+    /// `Sink` через [`Searcher::current_path`].
+    #[cfg(feature = "zip")]
+    pub fn search_zip_path<P, M, S>(
+        &mut self,
+        matcher: M,
+        path: P,
+        mut write_to: S,
+    ) -> Result<(), S::Error>
+    where
+        P: AsRef<Path>,
+        M: Matcher,
+        S: Sink,
+    {
+        use std::io::Read;
+
+        fn zip_err<S: Sink>(err: ::zip::result::ZipError) -> S::Error {
+            S::Error::error_io(io::Error::other(err))
+        }
+
+        let file = File::open(path.as_ref()).map_err(S::Error::error_io)?;
+        let mut archive =
+            ::zip::ZipArchive::new(file).map_err(zip_err::<S>)?;
+        let mut contents = vec![];
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i).map_err(zip_err::<S>)?;
+            if !entry.is_file() {
+                continue;
+            }
+            let entry_path = entry
+                .enclosed_name()
+                .unwrap_or_else(|| entry.mangled_name());
+            contents.clear();
+            entry.read_to_end(&mut contents).map_err(S::Error::error_io)?;
+            drop(entry);
+            self.current_path = Some(entry_path);
+            let result =
+                self.search_slice(&matcher, &contents, &mut write_to);
+            self.current_path = None;
+            result?;
+        }
+        Ok(())
+    }
+
+    /// Как `search_reader`, но устанавливает `current_path` на время
+    /// поиска. Используется при поиске по записям архивов, где «путь» не
+    /// соответствует файлу в файловой системе.
+    #[cfg(feature = "tar")]
+    pub(crate) fn search_reader_with_path<M, R, S>(
+        &mut self,
+        matcher: M,
+        path: &Path,
+        read_from: R,
+        write_to: S,
+    ) -> Result<(), S::Error>
+    where
+        M: Matcher,
+        R: io::Read,
+        S: Sink,
+    {
+        self.current_path = Some(path.to_path_buf());
+        let result = self.search_reader(matcher, read_from, write_to);
+        self.current_path = None;
+        result
+    }
+
     /// Выполнить поиск по файлу и записать результаты в данный sink.
     ///
     /// Если отображения памяти включены и поисковик эвристически полагает,
@@ -706,13 +966,42 @@ impl Searcher {
         file: &File,
         write_to: S,
     ) -> Result<(), S::Error>
+    where
+        M: Matcher,
+        S: Sink,
+    {
+        self.current_path = path.map(|p| p.to_path_buf());
+        let result =
+            self.search_file_maybe_path_impl(matcher, path, file, write_to);
+        self.current_path = None;
+        result
+    }
+
+    fn search_file_maybe_path_impl<M, S>(
+        &mut self,
+        matcher: M,
+        path: Option<&Path>,
+        file: &File,
+        write_to: S,
+    ) -> Result<(), S::Error>
     where
         M: Matcher,
         S: Sink,
     {
         if let Some(mmap) = self.config.mmap.open(file, path) {
             log::trace!("{:?}: поиск через отображение в память", path);
-            return self.search_slice(matcher, &mmap, write_to);
+            let start = self
+                .config
+                .skip_first_n_bytes
+                .map_or(0, |n| usize::try_from(n).unwrap_or(usize::MAX))
+                .min(mmap.len());
+            return self.search_slice(matcher, &mmap[start..], write_to);
+        }
+        if let Some(n) = self.config.skip_first_n_bytes {
+            use std::io::Seek;
+            (&*file)
+                .seek(io::SeekFrom::Start(n))
+                .map_err(S::Error::error_io)?;
         }
         // Быстрый путь для поиска по нескольким строкам файлов, когда
         // отображения памяти не включены. Это предварительно выделяет
@@ -734,7 +1023,7 @@ impl Searcher {
             .run()
         } else {
             log::trace!("{:?}: поиск с использованием универсального reader", path);
-            self.search_reader(matcher, file, write_to)
+            self.search_reader_impl(matcher, file, write_to)
         }
     }
 
@@ -751,6 +1040,29 @@ impl Searcher {
     /// поиск по файлу или пути к файлу), чтобы отображения памяти
     /// могли быть использованы, если они доступны и включены.
     pub fn search_reader<M, R, S>(
+        &mut self,
+        matcher: M,
+        mut read_from: R,
+        write_to: S,
+    ) -> Result<(), S::Error>
+    where
+        M: Matcher,
+        R: io::Read,
+        S: Sink,
+    {
+        if let Some(n) = self.config.skip_first_n_bytes {
+            // Читатель не поддерживает позиционирование, поэтому
+            // пропускаемые байты читаются и отбрасываются.
+            io::copy(&mut (&mut read_from).take(n), &mut io::sink())
+                .map_err(S::Error::error_io)?;
+        }
+        self.search_reader_impl(matcher, read_from, write_to)
+    }
+
+    /// Как `search_reader`, но без пропуска первых байтов. Используется
+    /// внутренними путями поиска по файлам, где пропуск уже выполнен
+    /// через `seek`.
+    fn search_reader_impl<M, R, S>(
         &mut self,
         matcher: M,
         read_from: R,
@@ -790,6 +1102,42 @@ impl Searcher {
         }
     }
 
+    /// Выполнить поиск по любой реализации `std::io::Read` с крайним
+    /// сроком и записать результаты в данный sink.
+    ///
+    /// Это ведёт себя как [`Searcher::search_reader`], за исключением того,
+    /// что поиск прерывается, как только текущее время превышает `deadline`.
+    /// Это полезно для интерактивных инструментов (редакторов, оболочек),
+    /// которым нужно ограничить время долгих поисков.
+    ///
+    /// При превышении крайнего срока возвращается ошибка, построенная из
+    /// ошибки I/O с видом `std::io::ErrorKind::TimedOut`, так что sink'и
+    /// на основе `io::Error` могут программно распознать прерывание.
+    ///
+    /// Крайний срок проверяется в начале каждого заполнения буфера и не
+    /// чаще, чем раз в `deadline_poll_interval` строк (см.
+    /// [`SearcherBuilder::deadline_poll_interval`]). Обратите внимание,
+    /// что при включённом поиске по нескольким строкам весь вход читается
+    /// в кучу перед началом поиска, и крайний срок во время этого чтения
+    /// не проверяется.
+    pub fn search_with_deadline<M, R, S>(
+        &mut self,
+        matcher: M,
+        read_from: R,
+        write_to: S,
+        deadline: std::time::Instant,
+    ) -> Result<(), S::Error>
+    where
+        M: Matcher,
+        R: io::Read,
+        S: Sink,
+    {
+        let previous = self.config.deadline.replace(deadline);
+        let result = self.search_reader(matcher, read_from, write_to);
+        self.config.deadline = previous;
+        result
+    }
+
     /// Выполнить поиск по данному срезу и записать результаты в данный sink.
     pub fn search_slice<M, S>(
         &mut self,
@@ -820,6 +1168,17 @@ impl Searcher {
         }
     }
 
+    /// Возвращает путь к файлу, поиск по которому выполняется в данный
+    /// момент, если он известен.
+    ///
+    /// Это возвращает `Some` только во время поиска, начатого через
+    /// [`Searcher::search_path`]. Это позволяет универсальным реализациям
+    /// `Sink` выводить строки вида `путь:номер:текст`, не требуя
+    /// внеполосной передачи пути от вызывающего.
+    pub fn current_path(&self) -> Option<&Path> {
+        self.current_path.as_deref()
+    }
+
     /// Установить метод обнаружения двоичных данных, используемый этим
     /// поисковиком.
     pub fn set_binary_detection(&mut self, detection: BinaryDetection) {
@@ -1123,4 +1482,56 @@ mod tests {
         let sink_output = String::from_utf8(sink.as_bytes().to_vec()).unwrap();
         assert_eq!(sink_output, "1:0:foo\nbyte count:3\n");
     }
+
+    #[test]
+    fn skip_first_n_bytes() {
+        let matcher = RegexMatcher::new("foo");
+        let mut searcher = SearcherBuilder::new()
+            .skip_first_n_bytes(Some(8))
+            .build();
+        let haystack = &b"foo\nbar\nfoo\nbaz\n"[..];
+
+        let mut sink = KitchenSink::new();
+        searcher.search_reader(&matcher, haystack, &mut sink).unwrap();
+        let got = String::from_utf8(sink.as_bytes().to_vec()).unwrap();
+        assert_eq!(got, "1:0:foo\n\nbyte count:8\n");
+
+        // search_slice не затрагивается этой настройкой.
+        let mut sink = KitchenSink::new();
+        searcher.search_slice(&matcher, haystack, &mut sink).unwrap();
+        let got = String::from_utf8(sink.as_bytes().to_vec()).unwrap();
+        assert_eq!(got, "1:0:foo\n3:8:foo\n\nbyte count:16\n");
+    }
+
+    #[test]
+    #[cfg(feature = "zip")]
+    fn search_zip_path() {
+        use std::io::Write;
+
+        let tmpdir = crate::testutil::TempDir::new();
+        let path = tmpdir.path().join("basic.zip");
+        let file = File::create(&path).unwrap();
+        let mut writer = ::zip::ZipWriter::new(file);
+        let options = ::zip::write::SimpleFileOptions::default();
+        writer.start_file("a.txt", options).unwrap();
+        writer.write_all(b"foo\nbar\n").unwrap();
+        writer.start_file("b.txt", options).unwrap();
+        writer.write_all(b"baz\nfoo\n").unwrap();
+        writer.finish().unwrap();
+
+        let matcher = RegexMatcher::new("foo");
+        let mut searcher = SearcherBuilder::new().line_number(true).build();
+        let mut got = vec![];
+        searcher
+            .search_zip_path(
+                &matcher,
+                &path,
+                crate::sink::sinks::UTF8(|lnum, line| {
+                    got.push(format!("{}:{}", lnum, line));
+                    Ok(true)
+                }),
+            )
+            .unwrap();
+        assert_eq!(got, vec!["1:foo\n", "2:foo\n"]);
+    }
 }
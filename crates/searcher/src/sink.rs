@@ -1,9 +1,9 @@
 use std::io;
 
-use grep_matcher::LineTerminator;
+use grep_matcher::{LineTerminator, Match, Matcher};
 
 use crate::{
-    lines::LineIter,
+    lines::{self, LineIter},
     searcher::{ConfigError, Searcher},
 };
 
@@ -338,6 +338,8 @@ impl<S: Sink + ?Sized> Sink for Box<S> {
 #[derive(Clone, Debug)]
 pub struct SinkFinish {
     pub(crate) byte_count: u64,
+    pub(crate) lines_scanned: u64,
+    pub(crate) bytes_printed: u64,
     pub(crate) binary_byte_offset: Option<u64>,
 }
 
@@ -348,6 +350,38 @@ impl SinkFinish {
         self.byte_count
     }
 
+    /// Вернуть общее количество прочитанных из источника байтов.
+    ///
+    /// Это синоним [`SinkFinish::byte_count`], предоставленный для
+    /// симметрии с [`SinkFinish::bytes_printed`].
+    #[inline]
+    pub fn bytes_searched(&self) -> u64 {
+        self.byte_count
+    }
+
+    /// Вернуть общее количество байтов, доставленных sink.
+    ///
+    /// Это сумма длин байтов всех совпадений и контекстных строк,
+    /// переданных sink во время этого поиска. Байты учитываются
+    /// независимо от того, попросил ли sink остановить поиск.
+    #[inline]
+    pub fn bytes_printed(&self) -> u64 {
+        self.bytes_printed
+    }
+
+    /// Вернуть общее количество просмотренных строк.
+    ///
+    /// Это учитывает каждую завершённую строку, обработанную поисковиком,
+    /// независимо от того, совпала она или нет. Последняя строка без
+    /// терминатора строки не учитывается.
+    ///
+    /// Обратите внимание, что для многострочных поисков это число может быть
+    /// меньше фактического количества строк во входных данных.
+    #[inline]
+    pub fn lines_scanned(&self) -> u64 {
+        self.lines_scanned
+    }
+
     /// Если обнаружение двоичных данных включено и двоичные данные были найдены, то это
     /// возвращает абсолютное смещение байта первого обнаруженного байта двоичных
     /// данных.
@@ -422,6 +456,34 @@ impl<'b> SinkMatch<'b> {
     pub fn bytes_range_in_buffer(&self) -> std::ops::Range<usize> {
         self.bytes_range_in_buffer.clone()
     }
+
+    /// Вычисляет все неперекрывающиеся совпадения данного матчера в байтах
+    /// этого совпадения.
+    ///
+    /// Когда на одной строке есть несколько совпадений, поисковик сообщает
+    /// о них одним вызовом `matched`. Это удобная подпрограмма для
+    /// реализаций sink (в первую очередь принтеров), которые хотят
+    /// подсветить каждое совпадение в строке, не вызывая
+    /// `Matcher::find_iter` самостоятельно.
+    ///
+    /// Возвращаемые диапазоны являются смещениями в [`SinkMatch::bytes`].
+    /// Завершающий терминатор строки не включается в поиск, чтобы
+    /// regex-выражения вроде `(?m)^$` не совпадали в позиции за ним.
+    ///
+    /// Если матчер сообщает об ошибке во время итерации, то она
+    /// возвращается.
+    pub fn all_match_ranges<M: Matcher>(
+        &self,
+        matcher: M,
+    ) -> Result<Vec<Match>, M::Error> {
+        let slice = lines::without_terminator(self.bytes, self.line_term);
+        let mut matches = vec![];
+        matcher.find_iter(slice, |m| {
+            matches.push(m);
+            true
+        })?;
+        Ok(matches)
+    }
 }
 
 /// Тип контекста, сообщаемого поисковиком.
@@ -518,7 +580,7 @@ pub mod sinks {
 
     use crate::searcher::Searcher;
 
-    use super::{Sink, SinkError, SinkMatch};
+    use super::{Sink, SinkContext, SinkError, SinkFinish, SinkMatch};
 
     /// Sink, который предоставляет номера строк и совпадения как строки, игнорируя
     /// всё остальное.
@@ -659,4 +721,237 @@ pub mod sinks {
             (self.0)(line_number, mat.bytes())
         }
     }
+
+    /// Sink, который останавливает поиск после заданного числа совпадений.
+    ///
+    /// Все события передаются внутреннему sink без изменений. Как только
+    /// внутреннему sink было доставлено `limit` совпадений, этот sink
+    /// возвращает `false` из `matched`, и поиск немедленно
+    /// останавливается. Это полезно, когда вызывающему нужны только первые
+    /// N результатов и он не хочет самостоятельно реализовывать логику
+    /// отмены. В отличие от настройки на уровне поисковика, этот sink
+    /// можно компоновать с другими комбинаторами, например с [`Tee`].
+    ///
+    /// Если `limit` равен `0`, то поиск останавливается на первом же
+    /// совпадении, и внутренний sink его не видит.
+    #[derive(Clone, Debug)]
+    pub struct Limit<S> {
+        limit: u64,
+        count: u64,
+        inner: S,
+    }
+
+    impl<S: Sink> Limit<S> {
+        /// Создаёт новый sink, который останавливает поиск после того, как
+        /// `inner` получит `limit` совпадений.
+        pub fn new(limit: u64, inner: S) -> Limit<S> {
+            Limit { limit, count: 0, inner }
+        }
+
+        /// Возвращает число совпадений, доставленных внутреннему sink до
+        /// сих пор.
+        pub fn count(&self) -> u64 {
+            self.count
+        }
+
+        /// Потребляет этот sink и возвращает внутренний sink.
+        pub fn into_inner(self) -> S {
+            self.inner
+        }
+    }
+
+    impl<S: Sink> Sink for Limit<S> {
+        type Error = S::Error;
+
+        fn matched(
+            &mut self,
+            searcher: &Searcher,
+            mat: &SinkMatch<'_>,
+        ) -> Result<bool, S::Error> {
+            if self.count >= self.limit {
+                return Ok(false);
+            }
+            self.count += 1;
+            let keep = self.inner.matched(searcher, mat)?;
+            Ok(keep && self.count < self.limit)
+        }
+
+        fn context(
+            &mut self,
+            searcher: &Searcher,
+            context: &SinkContext<'_>,
+        ) -> Result<bool, S::Error> {
+            self.inner.context(searcher, context)
+        }
+
+        fn context_break(
+            &mut self,
+            searcher: &Searcher,
+        ) -> Result<bool, S::Error> {
+            self.inner.context_break(searcher)
+        }
+
+        fn binary_data(
+            &mut self,
+            searcher: &Searcher,
+            binary_byte_offset: u64,
+        ) -> Result<bool, S::Error> {
+            self.inner.binary_data(searcher, binary_byte_offset)
+        }
+
+        fn begin(&mut self, searcher: &Searcher) -> Result<bool, S::Error> {
+            self.inner.begin(searcher)
+        }
+
+        fn finish(
+            &mut self,
+            searcher: &Searcher,
+            sink_finish: &SinkFinish,
+        ) -> Result<(), S::Error> {
+            self.inner.finish(searcher, sink_finish)
+        }
+    }
+
+    /// Sink, который разветвляет каждое событие на два внутренних sink.
+    ///
+    /// Каждое событие поиска сначала передаётся первому sink, а затем
+    /// второму. Поиск продолжается только если оба sink вернули `true`;
+    /// если хотя бы один вернул `false`, то поиск немедленно
+    /// останавливается. Обратите внимание, что второй sink вызывается даже
+    /// когда первый вернул `false`, так что оба sink всегда видят одну и
+    /// ту же последовательность событий.
+    ///
+    /// Если какой-либо sink возвращает ошибку, то поиск немедленно
+    /// останавливается, и ошибка передаётся обратно вызывающему. Если
+    /// первый sink вернул ошибку, то второй sink это событие не видит.
+    ///
+    /// Оба sink должны использовать один и тот же тип ошибки.
+    #[derive(Clone, Debug)]
+    pub struct Tee<S1, S2> {
+        sink1: S1,
+        sink2: S2,
+    }
+
+    impl<S1, S2> Tee<S1, S2>
+    where
+        S1: Sink,
+        S2: Sink<Error = S1::Error>,
+    {
+        /// Создаёт новый sink, который передаёт каждое событие как `sink1`,
+        /// так и `sink2`.
+        pub fn new(sink1: S1, sink2: S2) -> Tee<S1, S2> {
+            Tee { sink1, sink2 }
+        }
+
+        /// Возвращает ссылки на оба внутренних sink.
+        pub fn get_ref(&self) -> (&S1, &S2) {
+            (&self.sink1, &self.sink2)
+        }
+
+        /// Потребляет этот sink и возвращает оба внутренних sink.
+        pub fn into_inner(self) -> (S1, S2) {
+            (self.sink1, self.sink2)
+        }
+    }
+
+    impl<S1, S2> Sink for Tee<S1, S2>
+    where
+        S1: Sink,
+        S2: Sink<Error = S1::Error>,
+    {
+        type Error = S1::Error;
+
+        fn matched(
+            &mut self,
+            searcher: &Searcher,
+            mat: &SinkMatch<'_>,
+        ) -> Result<bool, S1::Error> {
+            let keep1 = self.sink1.matched(searcher, mat)?;
+            let keep2 = self.sink2.matched(searcher, mat)?;
+            Ok(keep1 && keep2)
+        }
+
+        fn context(
+            &mut self,
+            searcher: &Searcher,
+            context: &SinkContext<'_>,
+        ) -> Result<bool, S1::Error> {
+            let keep1 = self.sink1.context(searcher, context)?;
+            let keep2 = self.sink2.context(searcher, context)?;
+            Ok(keep1 && keep2)
+        }
+
+        fn context_break(
+            &mut self,
+            searcher: &Searcher,
+        ) -> Result<bool, S1::Error> {
+            let keep1 = self.sink1.context_break(searcher)?;
+            let keep2 = self.sink2.context_break(searcher)?;
+            Ok(keep1 && keep2)
+        }
+
+        fn binary_data(
+            &mut self,
+            searcher: &Searcher,
+            binary_byte_offset: u64,
+        ) -> Result<bool, S1::Error> {
+            let keep1 = self.sink1.binary_data(searcher, binary_byte_offset)?;
+            let keep2 = self.sink2.binary_data(searcher, binary_byte_offset)?;
+            Ok(keep1 && keep2)
+        }
+
+        fn begin(&mut self, searcher: &Searcher) -> Result<bool, S1::Error> {
+            let keep1 = self.sink1.begin(searcher)?;
+            let keep2 = self.sink2.begin(searcher)?;
+            Ok(keep1 && keep2)
+        }
+
+        fn finish(
+            &mut self,
+            searcher: &Searcher,
+            sink_finish: &SinkFinish,
+        ) -> Result<(), S1::Error> {
+            self.sink1.finish(searcher, sink_finish)?;
+            self.sink2.finish(searcher, sink_finish)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grep_matcher::{LineTerminator, Match};
+
+    use crate::testutil::RegexMatcher;
+
+    use super::SinkMatch;
+
+    fn sink_match(bytes: &[u8]) -> SinkMatch<'_> {
+        SinkMatch {
+            line_term: LineTerminator::byte(b'\n'),
+            bytes,
+            absolute_byte_offset: 0,
+            line_number: None,
+            buffer: bytes,
+            bytes_range_in_buffer: 0..bytes.len(),
+        }
+    }
+
+    #[test]
+    fn all_match_ranges_multiple_matches() {
+        let m = sink_match(b"foo bar foo\n");
+        let matcher = RegexMatcher::new("foo");
+        let ranges = m.all_match_ranges(&matcher).unwrap();
+        assert_eq!(ranges, vec![Match::new(0, 3), Match::new(8, 11)]);
+    }
+
+    #[test]
+    fn all_match_ranges_excludes_line_terminator() {
+        // На "\n" целиком `(?m)^$` совпал бы и в позиции после
+        // терминатора. Поиск по строке без терминатора даёт ровно одно
+        // совпадение.
+        let m = sink_match(b"\n");
+        let matcher = RegexMatcher::new(r"(?m)^$");
+        let ranges = m.all_match_ranges(&matcher).unwrap();
+        assert_eq!(ranges, vec![Match::new(0, 0)]);
+    }
 }
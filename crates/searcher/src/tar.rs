@@ -0,0 +1,216 @@
+/*!
+Этот модуль предоставляет поиск по записям внутри архивов `tar`.
+
+Основной тип — [`TarSearcher`], который итерирует по записям архива и
+выполняет поиск по каждой обычной файловой записи через
+[`Searcher::search_reader`](crate::Searcher::search_reader). Для
+удобства [`Searcher::search_tar_path`](crate::Searcher::search_tar_path)
+выполняет то же самое без фильтрации записей.
+*/
+
+use std::{fs::File, path::Path};
+
+use grep_matcher::Matcher;
+
+use crate::{
+    searcher::Searcher,
+    sink::{Sink, SinkError},
+};
+
+/// Фильтр записей архива, используемый [`TarSearcher`].
+///
+/// Фильтр получает запись архива и возвращает `true`, если по ней нужно
+/// выполнить поиск.
+pub type TarEntryFilter = Box<dyn Fn(&::tar::Entry<'_, File>) -> bool>;
+
+/// Построитель для поисковика по архивам `tar`.
+///
+/// Построитель позволяет настроить фильтрацию записей архива перед
+/// созданием [`TarSearcher`].
+#[derive(Default)]
+pub struct TarSearcherBuilder {
+    entry_filter: Option<TarEntryFilter>,
+}
+
+impl std::fmt::Debug for TarSearcherBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TarSearcherBuilder")
+            .field(
+                "entry_filter",
+                &self.entry_filter.as_ref().map(|_| "<closure>"),
+            )
+            .finish()
+    }
+}
+
+impl TarSearcherBuilder {
+    /// Создать новый построитель с конфигурацией по умолчанию.
+    pub fn new() -> TarSearcherBuilder {
+        TarSearcherBuilder::default()
+    }
+
+    /// Создать поисковик по архивам `tar` с текущей конфигурацией.
+    pub fn build(self) -> TarSearcher {
+        TarSearcher { entry_filter: self.entry_filter }
+    }
+
+    /// Установить фильтр записей архива.
+    ///
+    /// Поиск выполняется только по записям, для которых фильтр возвращает
+    /// `true`. Записи, не являющиеся обычными файлами (каталоги,
+    /// символические ссылки и т.д.), пропускаются независимо от фильтра.
+    ///
+    /// По умолчанию фильтр не установлен, и поиск выполняется по всем
+    /// обычным файловым записям.
+    pub fn entry_filter<F>(&mut self, filter: F) -> &mut TarSearcherBuilder
+    where
+        F: Fn(&::tar::Entry<'_, File>) -> bool + 'static,
+    {
+        self.entry_filter = Some(Box::new(filter));
+        self
+    }
+}
+
+/// Поисковик по записям внутри архива `tar`.
+///
+/// Этот тип не выполняет поиск сам, а итерирует по записям архива и
+/// передаёт каждую обычную файловую запись данному [`Searcher`]. Путь
+/// записи внутри архива доступен реализациям `Sink` через
+/// [`Searcher::current_path`](crate::Searcher::current_path).
+///
+/// Обратите внимание, что сжатые архивы (например, `.tar.gz`) должны быть
+/// распакованы до передачи сюда, например, через `DecompressionReader` из
+/// крейта `grep-cli`.
+pub struct TarSearcher {
+    entry_filter: Option<TarEntryFilter>,
+}
+
+impl std::fmt::Debug for TarSearcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TarSearcher")
+            .field(
+                "entry_filter",
+                &self.entry_filter.as_ref().map(|_| "<closure>"),
+            )
+            .finish()
+    }
+}
+
+impl TarSearcher {
+    /// Выполнить поиск по каждой записи архива `tar` с данным путём и
+    /// записать результаты в данный sink.
+    ///
+    /// Один и тот же sink используется для всех записей архива: для каждой
+    /// записи вызываются `begin` и `finish`, как при поиске по отдельному
+    /// файлу.
+    pub fn search_path<P, M, S>(
+        &self,
+        searcher: &mut Searcher,
+        matcher: M,
+        path: P,
+        mut write_to: S,
+    ) -> Result<(), S::Error>
+    where
+        P: AsRef<Path>,
+        M: Matcher,
+        S: Sink,
+    {
+        let file =
+            File::open(path.as_ref()).map_err(S::Error::error_io)?;
+        let mut archive = ::tar::Archive::new(file);
+        let entries = archive.entries().map_err(S::Error::error_io)?;
+        for result in entries {
+            let entry = result.map_err(S::Error::error_io)?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            if let Some(ref filter) = self.entry_filter
+                && !filter(&entry)
+            {
+                continue;
+            }
+            let entry_path =
+                entry.path().map_err(S::Error::error_io)?.into_owned();
+            searcher.search_reader_with_path(
+                &matcher,
+                &entry_path,
+                entry,
+                &mut write_to,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::searcher::SearcherBuilder;
+    use grep_regex::RegexMatcher;
+
+    fn archive() -> Vec<u8> {
+        let mut builder = ::tar::Builder::new(vec![]);
+        for (name, contents) in
+            [("a.txt", &b"foo\nbar\n"[..]), ("b.txt", &b"baz\nfoo\n"[..])]
+        {
+            let mut header = ::tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, contents).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn basic() {
+        let tmpdir = crate::testutil::TempDir::new();
+        let path = tmpdir.path().join("basic.tar");
+        std::fs::write(&path, archive()).unwrap();
+
+        let matcher = RegexMatcher::new("foo").unwrap();
+        let mut searcher = SearcherBuilder::new().line_number(true).build();
+        let mut got = vec![];
+        searcher
+            .search_tar_path(
+                &matcher,
+                &path,
+                crate::sink::sinks::UTF8(|lnum, line| {
+                    got.push(format!("{}:{}", lnum, line));
+                    Ok(true)
+                }),
+            )
+            .unwrap();
+        assert_eq!(got, vec!["1:foo\n", "2:foo\n"]);
+    }
+
+    #[test]
+    fn entry_filter() {
+        let tmpdir = crate::testutil::TempDir::new();
+        let path = tmpdir.path().join("filter.tar");
+        std::fs::write(&path, archive()).unwrap();
+
+        let mut builder = TarSearcherBuilder::new();
+        builder.entry_filter(|entry| {
+            entry.path().is_ok_and(|p| p.ends_with("b.txt"))
+        });
+        let tar_searcher = builder.build();
+
+        let matcher = RegexMatcher::new("foo").unwrap();
+        let mut searcher = SearcherBuilder::new().line_number(true).build();
+        let mut got = vec![];
+        tar_searcher
+            .search_path(
+                &mut searcher,
+                &matcher,
+                &path,
+                crate::sink::sinks::UTF8(|lnum, line| {
+                    got.push(format!("{}:{}", lnum, line));
+                    Ok(true)
+                }),
+            )
+            .unwrap();
+        assert_eq!(got, vec!["2:foo\n"]);
+    }
+}
@@ -491,7 +491,7 @@ impl SearcherTester {
                 // добавляем ещё одну, потому что реализация иногда будет
                 // включать дополнительную строку при обработке контекста.
                 // Нет особой хорошей причины, кроме как сохранить
-                /// реализацию простой.
+                // реализацию простой.
                 2 + self.before_context + self.after_context
             };
 
@@ -802,3 +802,48 @@ mod tests {
         assert_eq!(matcher.find_at(haystack, 2), Ok(Some(m(2, 2))));
     }
 }
+
+/// Простая обёртка для создания временного каталога, который автоматически
+/// удаляется при уничтожении.
+///
+/// Мы используем это вместо tempfile, потому что tempfile тянет слишком
+/// много зависимостей.
+#[derive(Debug)]
+pub(crate) struct TempDir(std::path::PathBuf);
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        std::fs::remove_dir_all(&self.0).unwrap();
+    }
+}
+
+impl TempDir {
+    /// Создаёт новый пустой временный каталог в системном временном
+    /// каталоге. Идентификатор процесса в имени гарантирует, что
+    /// параллельные запуски тестов не столкнутся друг с другом.
+    pub(crate) fn new() -> TempDir {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static TRIES: usize = 100;
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let tmpdir = std::env::temp_dir();
+        for _ in 0..TRIES {
+            let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+            let path = tmpdir
+                .join("rust-grep-searcher")
+                .join(format!("{}-{}", std::process::id(), count));
+            if path.is_dir() {
+                continue;
+            }
+            std::fs::create_dir_all(&path).unwrap();
+            return TempDir(path);
+        }
+        panic!("failed to create temp dir after {} tries", TRIES)
+    }
+
+    /// Возвращает путь к этому временному каталогу.
+    pub(crate) fn path(&self) -> &std::path::Path {
+        &self.0
+    }
+}
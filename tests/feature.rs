@@ -1,5 +1,5 @@
 use crate::hay::{SHERLOCK, SHERLOCK_CRLF};
-use crate::util::{Dir, TestCommand, sort_lines};
+use crate::util::{Dir, TestCommand, cmd_exists, sort_lines};
 
 // See: https://github.com/BurntSushi/ripgrep/issues/1
 rgtest!(f1_sjis, |dir: Dir, mut cmd: TestCommand| {
@@ -1172,3 +1172,369 @@ rgtest!(stop_on_nonmatch, |dir: Dir, mut cmd: TestCommand| {
     cmd.args(&["--stop-on-nonmatch", "[235]"]);
     eqnice!("test:line2\ntest:line3\n", cmd.stdout());
 });
+
+rgtest!(search_git_object, |dir: Dir, mut cmd: TestCommand| {
+    if !cmd_exists("git") {
+        return;
+    }
+
+    dir.create("sherlock", SHERLOCK);
+    let git = |args: &[&str]| {
+        let out = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        assert!(out.status.success(), "git {:?} failed: {:?}", args, out);
+    };
+    git(&["init", "-q"]);
+    git(&["add", "sherlock"]);
+    git(&[
+        "-c",
+        "user.name=test",
+        "-c",
+        "user.email=test@example.com",
+        "commit",
+        "-q",
+        "-m",
+        "init",
+    ]);
+    // Изменяем рабочую копию, чтобы убедиться, что ищется именно
+    // содержимое git-объекта, а не файл на диске.
+    dir.create("sherlock", "nothing to see here");
+
+    cmd.args(&["--search-git-object", "HEAD:sherlock", "Sherlock"]);
+    let expected = "\
+HEAD:sherlock:For the Doctor Watsons of this world, as opposed to the Sherlock
+HEAD:sherlock:be, to a very large extent, the result of luck. Sherlock Holmes
+";
+    eqnice!(expected, cmd.stdout());
+});
+
+rgtest!(os_path_separator, |dir: Dir, mut cmd: TestCommand| {
+    dir.create_dir("foo");
+    dir.create("foo/bar", "test");
+
+    cmd.arg("test").arg("--os-path-separator").arg(r"\");
+    eqnice!("foo\\bar:test\n", cmd.stdout());
+});
+
+rgtest!(os_path_separator_slash, |dir: Dir, mut cmd: TestCommand| {
+    dir.create_dir("foo");
+    dir.create("foo/bar", "test");
+
+    cmd.arg("test").arg("--os-path-separator").arg("/");
+    eqnice!("foo/bar:test\n", cmd.stdout());
+});
+
+rgtest!(os_path_separator_invalid, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("foo", "test");
+    cmd.arg("test").arg("--os-path-separator").arg("Z").assert_err();
+});
+
+rgtest!(count_lines_stats, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    let lines =
+        cmd.arg("-j1").arg("--count-lines").arg("Sherlock").stdout();
+    assert!(lines.contains("2 matched lines"));
+    assert!(lines.contains("6 lines searched"));
+});
+
+rgtest!(count_lines_stats_invert, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    // Количество просмотренных строк не зависит от того, сколько строк
+    // совпало.
+    let lines = cmd
+        .arg("-j1")
+        .arg("-v")
+        .arg("--count-lines")
+        .arg("Sherlock")
+        .stdout();
+    assert!(lines.contains("4 matched lines"));
+    assert!(lines.contains("6 lines searched"));
+});
+
+rgtest!(crlf_output, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    cmd.arg("--crlf-output").arg("Sherlock").arg("sherlock");
+    let expected = "\
+For the Doctor Watsons of this world, as opposed to the Sherlock\r
+be, to a very large extent, the result of luck. Sherlock Holmes\r
+";
+    eqnice!(expected, cmd.stdout());
+});
+
+rgtest!(crlf_output_disabled, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    // Без --crlf-output вывод использует LF-терминаторы.
+    cmd.arg("Sherlock").arg("sherlock");
+    let expected = "\
+For the Doctor Watsons of this world, as opposed to the Sherlock
+be, to a very large extent, the result of luck. Sherlock Holmes
+";
+    eqnice!(expected, cmd.stdout());
+});
+
+rgtest!(crlf_output_no_double, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK_CRLF);
+
+    // Уже существующие CRLF-терминаторы не удваиваются.
+    cmd.arg("--crlf").arg("--crlf-output").arg("Watson").arg("sherlock");
+    let expected = "\
+For the Doctor Watsons of this world, as opposed to the Sherlock\r
+but Doctor Watson has to have it taken out for him and dusted,\r
+";
+    eqnice!(expected, cmd.stdout());
+});
+
+rgtest!(context_only_matching_portion, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    // При инвертированном поиске контекстные строки могут содержать
+    // совпадения, которые усекаются до совпадающих частей.
+    cmd.args(&[
+        "-n",
+        "-v",
+        "-C1",
+        "--context-only-matching-portion",
+        "Sherlock",
+        "sherlock",
+    ]);
+    let expected = "\
+1-Sherlock
+2:Holmeses, success in the province of detective work must always
+3-Sherlock
+4:can extract a clew from a wisp of straw or a flake of cigar ash;
+5:but Doctor Watson has to have it taken out for him and dusted,
+6:and exhibited clearly, with a label attached.
+";
+    eqnice!(expected, cmd.stdout());
+});
+
+rgtest!(context_print_all_matching, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    // --context-print-all-matching переопределяет
+    // --context-only-matching-portion и восстанавливает поведение по
+    // умолчанию: контекстные строки печатаются целиком.
+    cmd.args(&[
+        "-n",
+        "-A1",
+        "--context-only-matching-portion",
+        "--context-print-all-matching",
+        "Sherlock",
+        "sherlock",
+    ]);
+    let expected = "\
+1:For the Doctor Watsons of this world, as opposed to the Sherlock
+2-Holmeses, success in the province of detective work must always
+3:be, to a very large extent, the result of luck. Sherlock Holmes
+4-can extract a clew from a wisp of straw or a flake of cigar ash;
+";
+    eqnice!(expected, cmd.stdout());
+});
+
+rgtest!(color_match_bg, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    cmd.args(&[
+        "--color=always",
+        "--color-match-bg",
+        "yellow",
+        "Sherlock",
+        "sherlock",
+    ]);
+    let got = cmd.stdout();
+
+    // Сокращение производит тот же самый ANSI-вывод, что и эквивалентная
+    // спецификация --colors.
+    let mut cmd = dir.command();
+    cmd.args(&[
+        "--color=always",
+        "--colors",
+        "match:bg:yellow",
+        "Sherlock",
+        "sherlock",
+    ]);
+    eqnice!(cmd.stdout(), got);
+});
+
+rgtest!(color_match_fg, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    cmd.args(&[
+        "--color=always",
+        "--color-match-fg",
+        "magenta",
+        "Sherlock",
+        "sherlock",
+    ]);
+    let got = cmd.stdout();
+
+    let mut cmd = dir.command();
+    cmd.args(&[
+        "--color=always",
+        "--colors",
+        "match:fg:magenta",
+        "Sherlock",
+        "sherlock",
+    ]);
+    eqnice!(cmd.stdout(), got);
+});
+
+rgtest!(count_unique_lines, |dir: Dir, mut cmd: TestCommand| {
+    let haystack = "error: disk full\n".repeat(100)
+        + "error: out of memory\nall good\n";
+    dir.create("log", &haystack);
+
+    cmd.arg("--count-unique-lines").arg("error").arg("log");
+    eqnice!("2\n", cmd.stdout());
+
+    // Для сравнения: --count считает каждую совпадающую строку.
+    let mut cmd = dir.command();
+    cmd.arg("--count").arg("error").arg("log");
+    eqnice!("101\n", cmd.stdout());
+});
+
+rgtest!(no_pcre2_unicode_ascii, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    // Для ASCII-шаблонов отключение режима Unicode не меняет результаты.
+    cmd.args(&["--no-pcre2-unicode", r"\w+ Holmes", "sherlock"]);
+    let got = cmd.stdout();
+
+    let mut cmd = dir.command();
+    cmd.args(&[r"\w+ Holmes", "sherlock"]);
+    eqnice!(cmd.stdout(), got);
+});
+
+rgtest!(stats_format_json, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    // Обычный текстовый вывод поиска, но статистика в формате JSON.
+    cmd.args(&["--stats", "--stats-format", "json", "Sherlock", "sherlock"]);
+    let output = cmd.stdout();
+    assert!(output.contains("For the Doctor Watsons of this world"));
+    let last = output.lines().last().unwrap();
+    assert!(last.contains(r#""type":"summary""#), "got: {last}");
+});
+
+rgtest!(stats_format_text, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    // Вывод поиска в формате JSON, но статистика в текстовом виде.
+    cmd.args(&["--json", "--stats-format", "text", "Sherlock", "sherlock"]);
+    let output = cmd.stdout();
+    assert!(output.contains(r#""type":"match""#));
+    assert!(output.contains("matched lines"), "got: {output}");
+});
+
+rgtest!(trim_crlf, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", &SHERLOCK.replace('\n', "\r\n"));
+
+    cmd.args(&["--trim-crlf", "-A1", "Doctor Watsons", "sherlock"]);
+    let expected = "\
+For the Doctor Watsons of this world, as opposed to the Sherlock
+Holmeses, success in the province of detective work must always
+";
+    eqnice!(expected, cmd.stdout());
+
+    // Без флага завершающий \r остается в выводе.
+    let mut cmd = dir.command();
+    cmd.args(&["Doctor Watsons", "sherlock"]);
+    assert!(cmd.stdout().contains('\r'));
+});
+
+rgtest!(ignore_case_env, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+
+    // В UTF-8 локали регистр игнорируется.
+    cmd.args(&["--ignore-case-env", "sherlock", "sherlock"]);
+    cmd.cmd().env("LC_ALL", "en_US.UTF-8");
+    let expected = "\
+For the Doctor Watsons of this world, as opposed to the Sherlock
+be, to a very large extent, the result of luck. Sherlock Holmes
+";
+    eqnice!(expected, cmd.stdout());
+
+    // Пустая LC_ALL уступает LC_COLLATE.
+    let mut cmd = dir.command();
+    cmd.args(&["--ignore-case-env", "sherlock", "sherlock"]);
+    cmd.cmd().env("LC_ALL", "").env("LC_COLLATE", "en_US.UTF-8");
+    eqnice!(expected, cmd.stdout());
+
+    // В локали C поиск ведется с учетом регистра.
+    let mut cmd = dir.command();
+    cmd.args(&["--ignore-case-env", "sherlock", "sherlock"]);
+    cmd.cmd().env("LC_ALL", "C");
+    cmd.assert_err();
+
+    // Турецкая локаль не поддерживается: регистр игнорируется по простым
+    // правилам Unicode, а в stderr выводится предупреждение.
+    let mut cmd = dir.command();
+    cmd.args(&["--ignore-case-env", "sherlock", "sherlock"]);
+    cmd.cmd().env("LC_ALL", "tr_TR.UTF-8");
+    let output = cmd.cmd().output().unwrap();
+    eqnice!(expected, String::from_utf8_lossy(&output.stdout).to_string());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("tr_TR"));
+});
+
+rgtest!(sort_key, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("c.txt", "zztop\n");
+    dir.create("a.txt", "zztop\n");
+    dir.create("b.txt", "zztop\n");
+
+    // `echo` печатает сам путь, поэтому сортировка по его выводу дает
+    // алфавитный порядок.
+    cmd.args(&["--sort-key", "echo", "zztop"]);
+    let expected = "\
+a.txt:zztop
+b.txt:zztop
+c.txt:zztop
+";
+    eqnice!(expected, cmd.stdout());
+
+    // Вместе с --sortr path порядок инвертируется.
+    let mut cmd = dir.command();
+    cmd.args(&["--sort-key", "echo", "--sortr", "path", "zztop"]);
+    let expected = "\
+c.txt:zztop
+b.txt:zztop
+a.txt:zztop
+";
+    eqnice!(expected, cmd.stdout());
+});
+
+rgtest!(sort_key_cache, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("b.txt", "zztop\n");
+    dir.create("a.txt", "zztop\n");
+
+    cmd.args(&[
+        "--sort-key",
+        "echo",
+        "--sort-key-cache",
+        "keys.cache",
+        "zztop",
+        "a.txt",
+        "b.txt",
+    ]);
+    eqnice!("a.txt:zztop\nb.txt:zztop\n", cmd.stdout());
+
+    // Кэш записан и используется при повторном запуске.
+    assert!(dir.path().join("keys.cache").exists());
+    let mut cmd = dir.command();
+    cmd.args(&[
+        "--sort-key",
+        "echo",
+        "--sort-key-cache",
+        "keys.cache",
+        "zztop",
+        "a.txt",
+        "b.txt",
+    ]);
+    eqnice!("a.txt:zztop\nb.txt:zztop\n", cmd.stdout());
+});
@@ -131,6 +131,7 @@ struct Stats {
     searches_with_match: u64,
     bytes_searched: u64,
     bytes_printed: u64,
+    lines_searched: u64,
     matched_lines: u64,
     matches: u64,
 }
@@ -439,3 +440,24 @@ rgtest!(r1412_look_behind_match_missing, |dir: Dir, mut cmd: TestCommand| {
     assert_eq!(m.lines, Data::text("bar\n"));
     assert_eq!(m.submatches.len(), 1);
 });
+
+rgtest!(pretty, |dir: Dir, mut cmd: TestCommand| {
+    dir.create("sherlock", SHERLOCK);
+    cmd.arg("--json-pretty").arg("Sherlock Holmes").arg("sherlock");
+
+    let pretty = cmd.stdout();
+    // Каждое сообщение печатается с отступами и завершается переводом строки.
+    assert!(pretty.contains("{\n  \"type\""));
+    assert!(pretty.ends_with('\n'));
+
+    let msgs = json_decode(&pretty);
+    let mut cmd = dir.command();
+    cmd.arg("--json").arg("Sherlock Holmes").arg("sherlock");
+    let compact_msgs = json_decode(&cmd.stdout());
+
+    // Логическое содержимое идентично компактному выводу --json. Финальные
+    // сообщения сравнивать нельзя, поскольку они содержат время поиска.
+    assert_eq!(compact_msgs.len(), msgs.len());
+    assert_eq!(compact_msgs[0], msgs[0]);
+    assert_eq!(compact_msgs[1], msgs[1]);
+});